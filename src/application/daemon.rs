@@ -0,0 +1,434 @@
+use crate::application::orchestration;
+use crate::application::use_cases::fetch_hot_news::FetchHotNewsService;
+use crate::domain::services::DiscordService;
+use crate::domain::{
+    Domain, NewsClassificationService, NewsFetcher, NewsRepository, SortOrder,
+    StoryClusteringService,
+};
+use rand::Rng;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+use tracing::{error, info};
+
+/// 常驻轮询模式（daemon）
+///
+/// **职责**：
+/// - 按每个数据源各自的间隔，反复执行 `FetchHotNewsService` 流程（抓取 → 分类 → 保存 → 推送）
+/// - 分类器、配置和数据库连接只初始化一次，在所有轮次之间复用
+/// - 每个数据源由一个独立的循环串行执行，同一数据源永远不会同时运行两次
+/// - 每次等待都加入随机抖动，避免所有数据源在同一时刻请求
+/// - 收到停止信号后，正在进行的抓取会执行完毕，然后所有循环退出
+pub struct Daemon {
+    sources: Vec<ScheduledSource>,
+    classifier: Arc<NewsClassificationService>,
+    repository: Arc<dyn NewsRepository>,
+    discord: Option<Arc<dyn DiscordService>>,
+    settings: DaemonSettings,
+}
+
+/// 一个按固定间隔轮询的数据源
+pub struct ScheduledSource {
+    pub fetcher: Arc<dyn NewsFetcher>,
+    pub interval: Duration,
+}
+
+/// 每轮抓取共用的参数
+#[derive(Debug, Clone)]
+pub struct DaemonSettings {
+    /// 每轮每个数据源的抓取数量
+    pub limit: usize,
+    /// 只保留这些领域的新闻（指定时执行分类）
+    pub domains: Option<Vec<Domain>>,
+    /// 新闻排序方式
+    pub sort_order: SortOrder,
+    /// 报道聚类的标题相似度阈值 (0.0, 1.0]
+    pub title_similarity: f32,
+    /// 抖动占间隔的比例（0.1 表示最多额外等待 10%）
+    pub jitter_ratio: f64,
+}
+
+impl Default for DaemonSettings {
+    fn default() -> Self {
+        Self {
+            limit: 10,
+            domains: None,
+            sort_order: SortOrder::default(),
+            title_similarity: StoryClusteringService::DEFAULT_TITLE_SIMILARITY,
+            jitter_ratio: 0.1,
+        }
+    }
+}
+
+impl Daemon {
+    pub fn new(
+        sources: Vec<ScheduledSource>,
+        classifier: Arc<NewsClassificationService>,
+        repository: Arc<dyn NewsRepository>,
+        settings: DaemonSettings,
+    ) -> Self {
+        Self {
+            sources,
+            classifier,
+            repository,
+            discord: None,
+            settings,
+        }
+    }
+
+    /// 设置 Discord 推送（可选），每轮抓取到的新新闻会按报道聚类推送
+    pub fn with_discord(mut self, discord: Arc<dyn DiscordService>) -> Self {
+        self.discord = Some(discord);
+        self
+    }
+
+    /// 运行直到 `shutdown` 变为 `true`
+    pub async fn run(self, shutdown: watch::Receiver<bool>) {
+        info!("🛰️  daemon 启动，共 {} 个数据源", self.sources.len());
+
+        let handles: Vec<_> = self
+            .sources
+            .into_iter()
+            .map(|source| {
+                let worker = SourceWorker {
+                    source,
+                    classifier: Arc::clone(&self.classifier),
+                    repository: Arc::clone(&self.repository),
+                    discord: self.discord.clone(),
+                    settings: self.settings.clone(),
+                };
+                tokio::spawn(worker.run(shutdown.clone()))
+            })
+            .collect();
+
+        for handle in handles {
+            if let Err(e) = handle.await {
+                error!("❌ 数据源轮询任务异常退出: {}", e);
+            }
+        }
+
+        info!("👋 daemon 已停止");
+    }
+}
+
+/// 单个数据源的轮询循环
+struct SourceWorker {
+    source: ScheduledSource,
+    classifier: Arc<NewsClassificationService>,
+    repository: Arc<dyn NewsRepository>,
+    discord: Option<Arc<dyn DiscordService>>,
+    settings: DaemonSettings,
+}
+
+impl SourceWorker {
+    async fn run(self, mut shutdown: watch::Receiver<bool>) {
+        let name = self.source.fetcher.source_name().to_string();
+        info!(
+            "⏱️  {} 每 {} 秒抓取一次",
+            name,
+            self.source.interval.as_secs()
+        );
+
+        // 启动时错开各数据源的首次抓取
+        let mut delay = jitter(self.source.interval, self.settings.jitter_ratio);
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(delay) => {}
+                _ = shutdown.changed() => {}
+            }
+            if *shutdown.borrow() {
+                break;
+            }
+
+            // 抓取过程中不响应停止信号，保证一轮处理（含保存）完整结束
+            if let Err(e) = self.run_once().await {
+                error!("❌ {} 抓取失败: {}", name, e);
+            }
+
+            delay = self.source.interval + jitter(self.source.interval, self.settings.jitter_ratio);
+        }
+
+        info!("⏹️  {} 已停止轮询", name);
+    }
+
+    async fn run_once(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let use_case =
+            FetchHotNewsService::new(&*self.source.fetcher, Arc::clone(&self.classifier))
+                .with_repository(Arc::clone(&self.repository))
+                .with_sort_order(self.settings.sort_order)
+                .with_title_similarity(self.settings.title_similarity);
+
+        let news_items = match self.settings.domains {
+            Some(_) => {
+                use_case
+                    .execute_with_classification(self.settings.limit)
+                    .await?
+            }
+            None => {
+                use_case
+                    .execute_without_classification(self.settings.limit)
+                    .await?
+            }
+        };
+        let news_items =
+            orchestration::filter_by_domains(news_items, self.settings.domains.as_deref());
+
+        if let Some(ref discord) = self.discord
+            && !news_items.is_empty()
+        {
+            orchestration::send_to_discord(
+                discord.as_ref(),
+                &news_items,
+                &self.classifier.domains(),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// 随机抖动：`[0, interval * ratio]`
+fn jitter(interval: Duration, ratio: f64) -> Duration {
+    let max = interval.mul_f64(ratio.max(0.0));
+    if max.is_zero() {
+        return Duration::ZERO;
+    }
+    rand::thread_rng().gen_range(Duration::ZERO..=max)
+}
+
+/// 等待 SIGINT（Ctrl+C）或 SIGTERM
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            error!("❌ 无法监听 Ctrl+C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                error!("❌ 无法监听 SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::NewsItem;
+    use crate::infrastructure::database::create_pool;
+    use crate::infrastructure::repositories::SqliteNewsRepository;
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// 记录调用次数与并发度的 fetcher
+    struct CountingFetcher {
+        calls: AtomicUsize,
+        running: AtomicUsize,
+        max_running: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl NewsFetcher for CountingFetcher {
+        async fn fetch(
+            &self,
+            _limit: usize,
+        ) -> Result<Vec<NewsItem>, Box<dyn std::error::Error + Send + Sync>> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_running.fetch_max(running, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            self.running.fetch_sub(1, Ordering::SeqCst);
+
+            Ok(vec![NewsItem::new(
+                format!("id-{}", call),
+                format!("Headline number {}", call),
+                format!("https://example.com/{}", call),
+                "counting".to_string(),
+                "author".to_string(),
+                Utc::now(),
+            )])
+        }
+
+        fn source_name(&self) -> &str {
+            "counting"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_daemon_polls_sequentially_until_shutdown() {
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        let repository: Arc<dyn NewsRepository> = Arc::new(SqliteNewsRepository::new(pool));
+        let fetcher = Arc::new(CountingFetcher {
+            calls: AtomicUsize::new(0),
+            running: AtomicUsize::new(0),
+            max_running: AtomicUsize::new(0),
+        });
+
+        let daemon = Daemon::new(
+            vec![ScheduledSource {
+                fetcher: Arc::clone(&fetcher) as Arc<dyn NewsFetcher>,
+                interval: Duration::from_millis(5),
+            }],
+            Arc::new(NewsClassificationService::new()),
+            Arc::clone(&repository),
+            DaemonSettings::default(),
+        );
+
+        let (tx, rx) = watch::channel(false);
+        let handle = tokio::spawn(daemon.run(rx));
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        tx.send(true).unwrap();
+        handle.await.unwrap();
+
+        let calls = fetcher.calls.load(Ordering::SeqCst);
+        assert!(calls >= 2, "calls = {}", calls);
+        // 间隔短于单次抓取耗时，同一数据源仍然不会并发执行
+        assert_eq!(fetcher.max_running.load(Ordering::SeqCst), 1);
+        // 停止信号后，进行中的一轮会完整保存
+        assert_eq!(repository.count().await.unwrap(), calls);
+    }
+
+    /// 每次调用依次返回预设的一批新闻
+    struct ScriptedFetcher {
+        batches: Vec<Vec<NewsItem>>,
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl NewsFetcher for ScriptedFetcher {
+        async fn fetch(
+            &self,
+            _limit: usize,
+        ) -> Result<Vec<NewsItem>, Box<dyn std::error::Error + Send + Sync>> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(self.batches.get(call).cloned().unwrap_or_default())
+        }
+
+        fn source_name(&self) -> &str {
+            "scripted"
+        }
+    }
+
+    /// 记录推送消息标题的 Discord 服务
+    #[derive(Default)]
+    struct RecordingDiscord {
+        titles: std::sync::Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl DiscordService for RecordingDiscord {
+        async fn send_message(
+            &self,
+            message: &crate::domain::services::DiscordMessage,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            self.titles.lock().unwrap().push(message.title.clone());
+            Ok(())
+        }
+
+        async fn send_batch(
+            &self,
+            messages: &[crate::domain::services::DiscordMessage],
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            for message in messages {
+                self.send_message(message).await?;
+            }
+            Ok(())
+        }
+    }
+
+    fn story(id: &str, source: &str, url: &str, title: &str) -> NewsItem {
+        NewsItem::new(
+            id.to_string(),
+            title.to_string(),
+            url.to_string(),
+            source.to_string(),
+            "author".to_string(),
+            Utc::now(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_late_coverage_of_stored_story_is_not_posted_again() {
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        let repository: Arc<dyn NewsRepository> = Arc::new(SqliteNewsRepository::new(pool));
+        let discord = Arc::new(RecordingDiscord::default());
+        let fetcher = Arc::new(ScriptedFetcher {
+            batches: vec![
+                vec![story(
+                    "hn-1",
+                    "hackernews",
+                    "https://a.com/etf",
+                    "Bitcoin ETF sees record inflows",
+                )],
+                vec![
+                    story(
+                        "cd-1",
+                        "coindesk",
+                        "https://b.com/etf",
+                        "Bitcoin ETF sees record inflows",
+                    ),
+                    story(
+                        "cd-2",
+                        "coindesk",
+                        "https://b.com/eth",
+                        "Ethereum completes upgrade",
+                    ),
+                ],
+            ],
+            calls: AtomicUsize::new(0),
+        });
+
+        let worker = SourceWorker {
+            source: ScheduledSource {
+                fetcher: fetcher as Arc<dyn NewsFetcher>,
+                interval: Duration::from_secs(60),
+            },
+            classifier: Arc::new(NewsClassificationService::new()),
+            repository: Arc::clone(&repository),
+            discord: Some(Arc::clone(&discord) as Arc<dyn DiscordService>),
+            settings: DaemonSettings::default(),
+        };
+
+        worker.run_once().await.unwrap();
+        worker.run_once().await.unwrap();
+
+        // 第二轮的后续报道已入库并归入第一轮的聚类，但不会再次推送
+        assert_eq!(repository.count().await.unwrap(), 3);
+        let late = repository.find_by_id("cd-1").await.unwrap().unwrap();
+        assert_eq!(late.cluster_id.as_deref(), Some("hn-1"));
+        assert_eq!(
+            *discord.titles.lock().unwrap(),
+            vec![
+                "Bitcoin ETF sees record inflows".to_string(),
+                "Ethereum completes upgrade".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_jitter_stays_within_ratio() {
+        let interval = Duration::from_secs(600);
+        for _ in 0..100 {
+            assert!(jitter(interval, 0.1) <= Duration::from_secs(60));
+        }
+        assert_eq!(jitter(interval, 0.0), Duration::ZERO);
+    }
+}
@@ -0,0 +1,285 @@
+use crate::domain::config::ClassificationConfig;
+use crate::domain::{
+    Domain, KeywordSuggestion, KeywordSuggestionRepository, KeywordSuggestionStatus,
+};
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// 交互式审核 AI 建议的关键词（关键词收件箱）
+///
+/// **职责**：
+/// - 按出现次数从多到少列出待审核的建议关键词及其来源新闻
+/// - 审核者可以批准为强关键词 / 弱关键词，或永久拒绝
+/// - 批准的关键词通过 `ClassificationConfig::save_to_file` 写回分类配置文件，
+///   之后的分类（包括 `reclassify`）立即使用
+///
+/// 输入输出是泛型的，CLI 使用 stdin / stdout，测试使用内存缓冲区。
+pub struct KeywordSuggestionSession<R, W> {
+    repository: Arc<dyn KeywordSuggestionRepository>,
+    config_path: PathBuf,
+    input: R,
+    output: W,
+}
+
+/// 审核者对一条建议的操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SuggestionCommand {
+    Strong,
+    Weak,
+    Reject,
+    Skip,
+    Quit,
+}
+
+impl std::str::FromStr for SuggestionCommand {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "g" | "strong" => Ok(SuggestionCommand::Strong),
+            "w" | "weak" => Ok(SuggestionCommand::Weak),
+            "r" | "reject" => Ok(SuggestionCommand::Reject),
+            "n" | "next" | "skip" | "" => Ok(SuggestionCommand::Skip),
+            "q" | "quit" => Ok(SuggestionCommand::Quit),
+            other => Err(format!("未知操作: {}", other)),
+        }
+    }
+}
+
+/// 一次审核的结果汇总
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct KeywordSuggestionSummary {
+    /// 批准为强关键词的数量
+    pub strong: usize,
+    /// 批准为弱关键词的数量
+    pub weak: usize,
+    /// 拒绝的数量
+    pub rejected: usize,
+    /// 跳过（保留在收件箱）的数量
+    pub skipped: usize,
+}
+
+/// 每条建议最多展示的来源新闻数量
+const MAX_SHOWN_NEWS_IDS: usize = 5;
+
+impl<R: BufRead, W: Write> KeywordSuggestionSession<R, W> {
+    pub fn new(repository: Arc<dyn KeywordSuggestionRepository>, input: R, output: W) -> Self {
+        Self {
+            repository,
+            config_path: PathBuf::from(ClassificationConfig::DEFAULT_PATH),
+            input,
+            output,
+        }
+    }
+
+    /// 指定批准的关键词写入的配置文件（默认 `config/classification.json`）
+    pub fn with_config_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config_path = path.into();
+        self
+    }
+
+    /// 审核最多 `limit` 条待审核建议，`domain` 为空表示所有领域
+    ///
+    /// 输入结束（EOF）或输入 `q` 时提前结束，已审核的结果都已保存。
+    pub async fn run(
+        &mut self,
+        domain: Option<Domain>,
+        limit: usize,
+    ) -> Result<KeywordSuggestionSummary, Box<dyn std::error::Error + Send + Sync>> {
+        let inbox = self.repository.find_pending(domain, limit).await?;
+        let mut summary = KeywordSuggestionSummary::default();
+
+        if inbox.is_empty() {
+            writeln!(self.output, "✅ 没有待审核的建议关键词")?;
+            return Ok(summary);
+        }
+
+        let total = inbox.len();
+        for (i, suggestion) in inbox.iter().enumerate() {
+            self.show(i + 1, total, suggestion)?;
+
+            let Some(command) = self.prompt()? else {
+                break;
+            };
+            let status = match command {
+                SuggestionCommand::Quit => break,
+                SuggestionCommand::Skip => {
+                    summary.skipped += 1;
+                    continue;
+                }
+                SuggestionCommand::Strong => KeywordSuggestionStatus::ApprovedStrong,
+                SuggestionCommand::Weak => KeywordSuggestionStatus::ApprovedWeak,
+                SuggestionCommand::Reject => KeywordSuggestionStatus::Rejected,
+            };
+
+            // 先写配置再更新状态：状态更新失败时重新批准也是幂等的
+            if status != KeywordSuggestionStatus::Rejected {
+                self.approve(suggestion, status)?;
+            }
+            self.repository
+                .set_status(&suggestion.keyword, suggestion.domain, status)
+                .await?;
+
+            match status {
+                KeywordSuggestionStatus::ApprovedStrong => summary.strong += 1,
+                KeywordSuggestionStatus::ApprovedWeak => summary.weak += 1,
+                _ => summary.rejected += 1,
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// 把批准的关键词写入分类配置文件（每次重新读取，避免覆盖其他修改）
+    fn approve(
+        &self,
+        suggestion: &KeywordSuggestion,
+        status: KeywordSuggestionStatus,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut config = ClassificationConfig::load_from_file(&self.config_path)?;
+        let keyword = suggestion.keyword.clone();
+        if status == KeywordSuggestionStatus::ApprovedStrong {
+            config.add_strong_keyword(suggestion.domain, keyword);
+        } else {
+            config.merge_suggested_keywords(suggestion.domain, vec![keyword]);
+        }
+        config.save_to_file(&self.config_path)
+    }
+
+    fn show(
+        &mut self,
+        index: usize,
+        total: usize,
+        suggestion: &KeywordSuggestion,
+    ) -> std::io::Result<()> {
+        let out = &mut self.output;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "[{}/{}] \"{}\" → {}",
+            index, total, suggestion.keyword, suggestion.domain
+        )?;
+        writeln!(
+            out,
+            "  出现 {} 次，首次 {}，最近 {}",
+            suggestion.occurrences,
+            suggestion.first_seen_at.format("%Y-%m-%d %H:%M"),
+            suggestion.last_seen_at.format("%Y-%m-%d %H:%M")
+        )?;
+        let shown: Vec<&str> = suggestion
+            .news_ids
+            .iter()
+            .take(MAX_SHOWN_NEWS_IDS)
+            .map(String::as_str)
+            .collect();
+        let more = suggestion.news_ids.len().saturating_sub(shown.len());
+        if more > 0 {
+            writeln!(
+                out,
+                "  来源新闻: {} 等 {} 条",
+                shown.join(", "),
+                suggestion.news_ids.len()
+            )?;
+        } else {
+            writeln!(out, "  来源新闻: {}", shown.join(", "))?;
+        }
+        Ok(())
+    }
+
+    /// 读取一条有效操作；输入结束时返回 `None`
+    fn prompt(&mut self) -> std::io::Result<Option<SuggestionCommand>> {
+        loop {
+            write!(
+                self.output,
+                "  [g]强关键词 / [w]弱关键词 / [r]拒绝 / [n]跳过 / [q]退出 > "
+            )?;
+            self.output.flush()?;
+
+            let mut line = String::new();
+            if self.input.read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+            match line.parse() {
+                Ok(command) => return Ok(Some(command)),
+                Err(e) => writeln!(self.output, "  {}", e)?,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database::create_pool;
+    use crate::infrastructure::repositories::SqliteKeywordSuggestionRepository;
+
+    #[test]
+    fn test_parse_suggestion_command() {
+        let parse = |s: &str| s.parse::<SuggestionCommand>();
+        assert_eq!(parse("g"), Ok(SuggestionCommand::Strong));
+        assert_eq!(parse("Strong"), Ok(SuggestionCommand::Strong));
+        assert_eq!(parse("w"), Ok(SuggestionCommand::Weak));
+        assert_eq!(parse("r"), Ok(SuggestionCommand::Reject));
+        assert_eq!(parse("n"), Ok(SuggestionCommand::Skip));
+        assert_eq!(parse("skip"), Ok(SuggestionCommand::Skip));
+        assert_eq!(parse("\n"), Ok(SuggestionCommand::Skip));
+        assert_eq!(parse("q"), Ok(SuggestionCommand::Quit));
+        // 单字母快捷键互不重叠，`s` 不再有歧义
+        assert!(parse("s").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_session_writes_approvals_to_config() {
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        let repository: Arc<dyn KeywordSuggestionRepository> =
+            Arc::new(SqliteKeywordSuggestionRepository::new(pool));
+        // 出现次数决定审核顺序：rag(3) → vector db(2) → hype(1)
+        for (news_id, keywords) in [
+            ("1", vec!["rag", "vector db", "hype"]),
+            ("2", vec!["rag", "vector db"]),
+            ("3", vec!["rag"]),
+        ] {
+            let keywords: Vec<String> = keywords.into_iter().map(String::from).collect();
+            repository
+                .record(Domain::new("AI"), news_id, &keywords)
+                .await
+                .unwrap();
+        }
+
+        let dir = std::env::temp_dir().join(format!(
+            "trendarc-keyword-suggestions-{}",
+            std::process::id()
+        ));
+        let config_path = dir.join("classification.json");
+        ClassificationConfig::empty()
+            .save_to_file(&config_path)
+            .unwrap();
+
+        let input = b"g\nw\nr\n".as_slice();
+        let mut output = Vec::new();
+        let summary = KeywordSuggestionSession::new(Arc::clone(&repository), input, &mut output)
+            .with_config_path(&config_path)
+            .run(None, 10)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            summary,
+            KeywordSuggestionSummary {
+                strong: 1,
+                weak: 1,
+                rejected: 1,
+                skipped: 0,
+            }
+        );
+        let config = ClassificationConfig::load_from_file(&config_path).unwrap();
+        let ai = config.domain(Domain::new("AI")).unwrap();
+        assert_eq!(ai.strong_keywords, vec!["rag".to_string()]);
+        assert_eq!(ai.weak_keywords, vec!["vector db".to_string()]);
+        assert!(!config.contains_keyword(Domain::new("AI"), "hype"));
+        assert!(repository.find_pending(None, 10).await.unwrap().is_empty());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,2 +1,5 @@
+pub mod daemon;
+pub mod keyword_suggestions;
 pub mod orchestration;
+pub mod review;
 pub mod use_cases;
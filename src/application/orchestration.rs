@@ -1,7 +1,20 @@
+use crate::application::use_cases::evaluate_classification::{
+    ClassificationStage, EvaluationReport,
+};
 use crate::application::use_cases::fetch_hot_news::{FetchHotNewsService, FetchHotNewsUseCase};
-use crate::domain::{Domain, NewsClassificationService, NewsFetcher};
+use crate::application::use_cases::reclassify_news::{
+    ClassificationLabel, ReclassifyNewsService, ReclassifyReport,
+};
+use crate::domain::config::DomainDefinition;
+use crate::domain::services::{DiscordMessage, DiscordService, StoryCluster};
+use crate::domain::{
+    ClassificationTrace, Domain, DomainLabel, NewsClassificationService, NewsFetcher, NewsQuery,
+    NewsQueryOrder, NewsSortingService, SortOrder, StageStatus, StoryClusteringService,
+    StoryVelocityService,
+};
+use std::collections::HashMap;
 use std::sync::Arc;
-use tracing::info;
+use tracing::{info, warn};
 
 /// 应用层编排模块
 ///
@@ -22,8 +35,12 @@ pub async fn fetch_from_source_with_classification(
     limit: usize,
     repository: Option<Arc<dyn crate::domain::NewsRepository>>,
     should_classify: bool,
+    sort_order: SortOrder,
+    title_similarity: f32,
 ) -> Result<Vec<crate::domain::NewsItem>, Box<dyn std::error::Error + Send + Sync>> {
-    let mut use_case = FetchHotNewsService::new(&*fetcher, classifier);
+    let mut use_case = FetchHotNewsService::new(&*fetcher, classifier)
+        .with_sort_order(sort_order)
+        .with_title_similarity(title_similarity);
 
     // 如果需要保存，注入 Repository
     if let Some(ref repo) = repository {
@@ -45,62 +62,140 @@ pub async fn fetch_from_source(
     limit: usize,
     repository: Option<Arc<dyn crate::domain::NewsRepository>>,
 ) -> Result<Vec<crate::domain::NewsItem>, Box<dyn std::error::Error + Send + Sync>> {
-    fetch_from_source_with_classification(fetcher, classifier, limit, repository, true).await
+    fetch_from_source_with_classification(
+        fetcher,
+        classifier,
+        limit,
+        repository,
+        true,
+        SortOrder::default(),
+        StoryClusteringService::DEFAULT_TITLE_SIMILARITY,
+    )
+    .await
 }
 
-/// 从数据库加载新闻
-pub async fn load_from_database(
-    repository: &Arc<dyn crate::domain::NewsRepository>,
+/// 只保留指定领域的新闻（未指定时原样返回）
+pub fn filter_by_domains(
+    news_items: Vec<crate::domain::NewsItem>,
     domains: Option<&[Domain]>,
-    limit: usize,
-) -> Result<Vec<crate::domain::NewsItem>, Box<dyn std::error::Error + Send + Sync>> {
+) -> Vec<crate::domain::NewsItem> {
     match domains {
-        Some(domains) if !domains.is_empty() => {
-            let news = repository.find_by_domains(domains, limit).await?;
-            Ok(news)
-        }
-        _ => {
-            let news = repository.find_recent(limit).await?;
-            Ok(news)
+        Some(domains) => {
+            info!(
+                "🔍 过滤领域: {}",
+                domains
+                    .iter()
+                    .map(|d| d.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            news_items
+                .into_iter()
+                .filter(|n| domains.iter().any(|&d| n.belongs_to(d)))
+                .collect()
         }
+        None => news_items,
     }
 }
 
-/// 显示新闻
-pub async fn display_news(news_items: &[crate::domain::NewsItem]) {
-    let classifier = NewsClassificationService::new();
-    let grouped = classifier.group_by_domain(news_items);
+/// 发送新闻到 Discord（每个报道聚类一条消息）
+///
+/// 归入已入库报道的后续报道不会再次推送，该报道在之前的抓取中已经推送过。
+/// `domains` 为当前配置中的领域，用于消息的图标和颜色。
+pub async fn send_to_discord(
+    discord_service: &dyn DiscordService,
+    news_items: &[crate::domain::NewsItem],
+    domains: &[DomainDefinition],
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    info!("📤 准备发送到 Discord...");
+    let (stories, follow_ups): (Vec<StoryCluster>, Vec<StoryCluster>) =
+        StoryClusteringService::group(news_items.to_vec())
+            .into_iter()
+            .partition(|story| !story.continues_stored_story());
+    if !follow_ups.is_empty() {
+        info!("⏭️  跳过 {} 个已推送报道的后续报道", follow_ups.len());
+    }
+    if stories.is_empty() {
+        info!("✅ 没有新的报道需要发送");
+        return Ok(());
+    }
 
-    info!("═════════════════════════════════════════════");
+    let discord_messages: Vec<_> = stories
+        .iter()
+        .map(|story| {
+            let message = DiscordMessage::from_story(story);
+            match story
+                .primary
+                .domain
+                .and_then(|domain| domains.iter().find(|d| d.name == domain))
+            {
+                Some(definition) => message.with_domain_style(definition),
+                None => message,
+            }
+        })
+        .collect();
 
-    // 展示 AI 领域新闻
-    let ai_news = grouped.get(&Domain::AI).unwrap();
-    if !ai_news.is_empty() {
-        info!("🤖 AI 领域 ({} 条)", ai_news.len());
-        info!("───────────────────────────────────────────");
-        for (i, news) in ai_news.iter().enumerate() {
-            print_news_item(i + 1, news);
+    discord_service.send_batch(&discord_messages).await?;
+    info!("✅ Discord 消息发送成功");
+    Ok(())
+}
+
+/// 从数据库加载新闻
+///
+/// 能在数据库中排序的 `sort_order` 先排序再分页；`Hotness` 随时间衰减，
+/// 只能对按发布时间取出的这一页重新排序
+pub async fn load_from_database(
+    repository: &Arc<dyn crate::domain::NewsRepository>,
+    query: &NewsQuery,
+    sort_order: SortOrder,
+) -> Result<Vec<crate::domain::NewsItem>, Box<dyn std::error::Error + Send + Sync>> {
+    match NewsQueryOrder::from_sort_order(sort_order) {
+        Some(order) => repository.find(&query.clone().with_order(order)).await,
+        None => {
+            let news = repository.find(query).await?;
+            Ok(NewsSortingService::sort(news, sort_order))
         }
     }
+}
 
-    // 展示 Block 领域新闻
-    let block_news = grouped.get(&Domain::Block).unwrap();
-    if !block_news.is_empty() {
-        info!("⛓️  Block 领域 ({} 条)", block_news.len());
-        info!("───────────────────────────────────────────");
-        for (i, news) in block_news.iter().enumerate() {
-            print_news_item(i + 1, news);
+/// 显示新闻
+///
+/// 同一事件的多篇报道（同一聚类）只展示一条，并注明其他报道的来源数量。
+/// 按 `domains`（当前配置中的领域）的顺序分组展示，有次要领域的新闻会出现在多个分组中。
+pub async fn display_news(news_items: &[crate::domain::NewsItem], domains: &[DomainDefinition]) {
+    let mut grouped: HashMap<Domain, Vec<crate::domain::NewsItem>> = HashMap::new();
+    for item in news_items {
+        for domain in item.domains() {
+            grouped.entry(domain).or_default().push(item.clone());
         }
     }
 
-    // 展示 Social 领域新闻
-    let social_news = grouped.get(&Domain::Social).unwrap();
-    if !social_news.is_empty() {
-        info!("📱 Social 领域 ({} 条)", social_news.len());
-        info!("───────────────────────────────────────────");
-        for (i, news) in social_news.iter().enumerate() {
-            print_news_item(i + 1, news);
+    info!("═════════════════════════════════════════════");
+
+    // 按配置顺序展示各领域，配置中已删除的领域排在最后
+    let mut definitions = domains.to_vec();
+    let mut removed: Vec<Domain> = grouped
+        .keys()
+        .filter(|domain| !definitions.iter().any(|d| d.name == **domain))
+        .copied()
+        .collect();
+    removed.sort();
+    definitions.extend(removed.into_iter().map(DomainDefinition::new));
+
+    for definition in definitions {
+        let news = grouped.remove(&definition.name).unwrap_or_default();
+        if news.is_empty() {
+            continue;
         }
+        let stories = StoryClusteringService::group(news);
+        info!(
+            "{} {} 领域 ({} 条)",
+            definition.emoji,
+            definition.name,
+            stories.len()
+        );
+        info!("───────────────────────────────────────────");
+        print_stories(&stories);
     }
 }
 
@@ -123,12 +218,382 @@ pub async fn show_stats(
     Ok(())
 }
 
+/// 显示近期热度增长最快的新闻
+///
+/// 基于最近 `window_minutes` 分钟内的快照计算增长，至少需要两次快照；按每小时增长的分数排序。
+pub async fn show_trending(
+    repository: &Arc<dyn crate::domain::NewsRepository>,
+    window_minutes: i64,
+    limit: usize,
+) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
+    let since = chrono::Utc::now() - chrono::Duration::minutes(window_minutes);
+    let snapshots = repository.find_snapshots_since(since).await?;
+    let velocities = StoryVelocityService::compute(&snapshots);
+
+    info!("🚀 最近 {} 分钟热度上升最快的新闻", window_minutes);
+    info!("───────────────────────────────────────────");
+
+    let mut shown = 0;
+    for velocity in velocities.iter().take(limit) {
+        let Some(news) = repository.find_by_id(&velocity.news_id).await? else {
+            continue;
+        };
+        shown += 1;
+        print_news_item(shown, &news);
+        info!(
+            "      增长: ▲ {:.0} 分/小时（+{} 分 / {} 分钟，+{} 评论）",
+            velocity.points_per_hour(),
+            velocity.score_gain,
+            velocity.elapsed.num_minutes(),
+            velocity.comment_gain
+        );
+    }
+
+    if shown == 0 {
+        info!("暂无足够的快照数据，请先多次运行 fetch --save");
+    }
+
+    Ok(shown)
+}
+
+/// 全文检索数据库中的新闻并展示命中片段
+///
+/// 返回展示的新闻数量
+pub async fn search_news(
+    repository: &Arc<dyn crate::domain::NewsRepository>,
+    query: &str,
+    limit: usize,
+) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
+    let hits = repository.search(query, limit).await?;
+
+    info!("🔍 搜索: {}", query);
+    info!("───────────────────────────────────────────");
+
+    for (i, hit) in hits.iter().enumerate() {
+        print_news_item(i + 1, &hit.news);
+        info!("      片段: {}", hit.snippet);
+        info!("      相关度: {:.2}", -hit.relevance);
+    }
+
+    if hits.is_empty() {
+        info!("没有找到匹配的新闻");
+    }
+
+    Ok(hits.len())
+}
+
+/// 显示一条新闻的详情和分类轨迹
+///
+/// 返回是否找到该新闻
+pub async fn show_news(
+    repository: &Arc<dyn crate::domain::NewsRepository>,
+    id: &str,
+) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+    let Some(news) = repository.find_by_id(id).await? else {
+        return Ok(false);
+    };
+
+    info!("📰 新闻详情: {}", news.id);
+    info!("───────────────────────────────────────────");
+    print_news_item(1, &news);
+    info!(
+        "      分类: {} | 置信度: {} | 状态: {:?}",
+        format_optional_domain(news.domain),
+        news.classification_confidence
+            .map_or_else(|| "-".to_string(), |c| format!("{:.2}", c)),
+        news.status
+    );
+
+    let Some(trace) = news.classification_trace else {
+        info!("      暂无分类轨迹（旧数据可通过 reclassify 重新分类生成）");
+        return Ok(true);
+    };
+
+    info!("");
+    info!(
+        "  分类轨迹（{}）",
+        trace.classified_at.format("%Y-%m-%d %H:%M:%S UTC")
+    );
+    for line in format_trace_stages(&trace) {
+        info!("{}", line);
+    }
+
+    Ok(true)
+}
+
+/// 逐行格式化分类轨迹的各个阶段（含阶段详情和错误）
+pub fn format_trace_stages(trace: &ClassificationTrace) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (i, stage) in trace.stages.iter().enumerate() {
+        let mut result = match (stage.domain, stage.confidence) {
+            (Some(domain), Some(confidence)) => format!(" → {} {:.2}", domain, confidence),
+            (None, Some(confidence)) => format!(" → 无关 {:.2}", confidence),
+            _ => String::new(),
+        };
+        if !stage.secondary_domains.is_empty() {
+            result.push_str(&format!(
+                " + {}",
+                format_secondary_domains(&stage.secondary_domains)
+            ));
+        }
+        lines.push(format!(
+            "  {}. {:<18} {:<10}{} ({} ms)",
+            i + 1,
+            stage.stage,
+            format_stage_status(stage.status),
+            result,
+            stage.elapsed_ms
+        ));
+        if !stage.detail.is_empty() {
+            lines.push(format!("       {}", stage.detail));
+        }
+        if let Some(ref error) = stage.error {
+            lines.push(format!("       错误: {}", error));
+        }
+    }
+    lines
+}
+
+fn format_stage_status(status: StageStatus) -> &'static str {
+    match status {
+        StageStatus::Decided => "✅ 决定",
+        StageStatus::Candidate => "🔸 候选",
+        StageStatus::Completed => "· 完成",
+        StageStatus::Skipped => "⏭ 跳过",
+        StageStatus::Failed => "❌ 失败",
+    }
+}
+
+/// 重新分类数据库中匹配 `query` 的新闻，并打印变化明细
+///
+/// `dry_run` 为 true 时只打印变化，不写回数据库
+pub async fn reclassify(
+    repository: Arc<dyn crate::domain::NewsRepository>,
+    classifier: Arc<NewsClassificationService>,
+    query: &NewsQuery,
+    dry_run: bool,
+) -> Result<ReclassifyReport, Box<dyn std::error::Error + Send + Sync>> {
+    let report = ReclassifyNewsService::new(repository, classifier)
+        .with_dry_run(dry_run)
+        .execute(query)
+        .await?;
+
+    info!(
+        "🔁 重新分类结果{}",
+        if dry_run {
+            "（dry run，未写回）"
+        } else {
+            ""
+        }
+    );
+    info!("───────────────────────────────────────────");
+
+    let mut transitions: Vec<(String, usize)> = Vec::new();
+    for (i, change) in report.changes.iter().enumerate() {
+        info!("  【{}】{}", i + 1, change.title);
+        info!("      ID: {}", change.news_id);
+        info!(
+            "      {} → {}",
+            format_label(&change.before),
+            format_label(&change.after)
+        );
+        if let Some(ref reason) = change.after.reason {
+            info!("      依据: {}", reason);
+        }
+
+        if change.before.differs_in_label(&change.after) {
+            let key = format!(
+                "{} → {}",
+                format_domain(&change.before),
+                format_domain(&change.after)
+            );
+            match transitions.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, count)) => *count += 1,
+                None => transitions.push((key, 1)),
+            }
+        }
+    }
+
+    info!("───────────────────────────────────────────");
+    info!(
+        "📊 共处理 {} 条，{} 条有变化，其中 {} 条领域或状态改变",
+        report.scanned,
+        report.changes.len(),
+        transitions.iter().map(|(_, count)| count).sum::<usize>()
+    );
+    for (transition, count) in &transitions {
+        info!("  {}: {} 条", transition, count);
+    }
+
+    Ok(report)
+}
+
+fn format_label(label: &ClassificationLabel) -> String {
+    match label.confidence {
+        Some(confidence) => format!(
+            "{} ({:.2}, {:?})",
+            format_domain(label),
+            confidence,
+            label.status
+        ),
+        None => format!("{} ({:?})", format_domain(label), label.status),
+    }
+}
+
+fn format_domain(label: &ClassificationLabel) -> String {
+    let primary = label
+        .domain
+        .map_or_else(|| "无领域".to_string(), |d| d.to_string());
+    label
+        .secondary_domains
+        .iter()
+        .fold(primary, |acc, d| format!("{}+{}", acc, d))
+}
+
+/// 打印分类评估结果：各领域指标、混淆矩阵、各阶段命中率和平均耗时
+pub fn print_evaluation(report: &EvaluationReport) {
+    info!("🧪 分类评估结果（{} 条样本）", report.total());
+    info!("───────────────────────────────────────────");
+    info!("  准确率: {:.1}%", report.accuracy() * 100.0);
+    let unconfigured = report.unconfigured_domains();
+    if !unconfigured.is_empty() {
+        warn!(
+            "⚠️ 数据集中的领域未在配置中定义，这些样本不可能被正确分类: {}",
+            unconfigured
+                .iter()
+                .map(|d| d.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    info!("");
+    info!(
+        "  {:<8} {:>9} {:>9} {:>9} {:>7}",
+        "领域", "精确率", "召回率", "F1", "样本"
+    );
+    for domain in report.labels().into_iter().flatten() {
+        let m = report.domain_metrics(domain);
+        info!(
+            "  {:<8} {:>9.3} {:>9.3} {:>9.3} {:>7}",
+            domain.to_string(),
+            m.precision,
+            m.recall,
+            m.f1,
+            m.support
+        );
+    }
+
+    info!("");
+    info!("  混淆矩阵（行：期望，列：预测）");
+    let labels = report.labels();
+    let header: Vec<String> = labels
+        .iter()
+        .map(|label| format!("{:>7}", format_optional_domain(*label)))
+        .collect();
+    info!("  {:<8}{}", "", header.join(""));
+    for &expected in &labels {
+        let row: Vec<String> = labels
+            .iter()
+            .map(|predicted| format!("{:>7}", report.confusion(expected, *predicted)))
+            .collect();
+        info!("  {:<8}{}", format_optional_domain(expected), row.join(""));
+    }
+
+    info!("");
+    info!("  各阶段命中率");
+    for stats in report.stage_stats() {
+        info!(
+            "  {:<18} {:>5} 条 ({:>5.1}%)，其中正确 {:>5.1}%",
+            format_stage(stats.stage),
+            stats.count,
+            stats.count as f64 * 100.0 / report.total() as f64,
+            stats.correct as f64 * 100.0 / stats.count as f64
+        );
+    }
+
+    info!("");
+    info!(
+        "  平均耗时: {:.1} ms/条",
+        report.average_latency().as_secs_f64() * 1000.0
+    );
+    info!("───────────────────────────────────────────");
+}
+
+fn format_optional_domain(domain: Option<Domain>) -> String {
+    domain.map_or_else(|| "无关".to_string(), |d| d.to_string())
+}
+
+fn format_stage(stage: ClassificationStage) -> &'static str {
+    match stage {
+        ClassificationStage::Excluded => "Excluded",
+        ClassificationStage::FastPass => "FastPass",
+        ClassificationStage::SourceTendency => "SourceTendency",
+        ClassificationStage::FullContentScan => "FullContentScan",
+        ClassificationStage::AI => "AI",
+        ClassificationStage::WeakMatchFallback => "WeakMatchFallback",
+        ClassificationStage::Strategy => "Strategy",
+        ClassificationStage::Unclassified => "Unclassified",
+    }
+}
+
+/// 打印一组报道聚类（每个聚类展示主新闻）
+fn print_stories(stories: &[StoryCluster]) {
+    for (i, story) in stories.iter().enumerate() {
+        print_news_item(i + 1, &story.primary);
+        if !story.related.is_empty() {
+            let sources = story.related_sources();
+            if sources.is_empty() {
+                info!("      另有 {} 篇相关报道", story.related.len());
+            } else {
+                info!(
+                    "      另有 {} 个来源报道: {}",
+                    sources.len(),
+                    sources.join(", ")
+                );
+            }
+        }
+    }
+}
+
 /// 打印单条新闻
 fn print_news_item(index: usize, news: &crate::domain::NewsItem) {
     info!("  【{}】{}", index, news.title);
     info!("      来源: {} | 作者: {}", news.source, news.author);
     info!("      链接: {}", news.url);
+    if let Some(engagement) = format_engagement(news) {
+        info!("      热度: {}", engagement);
+    }
+    if let Some(ref discussion_url) = news.discussion_url {
+        info!("      讨论: {}", discussion_url);
+    }
+    if !news.secondary_domains.is_empty() {
+        info!(
+            "      同时属于: {}",
+            format_secondary_domains(&news.secondary_domains)
+        );
+    }
     if let Some(ref reason) = news.classification_reason {
         info!("      依据: {}", reason);
     }
-}
\ No newline at end of file
+}
+
+/// 格式化次要领域，如 `Block (0.69), Social (0.40)`
+fn format_secondary_domains(labels: &[DomainLabel]) -> String {
+    labels
+        .iter()
+        .map(|label| label.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// 格式化热度指标（分数 / 评论数），两者都缺失时返回 None
+fn format_engagement(news: &crate::domain::NewsItem) -> Option<String> {
+    let mut parts = Vec::new();
+    if let Some(score) = news.score {
+        parts.push(format!("{} 分", score));
+    }
+    if let Some(comments) = news.comment_count {
+        parts.push(format!("{} 评论", comments));
+    }
+    (!parts.is_empty()).then(|| parts.join(" | "))
+}
@@ -0,0 +1,312 @@
+use crate::application::orchestration;
+use crate::domain::config::ClassificationConfig;
+use crate::domain::{
+    Domain, NewsItem, NewsItemStatus, NewsQuery, NewsRepository, ReviewDecision, ReviewLabel,
+};
+use std::io::{BufRead, Write};
+use std::sync::Arc;
+
+/// 交互式审核待复核（NeedsReview）的新闻
+///
+/// **职责**：
+/// - 加载状态为 `NeedsReview` 的新闻（弱匹配回退、AI 置信度低于阈值的分类结果）
+/// - 逐条展示标题、来源、预测领域、置信度、分类依据和分类轨迹，由审核者确认、改标或标记为无关
+/// - 审核结果写回新闻的分类，同时记录为 `review_labels` 中的人工标签（评估分类器的标准答案）
+///
+/// 输入输出是泛型的，CLI 使用 stdin / stdout，测试使用内存缓冲区。
+pub struct ReviewSession<R, W> {
+    repository: Arc<dyn NewsRepository>,
+    input: R,
+    output: W,
+    /// 可改标的领域
+    domains: Vec<Domain>,
+}
+
+/// 审核者对一条新闻的操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReviewCommand {
+    Accept,
+    Relabel(Domain),
+    Irrelevant,
+    Skip,
+    Quit,
+}
+
+impl ReviewCommand {
+    /// 解析一行输入；领域名称不区分大小写，只接受 `domains` 中的领域
+    fn parse(s: &str, domains: &[Domain]) -> Result<Self, String> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "a" | "accept" => Ok(ReviewCommand::Accept),
+            "i" | "irrelevant" => Ok(ReviewCommand::Irrelevant),
+            "s" | "skip" | "" => Ok(ReviewCommand::Skip),
+            "q" | "quit" => Ok(ReviewCommand::Quit),
+            other => domains
+                .iter()
+                .find(|d| d.display_name().eq_ignore_ascii_case(other))
+                .map(|d| ReviewCommand::Relabel(*d))
+                .ok_or_else(|| format!("未知操作: {}", s.trim())),
+        }
+    }
+}
+
+/// 一次审核的结果汇总
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ReviewSummary {
+    /// 确认预测领域的数量
+    pub accepted: usize,
+    /// 改为其他领域的数量
+    pub relabeled: usize,
+    /// 标记为无关的数量
+    pub irrelevant: usize,
+    /// 跳过（保持待复核）的数量
+    pub skipped: usize,
+}
+
+impl ReviewSummary {
+    /// 写入了人工标签的数量
+    pub fn reviewed(&self) -> usize {
+        self.accepted + self.relabeled + self.irrelevant
+    }
+}
+
+/// 正文预览的最大字符数
+const CONTENT_PREVIEW_CHARS: usize = 280;
+
+impl<R: BufRead, W: Write> ReviewSession<R, W> {
+    pub fn new(repository: Arc<dyn NewsRepository>, input: R, output: W) -> Self {
+        Self {
+            repository,
+            input,
+            output,
+            domains: ClassificationConfig::default().domain_names(),
+        }
+    }
+
+    /// 使用配置中的领域（默认为内置的 AI、Block、Social）
+    pub fn with_domains(mut self, domains: Vec<Domain>) -> Self {
+        self.domains = domains;
+        self
+    }
+
+    /// 审核匹配 `query` 的待复核新闻（`query` 中的状态过滤会被替换为 `NeedsReview`）
+    ///
+    /// 输入结束（EOF）或输入 `q` 时提前结束，已审核的结果都已保存。
+    pub async fn run(
+        &mut self,
+        query: &NewsQuery,
+    ) -> Result<ReviewSummary, Box<dyn std::error::Error + Send + Sync>> {
+        let query = query
+            .clone()
+            .with_statuses(vec![NewsItemStatus::NeedsReview]);
+        let queue = self.repository.find(&query).await?;
+        let mut summary = ReviewSummary::default();
+
+        if queue.is_empty() {
+            writeln!(self.output, "✅ 没有待复核的新闻")?;
+            return Ok(summary);
+        }
+
+        let total = queue.len();
+        for (i, mut news) in queue.into_iter().enumerate() {
+            self.show(i + 1, total, &news)?;
+
+            let Some(command) = self.prompt()? else {
+                break;
+            };
+            let label = match command {
+                ReviewCommand::Quit => break,
+                ReviewCommand::Skip => {
+                    summary.skipped += 1;
+                    continue;
+                }
+                ReviewCommand::Accept => ReviewLabel::accept(&news),
+                ReviewCommand::Relabel(domain) => ReviewLabel::relabel(&news, domain),
+                ReviewCommand::Irrelevant => ReviewLabel::irrelevant(&news),
+            };
+
+            // 没有预测领域时无法"确认"，要求审核者明确给出领域
+            if label.domain.is_none() && command == ReviewCommand::Accept {
+                writeln!(self.output, "⚠️  该新闻没有预测领域，已跳过")?;
+                summary.skipped += 1;
+                continue;
+            }
+
+            label.apply_to(&mut news);
+            self.repository.save_review(&news, &label).await?;
+            match label.decision {
+                ReviewDecision::Accepted => summary.accepted += 1,
+                ReviewDecision::Relabeled => summary.relabeled += 1,
+                ReviewDecision::Irrelevant => summary.irrelevant += 1,
+            }
+        }
+
+        Ok(summary)
+    }
+
+    fn show(&mut self, index: usize, total: usize, news: &NewsItem) -> std::io::Result<()> {
+        let out = &mut self.output;
+        writeln!(out)?;
+        writeln!(out, "[{}/{}] {}", index, total, news.title)?;
+        writeln!(out, "  来源: {}  作者: {}", news.source, news.author)?;
+        writeln!(out, "  链接: {}", news.url)?;
+        let domain = news
+            .domain
+            .map_or_else(|| "无领域".to_string(), |d| d.to_string());
+        match news.classification_confidence {
+            Some(confidence) => writeln!(out, "  预测: {} ({:.2})", domain, confidence)?,
+            None => writeln!(out, "  预测: {}", domain)?,
+        }
+        if let Some(reason) = &news.classification_reason {
+            writeln!(out, "  依据: {}", reason)?;
+        }
+        if let Some(trace) = &news.classification_trace {
+            writeln!(out, "  分类轨迹:")?;
+            for line in orchestration::format_trace_stages(trace) {
+                writeln!(out, "  {}", line)?;
+            }
+        }
+        if let Some(content) = &news.content {
+            let preview: String = content.chars().take(CONTENT_PREVIEW_CHARS).collect();
+            let ellipsis = if content.chars().count() > CONTENT_PREVIEW_CHARS {
+                "…"
+            } else {
+                ""
+            };
+            writeln!(out, "  正文: {}{}", preview.trim(), ellipsis)?;
+        }
+        Ok(())
+    }
+
+    /// 读取一条有效操作；输入结束时返回 `None`
+    fn prompt(&mut self) -> std::io::Result<Option<ReviewCommand>> {
+        let domains: Vec<String> = self
+            .domains
+            .iter()
+            .map(|d| d.display_name().to_ascii_lowercase())
+            .collect();
+        loop {
+            write!(
+                self.output,
+                "  [a]确认 / [{}]改标 / [i]无关 / [s]跳过 / [q]退出 > ",
+                domains.join("|")
+            )?;
+            self.output.flush()?;
+
+            let mut line = String::new();
+            if self.input.read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+            match ReviewCommand::parse(&line, &self.domains) {
+                Ok(command) => return Ok(Some(command)),
+                Err(e) => writeln!(self.output, "  {}", e)?,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{ClassificationTrace, StageStatus, StageTrace};
+    use crate::infrastructure::database::create_pool;
+    use crate::infrastructure::repositories::SqliteNewsRepository;
+    use chrono::Utc;
+
+    async fn setup() -> Arc<dyn NewsRepository> {
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        let repository: Arc<dyn NewsRepository> = Arc::new(SqliteNewsRepository::new(pool));
+
+        let mut items: Vec<NewsItem> = (1..=4)
+            .map(|i| {
+                let mut news = NewsItem::new_with_classification(
+                    i.to_string(),
+                    format!("Story {}", i),
+                    format!("https://example.com/{}", i),
+                    "hackernews".to_string(),
+                    "author".to_string(),
+                    Utc::now() - chrono::Duration::minutes(i),
+                    Domain::new("AI"),
+                    0.4,
+                );
+                news.status = NewsItemStatus::NeedsReview;
+                news
+            })
+            .collect();
+        let mut trace = ClassificationTrace::new();
+        trace.push(StageTrace {
+            stage: "FastPass".to_string(),
+            status: StageStatus::Candidate,
+            domain: Some(Domain::new("AI")),
+            secondary_domains: Vec::new(),
+            confidence: Some(0.4),
+            detail: "AI score 0.30: 'llm' in title (weak)".to_string(),
+            error: None,
+            elapsed_ms: 0,
+        });
+        items[0].classification_trace = Some(trace);
+        repository.save_batch(&items).await.unwrap();
+        repository
+    }
+
+    #[test]
+    fn test_parse_review_command() {
+        let domains = [Domain::new("AI"), Domain::new("Block")];
+        let parse = |s| ReviewCommand::parse(s, &domains);
+        assert_eq!(parse("a"), Ok(ReviewCommand::Accept));
+        assert_eq!(
+            parse(" Block\n"),
+            Ok(ReviewCommand::Relabel(Domain::new("Block")))
+        );
+        assert_eq!(parse("\n"), Ok(ReviewCommand::Skip));
+        assert!(parse("x").is_err());
+        // 未配置的领域不能用于改标
+        assert!(parse("social").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_review_session_persists_labels() {
+        let repository = setup().await;
+        // 无效输入会重新提示；第 4 条之前退出
+        let input = b"a\nnope\nblock\ni\nq\n".as_slice();
+        let mut output = Vec::new();
+        let mut session = ReviewSession::new(Arc::clone(&repository), input, &mut output);
+
+        let summary = session.run(&NewsQuery::new(10)).await.unwrap();
+
+        assert_eq!(
+            summary,
+            ReviewSummary {
+                accepted: 1,
+                relabeled: 1,
+                irrelevant: 1,
+                skipped: 0,
+            }
+        );
+        let transcript = String::from_utf8(output).unwrap();
+        assert!(transcript.contains("未知操作: nope"), "{}", transcript);
+        // 分类轨迹与 show 命令格式一致
+        assert!(transcript.contains("分类轨迹:"), "{}", transcript);
+        assert!(
+            transcript.contains("'llm' in title (weak)"),
+            "{}",
+            transcript
+        );
+
+        let accepted = repository.find_by_id("1").await.unwrap().unwrap();
+        assert_eq!(accepted.domain, Some(Domain::new("AI")));
+        assert_eq!(accepted.status, NewsItemStatus::Completed);
+        let relabeled = repository.find_by_id("2").await.unwrap().unwrap();
+        assert_eq!(relabeled.domain, Some(Domain::new("Block")));
+        let irrelevant = repository.find_by_id("3").await.unwrap().unwrap();
+        assert_eq!(irrelevant.status, NewsItemStatus::Irrelevant);
+        let untouched = repository.find_by_id("4").await.unwrap().unwrap();
+        assert_eq!(untouched.status, NewsItemStatus::NeedsReview);
+
+        // 已审核的新闻不再出现在队列中
+        let remaining = repository
+            .find(&NewsQuery::new(10).with_statuses(vec![NewsItemStatus::NeedsReview]))
+            .await
+            .unwrap();
+        assert_eq!(remaining.len(), 1);
+    }
+}
@@ -0,0 +1,664 @@
+use crate::domain::config::ClassificationConfig;
+use crate::domain::services::{
+    ContentExtractor, ExtractedContent, InferenceResult, NewsInferenceService,
+};
+use crate::domain::strategies::source_based_strategy::SourceBasedStrategy;
+use crate::domain::{
+    ClassificationStrategy, ClassificationTrace, Domain, DomainLabel, KeywordBasedStrategy,
+    NewsClassificationService, NewsItem,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use clap::ValueEnum;
+use serde::{Deserialize, Deserializer};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::info;
+
+/// 在标注数据集上评估分类效果的用例
+///
+/// **职责**：
+/// - 读取 JSONL 标注数据集（每行一条：标题、URL、正文、期望领域）
+/// - 用完整分类流程或单个分类策略预测每条样本的领域
+/// - 汇总每个领域的精确率 / 召回率 / F1、混淆矩阵、各阶段命中率和平均耗时
+///
+/// **离线运行**：正文由数据集提供，不会抓取 URL；AI 仲裁可以使用数据集中记录的
+/// 模型响应回放（`ReplayInferenceService`），从而离线比较不同配置和模型。
+pub struct EvaluateClassificationService {
+    config: ClassificationConfig,
+    strategy: EvalStrategy,
+    inference: Option<Arc<dyn NewsInferenceService>>,
+}
+
+/// 被评估的分类方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum EvalStrategy {
+    /// 完整的五阶漏斗分类流程（`NewsClassificationService`）
+    #[default]
+    Pipeline,
+    /// 仅关键词策略
+    Keyword,
+    /// 仅来源倾向策略
+    Source,
+}
+
+/// 数据集中的一条标注样本
+#[derive(Debug, Clone, Deserialize)]
+pub struct LabeledExample {
+    pub title: String,
+    pub url: String,
+    #[serde(default = "LabeledExample::default_source")]
+    pub source: String,
+    #[serde(default)]
+    pub content: Option<String>,
+    /// 期望领域，`null` / `"none"` 表示无关新闻
+    #[serde(deserialize_with = "deserialize_expected_domain")]
+    pub expected_domain: Option<Domain>,
+    /// 记录下来的模型响应，供 `ReplayInferenceService` 回放
+    #[serde(default)]
+    pub inference: Option<RecordedInference>,
+}
+
+/// 一次记录下来的模型响应
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecordedInference {
+    #[serde(default, deserialize_with = "deserialize_expected_domain")]
+    pub domain: Option<Domain>,
+    #[serde(default)]
+    pub secondary_domains: Vec<DomainLabel>,
+    #[serde(default)]
+    pub confidence: f32,
+    #[serde(default)]
+    pub reason: String,
+}
+
+impl LabeledExample {
+    fn default_source() -> String {
+        "dataset".to_string()
+    }
+
+    fn to_news_item(&self, index: usize) -> NewsItem {
+        NewsItem::new(
+            format!("eval-{}", index),
+            self.title.clone(),
+            self.url.clone(),
+            self.source.clone(),
+            "dataset".to_string(),
+            Utc::now(),
+        )
+    }
+}
+
+fn deserialize_expected_domain<'de, D>(deserializer: D) -> Result<Option<Domain>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value: Option<String> = Option::deserialize(deserializer)?;
+    match value.as_deref().map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(s) if s.eq_ignore_ascii_case("none") || s.eq_ignore_ascii_case("irrelevant") => {
+            Ok(None)
+        }
+        Some(s) => Ok(Some(Domain::new(s))),
+    }
+}
+
+/// 读取 JSONL 标注数据集（忽略空行）
+pub fn load_dataset<P: AsRef<Path>>(
+    path: P,
+) -> Result<Vec<LabeledExample>, Box<dyn std::error::Error + Send + Sync>> {
+    let path = path.as_ref();
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("无法读取数据集 {}: {}", path.display(), e))?;
+    parse_dataset(&content)
+}
+
+fn parse_dataset(
+    content: &str,
+) -> Result<Vec<LabeledExample>, Box<dyn std::error::Error + Send + Sync>> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line)
+                .map_err(|e| format!("数据集第 {} 行无效: {}", i + 1, e).into())
+        })
+        .collect()
+}
+
+/// 分类结果来自哪个阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ClassificationStage {
+    /// 命中全局排除规则
+    Excluded,
+    /// 第一阶段：标题 / URL 强关键词
+    FastPass,
+    /// 来源倾向（`source_tendency`）
+    SourceTendency,
+    /// 第三阶段：全文关键词
+    FullContentScan,
+    /// 第四阶段：AI 仲裁
+    AI,
+    /// 第五阶段：弱匹配兜底
+    WeakMatchFallback,
+    /// 单个策略命中（`keyword` / `source` 评估方式）
+    Strategy,
+    /// 无法分类
+    Unclassified,
+}
+
+impl ClassificationStage {
+    /// 根据分类轨迹中做出决定的阶段判断（没有阶段做出决定时为 `Unclassified`）
+    pub fn from_trace(trace: Option<&ClassificationTrace>) -> Self {
+        let decided = trace.and_then(|t| t.decided_by());
+        match decided.map(|s| s.stage.as_str()) {
+            Some("Excluded") => ClassificationStage::Excluded,
+            Some("FastPass") => ClassificationStage::FastPass,
+            Some("SourceTendency") => ClassificationStage::SourceTendency,
+            Some("FullContentScan") => ClassificationStage::FullContentScan,
+            Some("AI") => ClassificationStage::AI,
+            Some("WeakMatchFallback") => ClassificationStage::WeakMatchFallback,
+            _ => ClassificationStage::Unclassified,
+        }
+    }
+}
+
+/// 一条样本的预测结果
+#[derive(Debug, Clone)]
+pub struct Prediction {
+    pub title: String,
+    pub expected: Option<Domain>,
+    pub predicted: Option<Domain>,
+    pub stage: ClassificationStage,
+    pub latency: Duration,
+}
+
+impl Prediction {
+    pub fn is_correct(&self) -> bool {
+        self.expected == self.predicted
+    }
+}
+
+/// 单个领域的评估指标
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DomainMetrics {
+    pub precision: f64,
+    pub recall: f64,
+    pub f1: f64,
+    /// 期望为该领域的样本数
+    pub support: usize,
+}
+
+/// 单个阶段的命中情况
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StageStats {
+    pub stage: ClassificationStage,
+    /// 由该阶段给出结果的样本数
+    pub count: usize,
+    /// 其中预测正确的样本数
+    pub correct: usize,
+}
+
+/// 评估结果
+#[derive(Debug, Clone, Default)]
+pub struct EvaluationReport {
+    /// 配置中的领域（混淆矩阵和各领域指标按此顺序展示）
+    pub domains: Vec<Domain>,
+    pub predictions: Vec<Prediction>,
+}
+
+impl EvaluationReport {
+    /// 混淆矩阵的行列顺序：配置中的领域、数据集中标注但未配置的领域，最后是无关（`None`）
+    pub fn labels(&self) -> Vec<Option<Domain>> {
+        self.domains
+            .iter()
+            .copied()
+            .chain(self.unconfigured_domains())
+            .map(Some)
+            .chain([None])
+            .collect()
+    }
+
+    /// 数据集中标注了、但配置中没有的领域（分类器不可能预测出这些领域），按名称排序
+    pub fn unconfigured_domains(&self) -> Vec<Domain> {
+        let mut domains: Vec<Domain> = self
+            .predictions
+            .iter()
+            .filter_map(|p| p.expected)
+            .filter(|domain| !self.domains.contains(domain))
+            .collect();
+        domains.sort();
+        domains.dedup();
+        domains
+    }
+
+    pub fn total(&self) -> usize {
+        self.predictions.len()
+    }
+
+    /// 准确率（包括无关样本）
+    pub fn accuracy(&self) -> f64 {
+        ratio(
+            self.predictions.iter().filter(|p| p.is_correct()).count(),
+            self.total(),
+        )
+    }
+
+    /// 期望为 `expected`、预测为 `predicted` 的样本数
+    pub fn confusion(&self, expected: Option<Domain>, predicted: Option<Domain>) -> usize {
+        self.predictions
+            .iter()
+            .filter(|p| p.expected == expected && p.predicted == predicted)
+            .count()
+    }
+
+    /// 某个领域的精确率、召回率和 F1
+    pub fn domain_metrics(&self, domain: Domain) -> DomainMetrics {
+        let label = Some(domain);
+        let true_positive = self.confusion(label, label);
+        let predicted = self
+            .predictions
+            .iter()
+            .filter(|p| p.predicted == label)
+            .count();
+        let support = self
+            .predictions
+            .iter()
+            .filter(|p| p.expected == label)
+            .count();
+
+        let precision = ratio(true_positive, predicted);
+        let recall = ratio(true_positive, support);
+        let f1 = if precision + recall > 0.0 {
+            2.0 * precision * recall / (precision + recall)
+        } else {
+            0.0
+        };
+        DomainMetrics {
+            precision,
+            recall,
+            f1,
+            support,
+        }
+    }
+
+    /// 各阶段的命中情况，按阶段顺序排列（不包含没有样本的阶段）
+    pub fn stage_stats(&self) -> Vec<StageStats> {
+        let mut stats: HashMap<ClassificationStage, StageStats> = HashMap::new();
+        for p in &self.predictions {
+            let entry = stats.entry(p.stage).or_insert(StageStats {
+                stage: p.stage,
+                count: 0,
+                correct: 0,
+            });
+            entry.count += 1;
+            if p.is_correct() {
+                entry.correct += 1;
+            }
+        }
+        let mut stats: Vec<StageStats> = stats.into_values().collect();
+        stats.sort_by_key(|s| s.stage);
+        stats
+    }
+
+    /// 平均每条样本的分类耗时
+    pub fn average_latency(&self) -> Duration {
+        if self.predictions.is_empty() {
+            return Duration::ZERO;
+        }
+        let total: Duration = self.predictions.iter().map(|p| p.latency).sum();
+        total / self.predictions.len() as u32
+    }
+}
+
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+impl EvaluateClassificationService {
+    pub fn new(config: ClassificationConfig, strategy: EvalStrategy) -> Self {
+        Self {
+            config,
+            strategy,
+            inference: None,
+        }
+    }
+
+    /// 完整流程中使用的 AI 仲裁服务（不设置则跳过 AI 阶段）
+    pub fn with_inference_service(mut self, service: Arc<dyn NewsInferenceService>) -> Self {
+        self.inference = Some(service);
+        self
+    }
+
+    /// 逐条分类并汇总评估结果
+    pub async fn execute(&self, examples: &[LabeledExample]) -> EvaluationReport {
+        let mut report = EvaluationReport {
+            domains: self.config.domain_names(),
+            ..EvaluationReport::default()
+        };
+        let classifier = self.pipeline(examples);
+        // 单独评估的策略只构建一次，不计入每条新闻的耗时
+        let strategy: Option<Box<dyn ClassificationStrategy>> = match self.strategy {
+            EvalStrategy::Pipeline => None,
+            EvalStrategy::Keyword => Some(Box::new(KeywordBasedStrategy::from_config(
+                self.config.clone(),
+            ))),
+            EvalStrategy::Source => Some(Box::new(SourceBasedStrategy::from_config(
+                self.config.clone(),
+            ))),
+        };
+
+        for (i, example) in examples.iter().enumerate() {
+            let started = Instant::now();
+            let (predicted, stage) = match strategy {
+                Some(ref strategy) => self.run_strategy(strategy.as_ref(), example, i),
+                None => {
+                    let mut items = [example.to_news_item(i)];
+                    classifier.classify_batch(&mut items).await;
+                    let [news] = items;
+                    let stage = ClassificationStage::from_trace(news.classification_trace.as_ref());
+                    (news.domain, stage)
+                }
+            };
+
+            report.predictions.push(Prediction {
+                title: example.title.clone(),
+                expected: example.expected_domain,
+                predicted,
+                stage,
+                latency: started.elapsed(),
+            });
+            if (i + 1) % 50 == 0 {
+                info!("🧪 已评估 {}/{} 条", i + 1, examples.len());
+            }
+        }
+
+        report
+    }
+
+    /// 构建离线运行的完整分类流程（正文来自数据集）
+    fn pipeline(&self, examples: &[LabeledExample]) -> NewsClassificationService {
+        let mut classifier = NewsClassificationService::new()
+            .with_config(self.config.clone())
+            .with_content_extractor(Arc::new(DatasetContentExtractor::new(examples)));
+        if let Some(ai) = &self.inference {
+            classifier = classifier.with_inference_service(Arc::clone(ai));
+        }
+        classifier
+    }
+
+    fn run_strategy(
+        &self,
+        strategy: &dyn ClassificationStrategy,
+        example: &LabeledExample,
+        index: usize,
+    ) -> (Option<Domain>, ClassificationStage) {
+        let mut news = example.to_news_item(index);
+        news.content = example.content.clone();
+        match strategy.classify(&news) {
+            Some(result) => (Some(result.domain), ClassificationStage::Strategy),
+            None => (None, ClassificationStage::Unclassified),
+        }
+    }
+}
+
+/// 从数据集中读取正文的提取器（不访问网络）
+struct DatasetContentExtractor {
+    contents: HashMap<String, String>,
+}
+
+impl DatasetContentExtractor {
+    fn new(examples: &[LabeledExample]) -> Self {
+        let contents = examples
+            .iter()
+            .filter_map(|e| e.content.clone().map(|c| (e.url.clone(), c)))
+            .collect();
+        Self { contents }
+    }
+}
+
+#[async_trait]
+impl ContentExtractor for DatasetContentExtractor {
+    async fn extract(
+        &self,
+        url: &str,
+    ) -> Result<ExtractedContent, Box<dyn std::error::Error + Send + Sync>> {
+        let text = self
+            .contents
+            .get(url)
+            .ok_or_else(|| format!("数据集中没有正文: {}", url))?;
+        Ok(ExtractedContent {
+            title: String::new(),
+            text: text.clone(),
+            source_url: url.to_string(),
+        })
+    }
+
+    fn name(&self) -> &str {
+        "dataset"
+    }
+}
+
+/// 回放数据集中记录的模型响应的推理服务
+///
+/// 没有记录响应的样本返回错误，分类流程会进入兜底阶段（与 AI 调用失败时相同）。
+pub struct ReplayInferenceService {
+    responses: HashMap<String, RecordedInference>,
+}
+
+impl ReplayInferenceService {
+    pub fn from_dataset(examples: &[LabeledExample]) -> Self {
+        let responses = examples
+            .iter()
+            .filter_map(|e| e.inference.clone().map(|r| (e.url.clone(), r)))
+            .collect();
+        Self { responses }
+    }
+}
+
+#[async_trait]
+impl NewsInferenceService for ReplayInferenceService {
+    async fn infer(
+        &self,
+        news: &NewsItem,
+    ) -> Result<InferenceResult, Box<dyn std::error::Error + Send + Sync>> {
+        let recorded = self
+            .responses
+            .get(&news.url)
+            .ok_or_else(|| format!("数据集中没有记录的模型响应: {}", news.url))?;
+        Ok(InferenceResult {
+            is_relevant: recorded.domain.is_some(),
+            domain: recorded.domain,
+            secondary_domains: recorded.secondary_domains.clone(),
+            confidence: recorded.confidence,
+            reason: recorded.reason.clone(),
+            suggested_keywords: Vec::new(),
+        })
+    }
+
+    fn name(&self) -> &str {
+        "replay"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::config::ExcludePattern;
+    use crate::domain::{StageStatus, StageTrace};
+
+    const DATASET: &str = r#"
+{"title": "OpenAI ships GPT-4 update", "url": "https://example.com/1", "expected_domain": "AI"}
+{"title": "Quarterly results", "url": "https://example.com/2", "content": "The bitcoin treasury grew.", "expected_domain": "block"}
+{"title": "A new cafe opens downtown", "url": "https://example.com/3", "expected_domain": null}
+{"title": "The future of tech", "url": "https://example.com/4", "expected_domain": "Social", "inference": {"domain": "Social", "confidence": 0.9, "reason": "platform news"}}
+"#;
+
+    fn prediction(expected: Option<Domain>, predicted: Option<Domain>) -> Prediction {
+        Prediction {
+            title: String::new(),
+            expected,
+            predicted,
+            stage: ClassificationStage::Unclassified,
+            latency: Duration::from_millis(10),
+        }
+    }
+
+    #[test]
+    fn test_parse_dataset() {
+        let examples = parse_dataset(DATASET).unwrap();
+        assert_eq!(examples.len(), 4);
+        assert_eq!(examples[1].expected_domain, Some(Domain::new("Block")));
+        assert_eq!(examples[2].expected_domain, None);
+        assert_eq!(examples[0].source, "dataset");
+
+        // 领域由配置决定，数据集可以标注任意领域名称
+        let custom =
+            parse_dataset("{\"title\": \"x\", \"url\": \"u\", \"expected_domain\": \"Sports\"}")
+                .unwrap();
+        assert_eq!(custom[0].expected_domain, Some(Domain::new("sports")));
+
+        let err = parse_dataset("{\"title\": \"x\", \"expected_domain\": \"AI\"}").unwrap_err();
+        assert!(err.to_string().contains("第 1 行"), "{}", err);
+    }
+
+    #[test]
+    fn test_metrics_and_confusion() {
+        let report = EvaluationReport {
+            predictions: vec![
+                prediction(Some(Domain::new("AI")), Some(Domain::new("AI"))),
+                prediction(Some(Domain::new("AI")), Some(Domain::new("Block"))),
+                prediction(Some(Domain::new("Block")), Some(Domain::new("Block"))),
+                prediction(None, Some(Domain::new("AI"))),
+            ],
+            ..EvaluationReport::default()
+        };
+
+        assert_eq!(report.accuracy(), 0.5);
+        assert_eq!(
+            report.confusion(Some(Domain::new("AI")), Some(Domain::new("Block"))),
+            1
+        );
+        let ai = report.domain_metrics(Domain::new("AI"));
+        assert_eq!(ai.support, 2);
+        assert_eq!(ai.precision, 0.5);
+        assert_eq!(ai.recall, 0.5);
+        assert_eq!(ai.f1, 0.5);
+        let social = report.domain_metrics(Domain::new("Social"));
+        assert_eq!(social.f1, 0.0);
+        assert_eq!(report.average_latency(), Duration::from_millis(10));
+    }
+
+    #[test]
+    fn test_labels_include_unconfigured_dataset_domains() {
+        let report = EvaluationReport {
+            domains: vec![Domain::new("AI"), Domain::new("Block")],
+            predictions: vec![
+                prediction(Some(Domain::new("Sports")), None),
+                prediction(Some(Domain::new("AI")), Some(Domain::new("AI"))),
+                prediction(Some(Domain::new("Sports")), Some(Domain::new("AI"))),
+            ],
+        };
+
+        assert_eq!(report.unconfigured_domains(), vec![Domain::new("Sports")]);
+        assert_eq!(
+            report.labels(),
+            vec![
+                Some(Domain::new("AI")),
+                Some(Domain::new("Block")),
+                Some(Domain::new("Sports")),
+                None,
+            ]
+        );
+        // 未配置领域的样本在混淆矩阵中有自己的一行
+        assert_eq!(
+            report.confusion(Some(Domain::new("Sports")), Some(Domain::new("AI"))),
+            1
+        );
+    }
+
+    #[test]
+    fn test_stage_from_trace() {
+        fn stage(name: &str, status: StageStatus) -> StageTrace {
+            StageTrace {
+                stage: name.to_string(),
+                status,
+                domain: None,
+                secondary_domains: Vec::new(),
+                confidence: None,
+                detail: String::new(),
+                error: None,
+                elapsed_ms: 0,
+            }
+        }
+
+        let mut trace = ClassificationTrace::new();
+        trace.push(stage("FastPass", StageStatus::Candidate));
+        trace.push(stage("AI", StageStatus::Decided));
+        assert_eq!(
+            ClassificationStage::from_trace(Some(&trace)),
+            ClassificationStage::AI
+        );
+
+        let mut undecided = ClassificationTrace::new();
+        undecided.push(stage("WeakMatchFallback", StageStatus::Completed));
+        assert_eq!(
+            ClassificationStage::from_trace(Some(&undecided)),
+            ClassificationStage::Unclassified
+        );
+        assert_eq!(
+            ClassificationStage::from_trace(None),
+            ClassificationStage::Unclassified
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pipeline_runs_offline_with_replayed_inference() {
+        let examples = parse_dataset(DATASET).unwrap();
+        let service = EvaluateClassificationService::new(
+            ClassificationConfig::default(),
+            EvalStrategy::Pipeline,
+        )
+        .with_inference_service(Arc::new(ReplayInferenceService::from_dataset(&examples)));
+
+        let report = service.execute(&examples).await;
+
+        let stages: Vec<ClassificationStage> = report.predictions.iter().map(|p| p.stage).collect();
+        assert_eq!(
+            stages,
+            vec![
+                ClassificationStage::FastPass,
+                ClassificationStage::FullContentScan,
+                ClassificationStage::Unclassified,
+                ClassificationStage::AI,
+            ]
+        );
+        assert_eq!(report.accuracy(), 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_excluded_news_skips_all_stages() {
+        let examples = parse_dataset(
+            r#"{"title": "OpenAI ships GPT-4 update", "url": "https://promo.spam.example/1", "expected_domain": null, "inference": {"domain": "AI", "confidence": 0.9, "reason": "llm news"}}"#,
+        )
+        .unwrap();
+        let config = ClassificationConfig {
+            exclude_patterns: vec![ExcludePattern::Host("spam.example".to_string())],
+            ..ClassificationConfig::default()
+        };
+        let service = EvaluateClassificationService::new(config, EvalStrategy::Pipeline)
+            .with_inference_service(Arc::new(ReplayInferenceService::from_dataset(&examples)));
+
+        let report = service.execute(&examples).await;
+
+        assert_eq!(report.predictions[0].stage, ClassificationStage::Excluded);
+        assert_eq!(report.predictions[0].predicted, None);
+    }
+}
@@ -1,8 +1,9 @@
 use crate::domain::{
     NewsClassificationService, NewsDeduplicationService, NewsFetcher, NewsItem, NewsRepository,
-    NewsSortingService,
+    NewsSortingService, SortOrder, StoryClusteringService, StorySnapshot,
 };
 use async_trait::async_trait;
+use chrono::{Duration, Utc};
 use std::sync::Arc;
 
 /// 获取热点新闻用例
@@ -10,8 +11,12 @@ use std::sync::Arc;
 /// **职责**：
 /// - 编排"获取热点新闻"这个业务流程
 /// - 依赖 `NewsFetcher` 接口，不关心具体实现
-/// - 对获取的新闻进行去重、排序
+/// - 丢弃命中全局排除规则（`exclude_patterns`）的新闻，无论是否执行分类
+/// - 对获取的新闻进行去重、排序（排序方式可通过 `with_sort_order()` 指定，默认按时间）
+/// - 同一事件的不同报道（规范化 URL 相同或标题近似）归入同一个聚类，而不是丢弃，
+///   同时与最近入库的新闻比较（相似度阈值可通过 `with_title_similarity()` 指定）
 /// - 可选地保存到数据库（通过 Repository）
+/// - 已存在于数据库的新闻不再重复处理，而是记录一条热度快照（分数、评论数、排名）
 ///
 /// **为什么在 Application 层而不是 Domain 层？**
 /// - 这是一个"用例"，是应用级别的流程编排
@@ -38,14 +43,21 @@ pub struct FetchHotNewsService<'a> {
     fetcher: &'a dyn NewsFetcher,
     classifier: Arc<NewsClassificationService>,
     repository: Option<Arc<dyn NewsRepository>>,
+    sort_order: SortOrder,
+    title_similarity: f32,
 }
 
+/// 新闻聚类时参与比较的最近入库新闻数量
+const RECENT_STORED_FOR_CLUSTERING: usize = 200;
+
 impl<'a> FetchHotNewsService<'a> {
     pub fn new(fetcher: &'a dyn NewsFetcher, classifier: Arc<NewsClassificationService>) -> Self {
         Self {
             fetcher,
             classifier,
             repository: None,
+            sort_order: SortOrder::default(),
+            title_similarity: StoryClusteringService::DEFAULT_TITLE_SIMILARITY,
         }
     }
 
@@ -60,6 +72,18 @@ impl<'a> FetchHotNewsService<'a> {
         self.repository = Some(repository);
         self
     }
+
+    /// 设置排序方式（默认按发布时间，最新在前）
+    pub fn with_sort_order(mut self, sort_order: SortOrder) -> Self {
+        self.sort_order = sort_order;
+        self
+    }
+
+    /// 设置新闻聚类的标题相似度阈值（0.0 - 1.0，默认 0.6）
+    pub fn with_title_similarity(mut self, threshold: f32) -> Self {
+        self.title_similarity = threshold;
+        self
+    }
 }
 
 use tracing::info;
@@ -86,34 +110,11 @@ impl<'a> FetchHotNewsService<'a> {
             self.fetcher.source_name()
         );
 
-        // 1. 获取数据
-        let news = self.fetcher.fetch(limit).await?;
-        info!("📰 抓取到 {} 条原始新闻", news.len());
+        // 1. 获取数据，丢弃命中全局排除规则的新闻
+        let news = self.fetch_without_excluded(limit).await?;
 
-        // 2. 过滤掉数据库中已存在的新闻（优化：批量检查）
-        let (filtered_news, skipped_count) = if let Some(ref repo) = self.repository {
-            // 提取所有 URL
-            let urls: Vec<String> = news.iter().map(|n| n.url.clone()).collect();
-
-            // 批量查询已存在的 URL
-            let existing_urls = repo.find_existing_urls(&urls).await?;
-
-            // 过滤掉已存在的新闻
-            let filtered: Vec<NewsItem> = news
-                .into_iter()
-                .filter(|n| !existing_urls.contains(&n.url))
-                .collect();
-
-            let skipped = urls.len() - filtered.len();
-
-            if skipped > 0 {
-                info!("⏭️  忽略 {} 条已存在于数据库的新闻", skipped);
-            }
-
-            (filtered, skipped)
-        } else {
-            (news, 0)
-        };
+        // 2. 过滤掉数据库中已存在的新闻，并为其记录热度快照
+        let (filtered_news, skipped_count) = self.split_new_and_record_snapshots(news).await?;
 
         if filtered_news.is_empty() {
             info!("✅ 没有新新闻需要处理");
@@ -132,21 +133,24 @@ impl<'a> FetchHotNewsService<'a> {
             );
         }
 
-        // 4. 排序（按时间，最新的在前）
-        let sorted_news = NewsSortingService::sort_by_published_at_desc(unique_news);
+        let unique_news = self.assign_story_clusters(unique_news).await?;
 
-        // 5. 分类新闻并过滤掉无关项
-        let mut news_items = sorted_news;
+        // 4. 分类新闻并过滤掉无关项
+        let mut news_items = unique_news;
         self.classifier
             .classify_batch_and_filter(&mut news_items)
             .await;
 
+        // 5. 排序（按配置的排序方式；按置信度排序依赖分类结果，必须在分类之后）
+        let news_items = NewsSortingService::sort(news_items, self.sort_order);
+
         // 6. 保存到数据库（如果提供了 Repository）
         if let Some(ref repo) = self.repository
             && !news_items.is_empty()
         {
             info!("💾 保存 {} 条新新闻到数据库...", news_items.len());
             repo.save_batch(&news_items).await?;
+            Self::record_initial_snapshots(repo.as_ref(), &news_items).await?;
             info!("✅ 保存完成！");
         }
 
@@ -169,34 +173,11 @@ impl<'a> FetchHotNewsService<'a> {
             self.fetcher.source_name()
         );
 
-        // 1. 获取数据
-        let news = self.fetcher.fetch(limit).await?;
-        info!("📰 抓取到 {} 条原始新闻", news.len());
-
-        // 2. 过滤掉数据库中已存在的新闻（优化：批量检查）
-        let (filtered_news, skipped_count) = if let Some(ref repo) = self.repository {
-            // 提取所有 URL
-            let urls: Vec<String> = news.iter().map(|n| n.url.clone()).collect();
-
-            // 批量查询已存在的 URL
-            let existing_urls = repo.find_existing_urls(&urls).await?;
+        // 1. 获取数据，丢弃命中全局排除规则的新闻
+        let news = self.fetch_without_excluded(limit).await?;
 
-            // 过滤掉已存在的新闻
-            let filtered: Vec<NewsItem> = news
-                .into_iter()
-                .filter(|n| !existing_urls.contains(&n.url))
-                .collect();
-
-            let skipped = urls.len() - filtered.len();
-
-            if skipped > 0 {
-                info!("⏭️  忽略 {} 条已存在于数据库的新闻", skipped);
-            }
-
-            (filtered, skipped)
-        } else {
-            (news, 0)
-        };
+        // 2. 过滤掉数据库中已存在的新闻，并为其记录热度快照
+        let (filtered_news, skipped_count) = self.split_new_and_record_snapshots(news).await?;
 
         if filtered_news.is_empty() {
             info!("✅ 没有新新闻需要处理");
@@ -215,8 +196,10 @@ impl<'a> FetchHotNewsService<'a> {
             );
         }
 
-        // 4. 排序（按时间，最新的在前）
-        let sorted_news = NewsSortingService::sort_by_published_at_desc(unique_news);
+        let unique_news = self.assign_story_clusters(unique_news).await?;
+
+        // 4. 排序（按配置的排序方式）
+        let sorted_news = NewsSortingService::sort(unique_news, self.sort_order);
 
         // 5. 不执行分类，所有新闻都保留
         let news_items = sorted_news;
@@ -227,6 +210,7 @@ impl<'a> FetchHotNewsService<'a> {
         {
             info!("💾 保存 {} 条新新闻到数据库...", news_items.len());
             repo.save_batch(&news_items).await?;
+            Self::record_initial_snapshots(repo.as_ref(), &news_items).await?;
             info!("✅ 保存完成！");
         }
 
@@ -238,4 +222,107 @@ impl<'a> FetchHotNewsService<'a> {
 
         Ok(news_items)
     }
+
+    /// 抓取新闻并立即丢弃命中全局排除规则的条目（在去重、正文抓取和入库之前）
+    async fn fetch_without_excluded(
+        &self,
+        limit: usize,
+    ) -> Result<Vec<NewsItem>, Box<dyn std::error::Error + Send + Sync>> {
+        let mut news = self.fetcher.fetch(limit).await?;
+        info!("📰 抓取到 {} 条原始新闻", news.len());
+
+        let excluded = self.classifier.drop_excluded(&mut news);
+        if excluded > 0 {
+            info!("🚫 排除规则过滤掉 {} 条新闻", excluded);
+        }
+        Ok(news)
+    }
+
+    /// 将抓取结果拆分为新新闻和已存在新闻
+    ///
+    /// 已存在的新闻会追加一条快照，用于计算热度增长速度。
+    /// 返回 (新新闻, 已存在数量)。
+    async fn split_new_and_record_snapshots(
+        &self,
+        news: Vec<NewsItem>,
+    ) -> Result<(Vec<NewsItem>, usize), Box<dyn std::error::Error + Send + Sync>> {
+        let Some(ref repo) = self.repository else {
+            return Ok((news, 0));
+        };
+
+        // 批量查询已存在的 URL 及其新闻 ID（按规范化 URL 匹配）
+        let urls: Vec<String> = news.iter().map(|n| n.url.clone()).collect();
+        let existing_ids = repo.find_ids_by_urls(&urls).await?;
+
+        let captured_at = Utc::now();
+        let mut snapshots = Vec::new();
+        let mut filtered = Vec::with_capacity(news.len());
+        for item in news {
+            match existing_ids.get(&item.url) {
+                Some(news_id) => {
+                    snapshots.push(StorySnapshot::capture(news_id.clone(), &item, captured_at))
+                }
+                None => filtered.push(item),
+            }
+        }
+
+        let skipped = urls.len() - filtered.len();
+        if skipped > 0 {
+            info!("⏭️  忽略 {} 条已存在于数据库的新闻", skipped);
+        }
+
+        if !snapshots.is_empty() {
+            repo.append_snapshots(&snapshots).await?;
+            info!("📈 记录 {} 条热度快照", snapshots.len());
+        }
+
+        Ok((filtered, skipped))
+    }
+
+    /// 为新闻分配聚类 ID：先与最近入库的新闻比较，再在本批次内比较
+    async fn assign_story_clusters(
+        &self,
+        mut news: Vec<NewsItem>,
+    ) -> Result<Vec<NewsItem>, Box<dyn std::error::Error + Send + Sync>> {
+        let recent = match self.repository {
+            Some(ref repo) if !news.is_empty() => {
+                repo.find_recent(RECENT_STORED_FOR_CLUSTERING).await?
+            }
+            _ => Vec::new(),
+        };
+
+        let clustering = StoryClusteringService::new(
+            self.title_similarity,
+            Duration::hours(StoryClusteringService::DEFAULT_WINDOW_HOURS),
+        );
+        clustering.assign_cluster_ids(&mut news, &recent);
+
+        let related = news
+            .iter()
+            .filter(|n| n.cluster_id.as_deref() != Some(n.id.as_str()))
+            .count();
+        if related > 0 {
+            info!("🔗 {} 条新闻归入已有报道的聚类", related);
+        }
+
+        Ok(news)
+    }
+
+    /// 为刚保存的新闻记录首条快照（仅限带热度数据的新闻）
+    async fn record_initial_snapshots(
+        repo: &dyn NewsRepository,
+        news_items: &[NewsItem],
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let captured_at = Utc::now();
+        let snapshots: Vec<StorySnapshot> = news_items
+            .iter()
+            .filter(|n| n.has_engagement())
+            .map(|n| StorySnapshot::capture(n.id.clone(), n, captured_at))
+            .collect();
+
+        if !snapshots.is_empty() {
+            repo.append_snapshots(&snapshots).await?;
+        }
+        Ok(())
+    }
 }
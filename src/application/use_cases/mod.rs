@@ -1 +1,3 @@
+pub mod evaluate_classification;
 pub mod fetch_hot_news;
+pub mod reclassify_news;
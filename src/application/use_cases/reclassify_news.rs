@@ -0,0 +1,309 @@
+use crate::domain::{
+    Domain, NewsClassificationService, NewsCursor, NewsItem, NewsItemStatus, NewsQuery,
+    NewsQueryOrder, NewsRepository,
+};
+use std::sync::Arc;
+use tracing::info;
+
+/// 重新分类已保存新闻的用例
+///
+/// **职责**：
+/// - 按 `NewsQuery` 的过滤条件从数据库分批加载新闻（游标分页，更新不会打乱翻页）
+/// - 跳过人工审核过的新闻，审核结果是评估用的标准答案，不能被分类器覆盖
+/// - 重新执行 `NewsClassificationService` 的分类流程
+/// - 原地更新领域、置信度、分类依据和状态，并返回变化明细
+///
+/// **使用场景**：修改 `config/classification.json` 或更换模型后，让旧数据使用新的分类结果。
+pub struct ReclassifyNewsService {
+    repository: Arc<dyn NewsRepository>,
+    classifier: Arc<NewsClassificationService>,
+    batch_size: usize,
+    dry_run: bool,
+}
+
+/// 一条新闻的分类结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassificationLabel {
+    pub domain: Option<Domain>,
+    /// 次要领域（不含置信度）
+    pub secondary_domains: Vec<Domain>,
+    pub confidence: Option<f32>,
+    pub reason: Option<String>,
+    pub status: NewsItemStatus,
+}
+
+impl ClassificationLabel {
+    fn of(news: &NewsItem) -> Self {
+        Self {
+            domain: news.domain,
+            secondary_domains: news.secondary_domains.iter().map(|l| l.domain).collect(),
+            confidence: news.classification_confidence,
+            reason: news.classification_reason.clone(),
+            status: news.status,
+        }
+    }
+
+    /// 领域（含次要领域）或状态是否不同（置信度和依据的变化不算）
+    pub fn differs_in_label(&self, other: &ClassificationLabel) -> bool {
+        self.domain != other.domain
+            || self.secondary_domains != other.secondary_domains
+            || self.status != other.status
+    }
+}
+
+/// 一条新闻分类结果的变化
+#[derive(Debug, Clone)]
+pub struct ClassificationChange {
+    pub news_id: String,
+    pub title: String,
+    pub before: ClassificationLabel,
+    pub after: ClassificationLabel,
+}
+
+/// 重新分类的结果汇总
+#[derive(Debug, Default)]
+pub struct ReclassifyReport {
+    /// 处理的新闻数量
+    pub scanned: usize,
+    /// 分类结果发生变化的新闻
+    pub changes: Vec<ClassificationChange>,
+}
+
+impl ReclassifyNewsService {
+    /// 每批加载和分类的新闻数量
+    pub const DEFAULT_BATCH_SIZE: usize = 50;
+
+    pub fn new(
+        repository: Arc<dyn NewsRepository>,
+        classifier: Arc<NewsClassificationService>,
+    ) -> Self {
+        Self {
+            repository,
+            classifier,
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+            dry_run: false,
+        }
+    }
+
+    /// 只计算变化，不写回数据库
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// 重新分类匹配 `query` 的新闻，最多处理 `query.limit` 条
+    ///
+    /// 忽略 `query` 中的排序和分页设置，总是按发布时间从新到旧处理；
+    /// 已有人工审核标签的新闻不会被处理。
+    pub async fn execute(
+        &self,
+        query: &NewsQuery,
+    ) -> Result<ReclassifyReport, Box<dyn std::error::Error + Send + Sync>> {
+        let mut report = ReclassifyReport::default();
+        let mut cursor: Option<NewsCursor> = None;
+
+        while report.scanned < query.limit {
+            let mut page = query
+                .clone()
+                .with_order(NewsQueryOrder::Newest)
+                .with_offset(0)
+                .without_reviewed();
+            page.limit = self.batch_size.min(query.limit - report.scanned);
+            page.cursor = cursor.take();
+
+            let mut batch = self.repository.find(&page).await?;
+            let Some(last) = batch.last() else {
+                break;
+            };
+            cursor = Some(NewsCursor::after(last));
+            let fetched = batch.len();
+
+            let before: Vec<ClassificationLabel> =
+                batch.iter().map(ClassificationLabel::of).collect();
+            self.classifier.classify_batch(&mut batch).await;
+
+            let mut updated = Vec::new();
+            for (news, before) in batch.into_iter().zip(before) {
+                let after = ClassificationLabel::of(&news);
+                if after != before {
+                    report.changes.push(ClassificationChange {
+                        news_id: news.id.clone(),
+                        title: news.title.clone(),
+                        before,
+                        after,
+                    });
+                    updated.push(news);
+                }
+            }
+
+            if !self.dry_run && !updated.is_empty() {
+                self.repository.update_classifications(&updated).await?;
+            }
+            report.scanned += fetched;
+            info!(
+                "🔁 已重新分类 {} 条，其中 {} 条有变化",
+                report.scanned,
+                report.changes.len()
+            );
+
+            if fetched < page.limit {
+                break;
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{ReviewLabel, StageStatus};
+    use crate::infrastructure::database::create_pool;
+    use crate::infrastructure::repositories::SqliteNewsRepository;
+    use chrono::Utc;
+
+    async fn setup() -> Arc<dyn NewsRepository> {
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        let repository: Arc<dyn NewsRepository> = Arc::new(SqliteNewsRepository::new(pool));
+
+        // 旧标签错误的新闻、无关新闻（URL 无法抓取，不触发网络请求）
+        let stale = NewsItem::new_with_classification(
+            "1".to_string(),
+            "OpenAI ships GPT-4 update".to_string(),
+            "url-1".to_string(),
+            "hackernews".to_string(),
+            "author".to_string(),
+            Utc::now(),
+            Domain::new("Block"),
+            0.4,
+        );
+        let unrelated = NewsItem::new_with_classification(
+            "2".to_string(),
+            "Local bakery opens second store".to_string(),
+            "url-2".to_string(),
+            "hackernews".to_string(),
+            "author".to_string(),
+            Utc::now() - chrono::Duration::hours(1),
+            Domain::new("Social"),
+            0.35,
+        );
+        repository.save_batch(&[stale, unrelated]).await.unwrap();
+        repository
+    }
+
+    #[tokio::test]
+    async fn test_reclassify_updates_labels_in_place() {
+        let repository = setup().await;
+        let service = ReclassifyNewsService::new(
+            Arc::clone(&repository),
+            Arc::new(NewsClassificationService::new()),
+        );
+
+        let report = service.execute(&NewsQuery::new(10)).await.unwrap();
+
+        assert_eq!(report.scanned, 2);
+        assert_eq!(report.changes.len(), 2);
+        let updated = repository.find_by_id("1").await.unwrap().unwrap();
+        assert_eq!(updated.domain, Some(Domain::new("AI")));
+        assert_eq!(updated.status, NewsItemStatus::Completed);
+        assert!(updated.classification_reason.is_some());
+        let trace = updated.classification_trace.unwrap();
+        assert_eq!(trace.decided_by().unwrap().stage, "FastPass");
+
+        let irrelevant = repository.find_by_id("2").await.unwrap().unwrap();
+        assert_eq!(irrelevant.domain, None);
+        assert_eq!(irrelevant.status, NewsItemStatus::Irrelevant);
+        // 抓取失败等错误也记录在轨迹中
+        let trace = irrelevant.classification_trace.unwrap();
+        let enrichment = trace
+            .stages
+            .iter()
+            .find(|s| s.stage == "ContentEnrichment")
+            .unwrap();
+        assert_eq!(enrichment.status, StageStatus::Failed);
+        assert!(enrichment.error.is_some());
+        assert!(trace.decided_by().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_and_filters() {
+        let repository = setup().await;
+        let service = ReclassifyNewsService::new(
+            Arc::clone(&repository),
+            Arc::new(NewsClassificationService::new()),
+        )
+        .with_dry_run(true);
+
+        let query = NewsQuery::new(10).with_domains(vec![Domain::new("Block")]);
+        let report = service.execute(&query).await.unwrap();
+
+        assert_eq!(report.scanned, 1);
+        assert_eq!(report.changes[0].news_id, "1");
+        assert!(
+            report.changes[0]
+                .before
+                .differs_in_label(&report.changes[0].after)
+        );
+        // dry run 不写回
+        let unchanged = repository.find_by_id("1").await.unwrap().unwrap();
+        assert_eq!(unchanged.domain, Some(Domain::new("Block")));
+    }
+
+    #[tokio::test]
+    async fn test_reviewed_news_keeps_human_label() {
+        let repository = setup().await;
+        let mut reviewed = repository.find_by_id("1").await.unwrap().unwrap();
+        let label = ReviewLabel::relabel(&reviewed, Domain::new("Social"));
+        label.apply_to(&mut reviewed);
+        repository.save_review(&reviewed, &label).await.unwrap();
+
+        let service = ReclassifyNewsService::new(
+            Arc::clone(&repository),
+            Arc::new(NewsClassificationService::new()),
+        );
+        let report = service.execute(&NewsQuery::new(10)).await.unwrap();
+
+        // 只处理未审核的新闻
+        assert_eq!(report.scanned, 1);
+        assert!(report.changes.iter().all(|c| c.news_id != "1"));
+        let kept = repository.find_by_id("1").await.unwrap().unwrap();
+        assert_eq!(kept.domain, Some(Domain::new("Social")));
+        assert_eq!(kept.classification_confidence, Some(1.0));
+        assert!(
+            kept.classification_reason
+                .unwrap()
+                .starts_with("[HumanReview]")
+        );
+        let trace = kept.classification_trace.unwrap();
+        assert_eq!(trace.decided_by().unwrap().stage, "HumanReview");
+    }
+
+    #[tokio::test]
+    async fn test_confidence_threshold_is_exclusive_and_includes_unclassified() {
+        let repository = setup().await;
+        let unclassified = NewsItem::new(
+            "3".to_string(),
+            "Never classified".to_string(),
+            "url-3".to_string(),
+            "hackernews".to_string(),
+            "author".to_string(),
+            Utc::now() - chrono::Duration::hours(2),
+        );
+        repository.save(&unclassified).await.unwrap();
+        let service = ReclassifyNewsService::new(
+            Arc::clone(&repository),
+            Arc::new(NewsClassificationService::new()),
+        )
+        .with_dry_run(true);
+
+        // "1" 的置信度正好是 0.4，不在范围内
+        let query = NewsQuery::new(10).with_confidence_below(0.4);
+        let report = service.execute(&query).await.unwrap();
+
+        assert_eq!(report.scanned, 2);
+        let mut ids: Vec<&str> = report.changes.iter().map(|c| c.news_id.as_str()).collect();
+        ids.sort();
+        assert_eq!(ids, vec!["2", "3"]);
+    }
+}
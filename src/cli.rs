@@ -1,5 +1,9 @@
-use crate::domain::Domain;
+use crate::application::use_cases::evaluate_classification::EvalStrategy;
+use crate::domain::config::SourceRegistryConfig;
+use crate::domain::{NewsItemStatus, SortOrder};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use clap::Parser;
+use std::path::PathBuf;
 
 /// 热点新闻聚合器 - DDD 学习项目
 #[derive(Parser, Debug)]
@@ -16,22 +20,48 @@ pub struct Cli {
     pub database: String,
 }
 
-/// 数据源枚举
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, clap::ValueEnum)]
+/// 数据源选择
+///
+/// `all` 表示所有已启用的数据源，其他名称对应 config/sources.json 中注册的数据源
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum DataSource {
-    /// 所有可用数据源
+    /// 所有已启用的数据源
     All,
-    /// Hacker News (tech news)
-    #[value(name = "hackernews")]
-    HackerNews,
+    /// 按名称选择一个已注册的数据源
+    Named(String),
+}
+
+impl std::str::FromStr for DataSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim();
+        if name.is_empty() {
+            return Err("数据源名称不能为空".to_string());
+        }
+        if name.eq_ignore_ascii_case(SourceRegistryConfig::ALL) {
+            Ok(DataSource::All)
+        } else {
+            Ok(DataSource::Named(name.to_string()))
+        }
+    }
+}
+
+impl std::fmt::Display for DataSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataSource::All => write!(f, "{}", SourceRegistryConfig::ALL),
+            DataSource::Named(name) => write!(f, "{}", name),
+        }
+    }
 }
 
 #[derive(clap::Subcommand, Debug)]
 pub enum Commands {
     /// 从网络数据源抓取新闻
     Fetch {
-        /// 数据源 (all, hackernews)
-        #[arg(short = 'S', long, value_enum, default_value = "all")]
+        /// 数据源 (all 或 config/sources.json 中注册的任意数据源名称)
+        #[arg(short = 'S', long, default_value = "all")]
         source: DataSource,
 
         /// 是否保存到数据库
@@ -50,24 +80,288 @@ pub enum Commands {
         #[arg(short = 'n', long, default_value_t = 10)]
         limit: usize,
 
-        /// 指定领域过滤 (ai, block, social)
-        #[arg(short = 'd', long, value_enum)]
-        domain: Option<Vec<Domain>>,
+        /// 指定领域过滤（领域名称见 config/classification.json，不区分大小写）
+        #[arg(short = 'd', long)]
+        domain: Option<Vec<String>>,
+
+        /// 排序方式 (recency, hotness, confidence, source)
+        #[arg(long, value_enum, default_value = "recency")]
+        sort: SortOrder,
+
+        /// 报道聚类的标题相似度阈值 (0.0, 1.0]，越低合并越激进
+        #[arg(long, default_value_t = 0.6, value_parser = parse_title_similarity)]
+        title_similarity: f32,
     },
 
     /// 从数据库加载并列出新闻
     List {
-        /// 新闻数量限制
+        /// 每页新闻数量
         #[arg(short = 'n', long, default_value_t = 10)]
         limit: usize,
 
-        /// 指定领域过滤 (ai, block, social)
-        #[arg(short = 'd', long, value_enum)]
-        domain: Option<Vec<Domain>>,
+        /// 页码（从 1 开始，默认第 1 页；按 --sort 排序后翻页，不支持 hotness）
+        #[arg(short = 'p', long)]
+        page: Option<usize>,
+
+        /// 指定领域过滤（领域名称见 config/classification.json，不区分大小写）
+        #[arg(short = 'd', long)]
+        domain: Option<Vec<String>>,
+
+        /// 指定来源过滤（可多次指定）
+        #[arg(short = 'S', long)]
+        source: Vec<String>,
+
+        /// 指定状态过滤（可多次指定）
+        #[arg(long, value_enum)]
+        status: Vec<NewsItemStatus>,
+
+        /// 最低分类置信度 (0.0 - 1.0)
+        #[arg(long)]
+        min_confidence: Option<f32>,
+
+        /// 发布时间下限（RFC3339、YYYY-MM-DD，或相对时间如 30m、12h、7d）
+        #[arg(long, value_parser = parse_time_bound)]
+        since: Option<DateTime<Utc>>,
+
+        /// 发布时间上限（格式同 --since，不包含）
+        #[arg(long, value_parser = parse_time_bound)]
+        until: Option<DateTime<Utc>>,
+
+        /// 排序方式 (recency, hotness, confidence, source)；hotness 只对最新的一页重新排序
+        #[arg(long, value_enum, default_value = "recency")]
+        sort: SortOrder,
     },
 
     /// 显示数据库统计信息
     Stats,
+
+    /// 常驻运行，按每个数据源配置的间隔（interval_secs）轮询、保存并推送新闻
+    Serve {
+        /// 数据源 (all 或 config/sources.json 中注册的任意数据源名称)
+        #[arg(short = 'S', long, default_value = "all")]
+        source: DataSource,
+
+        /// 是否发送到 Discord
+        #[arg(long, action)]
+        discord: bool,
+
+        /// Discord webhook URL (可选，默认从环境变量读取)
+        #[arg(long)]
+        discord_webhook: Option<String>,
+
+        /// 每轮每个数据源的新闻数量限制
+        #[arg(short = 'n', long, default_value_t = 10)]
+        limit: usize,
+
+        /// 指定领域过滤（领域名称见 config/classification.json，不区分大小写）
+        #[arg(short = 'd', long)]
+        domain: Option<Vec<String>>,
+
+        /// 排序方式 (recency, hotness, confidence, source)
+        #[arg(long, value_enum, default_value = "recency")]
+        sort: SortOrder,
+
+        /// 报道聚类的标题相似度阈值 (0.0, 1.0]，越低合并越激进
+        #[arg(long, default_value_t = 0.6, value_parser = parse_title_similarity)]
+        title_similarity: f32,
+    },
+
+    /// 按近期热度增长速度列出新闻（基于重复抓取时记录的快照）
+    Trending {
+        /// 统计窗口（分钟，大于 0）
+        #[arg(short = 'w', long, default_value_t = 60, value_parser = parse_window_minutes)]
+        window: i64,
+
+        /// 新闻数量限制
+        #[arg(short = 'n', long, default_value_t = 10)]
+        limit: usize,
+    },
+
+    /// 用当前的分类配置和模型重新分类已保存的新闻
+    Reclassify {
+        /// 最多处理的新闻数量
+        #[arg(short = 'n', long, default_value_t = 100)]
+        limit: usize,
+
+        /// 指定领域过滤（领域名称见 config/classification.json，不区分大小写）
+        #[arg(short = 'd', long)]
+        domain: Option<Vec<String>>,
+
+        /// 指定来源过滤（可多次指定）
+        #[arg(short = 'S', long)]
+        source: Vec<String>,
+
+        /// 指定状态过滤（可多次指定）
+        #[arg(long, value_enum)]
+        status: Vec<NewsItemStatus>,
+
+        /// 只处理置信度低于该值（不包含）的新闻，从未分类的新闻也会处理 (0.0 - 1.0)
+        #[arg(long)]
+        max_confidence: Option<f32>,
+
+        /// 发布时间下限（RFC3339、YYYY-MM-DD，或相对时间如 30m、12h、7d）
+        #[arg(long, value_parser = parse_time_bound)]
+        since: Option<DateTime<Utc>>,
+
+        /// 发布时间上限（格式同 --since，不包含）
+        #[arg(long, value_parser = parse_time_bound)]
+        until: Option<DateTime<Utc>>,
+
+        /// 只打印变化，不写回数据库
+        #[arg(long, action)]
+        dry_run: bool,
+    },
+
+    /// 交互式审核待复核的新闻（低置信度分类），结果保存为人工标签
+    Review {
+        /// 最多审核的新闻数量
+        #[arg(short = 'n', long, default_value_t = 20)]
+        limit: usize,
+
+        /// 指定领域过滤（领域名称见 config/classification.json，不区分大小写）
+        #[arg(short = 'd', long)]
+        domain: Option<Vec<String>>,
+    },
+
+    /// 管理分类关键词
+    Keywords {
+        #[command(subcommand)]
+        command: KeywordsCommand,
+    },
+
+    /// 在标注数据集上评估分类效果（精确率 / 召回率 / F1、混淆矩阵、各阶段命中率）
+    Eval {
+        /// JSONL 标注数据集路径
+        dataset: PathBuf,
+
+        /// 评估的分类方式
+        #[arg(long, value_enum, default_value_t = EvalStrategy::Pipeline)]
+        strategy: EvalStrategy,
+
+        /// AI 仲裁使用的推理服务（仅 pipeline）
+        #[arg(long, value_enum, default_value_t = EvalInference::None)]
+        inference: EvalInference,
+
+        /// 使用指定的分类配置文件（默认 config/classification.json）
+        #[arg(long)]
+        config: Option<PathBuf>,
+
+        /// 打印每条预测错误的样本
+        #[arg(long, action)]
+        show_errors: bool,
+    },
+
+    /// 全文检索已保存的新闻（标题、正文、分类依据）
+    Search {
+        /// 检索词（多个词之间为"与"关系）
+        query: String,
+
+        /// 结果数量限制
+        #[arg(short = 'n', long, default_value_t = 20)]
+        limit: usize,
+    },
+
+    /// 查看一条新闻的详情和完整的分类轨迹
+    Show {
+        /// 新闻 ID
+        id: String,
+    },
+
+    /// 启动只读 HTTP JSON API
+    Api {
+        /// 监听地址
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        bind: std::net::SocketAddr,
+    },
+}
+
+/// `eval` 的 AI 仲裁来源
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvalInference {
+    /// 不使用 AI，仅规则引擎
+    None,
+    /// 回放数据集中记录的模型响应（离线）
+    Mock,
+    /// 使用环境变量配置的 OpenAI 兼容服务
+    Openai,
+}
+
+/// `keywords` 的子命令
+#[derive(clap::Subcommand, Debug)]
+pub enum KeywordsCommand {
+    /// 交互式审核 AI 建议的关键词（批准为强/弱关键词，或永久拒绝）
+    Suggestions {
+        /// 最多审核的建议数量（按出现次数从多到少）
+        #[arg(short = 'n', long, default_value_t = 20)]
+        limit: usize,
+
+        /// 只审核特定领域的建议（领域名称不区分大小写）
+        #[arg(short = 'd', long)]
+        domain: Option<String>,
+
+        /// 批准的关键词写入的分类配置文件（默认 config/classification.json）
+        #[arg(long)]
+        config: Option<PathBuf>,
+    },
+}
+
+/// 解析时间参数
+///
+/// 支持 RFC3339 时间、`YYYY-MM-DD`（UTC 零点），以及相对于当前时间的
+/// `<数字>m`、`<数字>h`、`<数字>d`（分别表示多少分钟、小时、天之前）
+fn parse_time_bound(s: &str) -> Result<DateTime<Utc>, String> {
+    let s = s.trim();
+    if let Ok(time) = DateTime::parse_from_rfc3339(s) {
+        return Ok(time.with_timezone(&Utc));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return Ok(date.and_hms_opt(0, 0, 0).unwrap().and_utc());
+    }
+
+    let invalid = || {
+        format!(
+            "无效的时间: {}（支持 RFC3339、YYYY-MM-DD、30m、12h、7d）",
+            s
+        )
+    };
+    let unit = s.chars().last().ok_or_else(invalid)?;
+    let amount: i64 = s[..s.len() - unit.len_utf8()]
+        .parse()
+        .map_err(|_| invalid())?;
+    let ago = match unit {
+        'm' => Duration::minutes(amount),
+        'h' => Duration::hours(amount),
+        'd' => Duration::days(amount),
+        _ => return Err(invalid()),
+    };
+    Ok(Utc::now() - ago)
+}
+
+/// 解析统计窗口（分钟），必须大于 0
+fn parse_window_minutes(s: &str) -> Result<i64, String> {
+    let minutes: i64 = s
+        .trim()
+        .parse()
+        .map_err(|_| format!("无效的分钟数: {}", s))?;
+    if minutes <= 0 {
+        return Err(format!("统计窗口必须大于 0 分钟: {}", minutes));
+    }
+    Ok(minutes)
+}
+
+/// 解析标题相似度阈值，必须在 (0.0, 1.0] 之间
+///
+/// 0 会把所有新闻合并为同一个聚类，大于 1 则永远不会匹配
+fn parse_title_similarity(s: &str) -> Result<f32, String> {
+    let value: f32 = s
+        .trim()
+        .parse()
+        .map_err(|_| format!("无效的相似度: {}", s))?;
+    if value > 0.0 && value <= 1.0 {
+        Ok(value)
+    } else {
+        Err(format!("相似度必须大于 0 且不超过 1: {}", s))
+    }
 }
 
 impl Cli {
@@ -86,4 +380,57 @@ mod tests {
         // Here we could add tests for clap parsing if needed,
         // but for now we'll just ensure the structure is valid.
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_data_source_parsing() {
+        assert_eq!("all".parse::<DataSource>(), Ok(DataSource::All));
+        assert_eq!("ALL".parse::<DataSource>(), Ok(DataSource::All));
+        assert_eq!(
+            "openai-news".parse::<DataSource>(),
+            Ok(DataSource::Named("openai-news".to_string()))
+        );
+        assert!("  ".parse::<DataSource>().is_err());
+    }
+
+    #[test]
+    fn test_time_bound_parsing() {
+        assert_eq!(
+            parse_time_bound("2025-03-01T08:00:00+08:00").unwrap(),
+            "2025-03-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(
+            parse_time_bound("2025-03-01").unwrap(),
+            "2025-03-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+
+        let week_ago = parse_time_bound("7d").unwrap();
+        let expected = Utc::now() - Duration::days(7);
+        assert!((week_ago - expected).num_seconds().abs() < 5);
+
+        assert!(parse_time_bound("7w").is_err());
+        assert!(parse_time_bound("d").is_err());
+        assert!(parse_time_bound("yesterday").is_err());
+    }
+
+    #[test]
+    fn test_title_similarity_parsing() {
+        assert_eq!(parse_title_similarity("0.6"), Ok(0.6));
+        assert_eq!(parse_title_similarity("1"), Ok(1.0));
+        assert!(parse_title_similarity("0").is_err());
+        assert!(parse_title_similarity("1.5").is_err());
+        assert!(parse_title_similarity("-0.2").is_err());
+    }
+
+    #[test]
+    fn test_window_minutes_parsing() {
+        assert_eq!(parse_window_minutes("60"), Ok(60));
+        assert!(parse_window_minutes("0").is_err());
+        assert!(parse_window_minutes("-5").is_err());
+        assert!(parse_window_minutes("1h").is_err());
+        assert!(parse_title_similarity("NaN").is_err());
+        assert!(parse_title_similarity("high").is_err());
+
+        let serve = Cli::try_parse_from(["trendarc", "serve", "--title-similarity", "0"]);
+        assert!(serve.is_err());
+    }
+}
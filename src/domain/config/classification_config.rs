@@ -1,110 +1,81 @@
 //! # Classification Configuration
 //!
 //! Contains configuration data for classification strategies including
-//! keyword mappings and source tendencies.
+//! the domain definitions with their keywords, and source tendencies.
+use super::domain_config::DomainDefinition;
+use super::pipeline_config::StageConfig;
 use crate::domain::Domain;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
+use tracing::warn;
 
 /// Configuration for news classification
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClassificationConfig {
-    /// Strong keywords (high confidence)
-    pub strong_keywords: HashMap<Domain, Vec<String>>,
-
-    /// Weak keywords (low confidence)
-    pub weak_keywords: HashMap<Domain, Vec<String>>,
+    /// Domains to classify into, in display order, each with its keywords
+    #[serde(default = "DomainDefinition::defaults")]
+    pub domains: Vec<DomainDefinition>,
 
     /// Source tendency mapping (default domain for each source)
     pub source_tendency: HashMap<String, Domain>,
-}
 
-impl Default for ClassificationConfig {
-    fn default() -> Self {
-        let mut strong_keywords: HashMap<Domain, Vec<String>> = HashMap::new();
-        let mut weak_keywords: HashMap<Domain, Vec<String>> = HashMap::new();
-        let source_tendency: HashMap<String, Domain> = HashMap::new();
-
-        // ===== AI Keywords =====
-        strong_keywords.insert(
-            Domain::AI,
-            vec![
-                "gpt-4".into(),
-                "gpt4".into(),
-                "chatgpt".into(),
-                "openai".into(),
-                "claude".into(),
-                "gemini".into(),
-                "llm".into(),
-                "generative ai".into(),
-                "deep learning".into(),
-                "neural network".into(),
-            ],
-        );
-        weak_keywords.insert(
-            Domain::AI,
-            vec![
-                "ai".into(),
-                "machine learning".into(),
-                "ml".into(),
-                "model".into(),
-            ],
-        );
+    /// Global exclusion rules, checked before any content fetching or AI calls
+    #[serde(default)]
+    pub exclude_patterns: Vec<ExcludePattern>,
 
-        // ===== Blockchain Keywords =====
-        strong_keywords.insert(
-            Domain::Block,
-            vec![
-                "bitcoin".into(),
-                "btc".into(),
-                "ethereum".into(),
-                "eth".into(),
-                "web3".into(),
-                "defi".into(),
-                "nft".into(),
-                "smart contract".into(),
-            ],
-        );
-        weak_keywords.insert(
-            Domain::Block,
-            vec!["crypto".into(), "blockchain".into(), "token".into()],
-        );
+    /// Ordered classification stages (defaults to the built-in funnel)
+    #[serde(default = "StageConfig::default_pipeline")]
+    pub pipeline: Vec<StageConfig>,
 
-        // ===== Social Keywords =====
-        strong_keywords.insert(
-            Domain::Social,
-            vec![
-                "twitter".into(),
-                "tiktok".into(),
-                "instagram".into(),
-                "meta".into(),
-                "youtube".into(),
-                "discord".into(),
-                "telegram".into(),
-            ],
-        );
-        weak_keywords.insert(
-            Domain::Social,
-            vec!["social media".into(), "influencer".into(), "viral".into()],
-        );
+    /// Per-source pipelines, keyed by source name; they replace `pipeline` for that source
+    #[serde(default)]
+    pub source_profiles: HashMap<String, Vec<StageConfig>>,
+}
+
+/// A global exclusion rule
+///
+/// Serialized as `{"host": "spam.example.com"}` or `{"title": "(?i)^sponsored:"}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExcludePattern {
+    /// Drop news whose URL host is this domain or one of its subdomains
+    Host(String),
+    /// Drop news whose title matches this regular expression
+    Title(String),
+}
 
+impl std::fmt::Display for ExcludePattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExcludePattern::Host(host) => write!(f, "host {}", host),
+            ExcludePattern::Title(pattern) => write!(f, "title /{}/", pattern),
+        }
+    }
+}
+
+impl Default for ClassificationConfig {
+    fn default() -> Self {
         Self {
-            strong_keywords,
-            weak_keywords,
-            source_tendency,
+            domains: DomainDefinition::defaults(),
+            ..Self::empty()
         }
     }
 }
 
 impl ClassificationConfig {
-    /// Create a new empty configuration
+    /// Default location of the configuration file
+    pub const DEFAULT_PATH: &'static str = "config/classification.json";
+
+    /// Create a new empty configuration (no domains)
     pub fn empty() -> Self {
         Self {
-            strong_keywords: HashMap::new(),
-            weak_keywords: HashMap::new(),
+            domains: Vec::new(),
             source_tendency: HashMap::new(),
+            exclude_patterns: Vec::new(),
+            pipeline: StageConfig::default_pipeline(),
+            source_profiles: HashMap::new(),
         }
     }
 
@@ -119,7 +90,9 @@ impl ClassificationConfig {
         }
 
         let content = fs::read_to_string(path)?;
-        let config: Self = serde_json::from_str(&content)?;
+        let mut value: serde_json::Value = serde_json::from_str(&content)?;
+        upgrade_legacy_keywords(&mut value);
+        let config: Self = serde_json::from_value(value)?;
         Ok(config)
     }
 
@@ -136,10 +109,59 @@ impl ClassificationConfig {
         Ok(())
     }
 
+    /// Names of the configured domains, in display order
+    pub fn domain_names(&self) -> Vec<Domain> {
+        self.domains.iter().map(|d| d.name).collect()
+    }
+
+    /// Definition of `domain`, if it is configured
+    pub fn domain(&self, domain: Domain) -> Option<&DomainDefinition> {
+        self.domains.iter().find(|d| d.name == domain)
+    }
+
+    /// The configured domain called `name` (case-insensitive)
+    pub fn resolve_domain(&self, name: &str) -> Option<Domain> {
+        let name = name.trim();
+        self.domains
+            .iter()
+            .find(|d| d.name.display_name().eq_ignore_ascii_case(name))
+            .map(|d| d.name)
+    }
+
+    /// Resolve user-supplied domain names, rejecting any that are not configured
+    pub fn parse_domains(&self, names: &[String]) -> Result<Vec<Domain>, String> {
+        names
+            .iter()
+            .map(|name| {
+                self.resolve_domain(name).ok_or_else(|| {
+                    let known: Vec<&str> =
+                        self.domains.iter().map(|d| d.name.display_name()).collect();
+                    format!(
+                        "unknown domain '{}' (expected one of: {})",
+                        name,
+                        known.join(", ")
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Definition of `domain`, added with default styling if it is not configured yet
+    fn domain_mut(&mut self, domain: Domain) -> &mut DomainDefinition {
+        let index = match self.domains.iter().position(|d| d.name == domain) {
+            Some(index) => index,
+            None => {
+                self.domains.push(DomainDefinition::new(domain));
+                self.domains.len() - 1
+            }
+        };
+        &mut self.domains[index]
+    }
+
     /// Add a strong keyword for a domain (avoiding duplicates)
     pub fn add_strong_keyword(&mut self, domain: Domain, keyword: String) {
         let kw = keyword.to_lowercase();
-        let vec = self.strong_keywords.entry(domain).or_insert_with(Vec::new);
+        let vec = &mut self.domain_mut(domain).strong_keywords;
         if !vec.contains(&kw) {
             vec.push(kw);
         }
@@ -148,12 +170,23 @@ impl ClassificationConfig {
     /// Add a weak keyword for a domain (avoiding duplicates)
     pub fn add_weak_keyword(&mut self, domain: Domain, keyword: String) {
         let kw = keyword.to_lowercase();
-        let vec = self.weak_keywords.entry(domain).or_insert_with(Vec::new);
+        let vec = &mut self.domain_mut(domain).weak_keywords;
         if !vec.contains(&kw) {
             vec.push(kw);
         }
     }
 
+    /// Whether `keyword` is already a strong or weak keyword of `domain` (case-insensitive)
+    pub fn contains_keyword(&self, domain: Domain, keyword: &str) -> bool {
+        let kw = keyword.trim().to_lowercase();
+        self.domain(domain).is_some_and(|d| {
+            d.strong_keywords
+                .iter()
+                .chain(&d.weak_keywords)
+                .any(|k| k.to_lowercase() == kw)
+        })
+    }
+
     /// Merge suggested keywords from AI
     pub fn merge_suggested_keywords(&mut self, domain: Domain, keywords: Vec<String>) {
         for kw in keywords {
@@ -162,3 +195,143 @@ impl ClassificationConfig {
         }
     }
 }
+
+/// Fold the top-level keyword maps of older config files into `domains`
+///
+/// Before domains were configurable, keywords lived in `strong_keywords`,
+/// `weak_keywords` and `negative_keywords` maps keyed by domain name. Such a
+/// file gets one domain per name (built-in styling for AI, Block and Social),
+/// and is written in the new layout the next time it is saved. A file that
+/// already has `domains` keeps them, and its legacy maps are ignored.
+fn upgrade_legacy_keywords(value: &mut serde_json::Value) {
+    let Some(object) = value.as_object_mut() else {
+        return;
+    };
+    let legacy_keys: Vec<&str> = ["strong_keywords", "weak_keywords", "negative_keywords"]
+        .into_iter()
+        .filter(|key| object.contains_key(*key))
+        .collect();
+    if legacy_keys.is_empty() {
+        return;
+    }
+    if object.contains_key("domains") {
+        warn!(
+            "⚠️ 配置已包含 domains，忽略旧版关键词字段: {}",
+            legacy_keys.join(", ")
+        );
+        return;
+    }
+    let legacy: Vec<(&str, serde_json::Value)> = legacy_keys
+        .into_iter()
+        .filter_map(|key| object.remove(key).map(|lists| (key, lists)))
+        .collect();
+
+    let builtin = DomainDefinition::defaults();
+    let mut domains: Vec<DomainDefinition> = Vec::new();
+    for (key, lists) in legacy {
+        let lists = match serde_json::from_value::<HashMap<Domain, Vec<String>>>(lists) {
+            Ok(lists) => lists,
+            Err(e) => {
+                warn!("⚠️ 忽略无法解析的旧版关键词字段 {}: {}", key, e);
+                continue;
+            }
+        };
+        for (name, keywords) in lists {
+            let index = match domains.iter().position(|d| d.name == name) {
+                Some(index) => index,
+                None => {
+                    let styled =
+                        builtin
+                            .iter()
+                            .find(|d| d.name == name)
+                            .map(|d| DomainDefinition {
+                                strong_keywords: Vec::new(),
+                                weak_keywords: Vec::new(),
+                                negative_keywords: Vec::new(),
+                                ..d.clone()
+                            });
+                    domains.push(styled.unwrap_or_else(|| DomainDefinition::new(name)));
+                    domains.len() - 1
+                }
+            };
+            let definition = &mut domains[index];
+            match key {
+                "strong_keywords" => definition.strong_keywords = keywords,
+                "weak_keywords" => definition.weak_keywords = keywords,
+                _ => definition.negative_keywords = keywords,
+            }
+        }
+    }
+    // Built-in domains keep their usual order, others follow alphabetically
+    domains.sort_by_key(|d| {
+        (
+            builtin
+                .iter()
+                .position(|b| b.name == d.name)
+                .unwrap_or(builtin.len()),
+            d.name,
+        )
+    });
+    if let Ok(domains) = serde_json::to_value(domains) {
+        object.insert("domains".to_string(), domains);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legacy_keyword_maps_become_domains() {
+        let path = std::env::temp_dir().join(format!(
+            "trendarc-legacy-config-{}.json",
+            std::process::id()
+        ));
+        fs::write(
+            &path,
+            r#"{
+                "strong_keywords": {"Block": ["bitcoin"], "AI": ["openai"], "Hardware": ["risc-v"]},
+                "weak_keywords": {"AI": ["ml"]},
+                "source_tendency": {}
+            }"#,
+        )
+        .unwrap();
+
+        let config = ClassificationConfig::load_from_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let names: Vec<&str> = config
+            .domains
+            .iter()
+            .map(|d| d.name.display_name())
+            .collect();
+        assert_eq!(names, vec!["AI", "Block", "Hardware"]);
+        let ai = config.domain(Domain::new("AI")).unwrap();
+        assert_eq!(ai.emoji, "🤖");
+        assert_eq!(ai.strong_keywords, vec!["openai".to_string()]);
+        assert_eq!(ai.weak_keywords, vec!["ml".to_string()]);
+        assert!(ai.negative_keywords.is_empty());
+        assert_eq!(
+            config.resolve_domain("hardware"),
+            Some(Domain::new("Hardware"))
+        );
+        assert_eq!(config.resolve_domain("sports"), None);
+    }
+
+    #[test]
+    fn test_domains_win_over_legacy_keyword_maps() {
+        let mut value = serde_json::json!({
+            "domains": [{"name": "AI", "strong_keywords": ["openai"]}],
+            "strong_keywords": {"Block": ["bitcoin"]},
+            "source_tendency": {}
+        });
+        upgrade_legacy_keywords(&mut value);
+
+        let config: ClassificationConfig = serde_json::from_value(value).unwrap();
+        assert_eq!(config.domain_names(), vec![Domain::new("AI")]);
+        assert_eq!(
+            config.domain(Domain::new("AI")).unwrap().strong_keywords,
+            vec!["openai".to_string()]
+        );
+    }
+}
@@ -0,0 +1,162 @@
+//! # Domain Definitions
+//!
+//! The domains news is classified into, with how they are displayed, how
+//! they are described to the AI model, and the keywords that signal them.
+use crate::domain::Domain;
+use serde::{Deserialize, Serialize};
+
+/// One configured domain
+///
+/// Serialized as e.g.
+/// `{"name": "Security", "emoji": "🔐", "color": "#e74c3c", "description": "...",
+///   "strong_keywords": ["cve"], "weak_keywords": ["exploit"]}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DomainDefinition {
+    /// Domain name, used in storage, filters and AI responses (case-insensitive)
+    pub name: Domain,
+    /// Shown before the domain name in the terminal and Discord
+    #[serde(default = "default_emoji")]
+    pub emoji: String,
+    /// Discord embed color, as `#rrggbb`
+    #[serde(default = "default_color")]
+    pub color: String,
+    /// What belongs in the domain, in a few words (sent to the AI model)
+    #[serde(default)]
+    pub description: String,
+    /// Strong keywords (high confidence)
+    #[serde(default)]
+    pub strong_keywords: Vec<String>,
+    /// Weak keywords (low confidence)
+    #[serde(default)]
+    pub weak_keywords: Vec<String>,
+    /// Negative keywords: a match suppresses the domain's keywords it overlaps
+    /// (e.g. "ethernet" hides "eth") and down-weights the domain otherwise
+    #[serde(default)]
+    pub negative_keywords: Vec<String>,
+}
+
+impl DomainDefinition {
+    /// A domain with default styling and no keywords
+    pub fn new(name: Domain) -> Self {
+        Self {
+            name,
+            emoji: default_emoji(),
+            color: default_color(),
+            description: String::new(),
+            strong_keywords: Vec::new(),
+            weak_keywords: Vec::new(),
+            negative_keywords: Vec::new(),
+        }
+    }
+
+    /// Embed color as a number, falling back to the default blue for malformed values
+    pub fn color_value(&self) -> u32 {
+        parse_color(&self.color)
+            .or_else(|| parse_color(&default_color()))
+            .unwrap_or_default()
+    }
+
+    /// The built-in domains: AI, Block and Social
+    pub fn defaults() -> Vec<DomainDefinition> {
+        vec![
+            DomainDefinition {
+                emoji: "🤖".into(),
+                color: "#9b59b6".into(),
+                description: "Artificial intelligence, LLMs, Neural Networks, Robotics, etc."
+                    .into(),
+                strong_keywords: vec![
+                    "gpt-4".into(),
+                    "gpt4".into(),
+                    "chatgpt".into(),
+                    "openai".into(),
+                    "claude".into(),
+                    "gemini".into(),
+                    "llm".into(),
+                    "generative ai".into(),
+                    "deep learning".into(),
+                    "neural network".into(),
+                ],
+                weak_keywords: vec![
+                    "ai".into(),
+                    "machine learning".into(),
+                    "ml".into(),
+                    "model".into(),
+                ],
+                // French "j'ai" is not AI
+                negative_keywords: vec!["j'ai".into()],
+                ..DomainDefinition::new(Domain::new("AI"))
+            },
+            DomainDefinition {
+                emoji: "⛓️".into(),
+                color: "#f39c12".into(),
+                description: "Cryptocurrency, Web3, DeFi, Smart Contracts, etc.".into(),
+                strong_keywords: vec![
+                    "bitcoin".into(),
+                    "btc".into(),
+                    "ethereum".into(),
+                    "eth".into(),
+                    "web3".into(),
+                    "defi".into(),
+                    "nft".into(),
+                    "smart contract".into(),
+                ],
+                weak_keywords: vec!["crypto".into(), "blockchain".into(), "token".into()],
+                negative_keywords: vec!["ethernet".into(), "ethics".into(), "ethical".into()],
+                ..DomainDefinition::new(Domain::new("Block"))
+            },
+            DomainDefinition {
+                emoji: "📱".into(),
+                color: "#1abc9c".into(),
+                description:
+                    "Social Media platforms (Twitter/X, Meta, Tiktok, etc.), tech platform news."
+                        .into(),
+                strong_keywords: vec![
+                    "twitter".into(),
+                    "tiktok".into(),
+                    "instagram".into(),
+                    "meta".into(),
+                    "youtube".into(),
+                    "discord".into(),
+                    "telegram".into(),
+                ],
+                weak_keywords: vec!["social media".into(), "influencer".into(), "viral".into()],
+                negative_keywords: vec!["metadata".into(), "metal".into()],
+                ..DomainDefinition::new(Domain::new("Social"))
+            },
+        ]
+    }
+}
+
+fn default_emoji() -> String {
+    "📰".to_string()
+}
+
+fn default_color() -> String {
+    "#3498db".to_string()
+}
+
+fn parse_color(color: &str) -> Option<u32> {
+    u32::from_str_radix(color.trim().strip_prefix('#')?, 16).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_definition_defaults_and_color() {
+        let definition: DomainDefinition =
+            serde_json::from_str(r##"{"name": "Hardware", "color": "#e74c3c"}"##).unwrap();
+
+        assert_eq!(definition.name.display_name(), "Hardware");
+        assert_eq!(definition.emoji, "📰");
+        assert_eq!(definition.color_value(), 0xe74c3c);
+        assert!(definition.strong_keywords.is_empty());
+
+        let malformed = DomainDefinition {
+            color: "red".to_string(),
+            ..definition
+        };
+        assert_eq!(malformed.color_value(), 0x3498db);
+    }
+}
@@ -1,7 +1,13 @@
 //! # Classification Configuration
 //!
-//! Contains configuration data for classification strategies.
+//! Contains configuration data for classification strategies and news sources.
 
 pub mod classification_config;
+pub mod domain_config;
+pub mod pipeline_config;
+pub mod source_config;
 
-pub use classification_config::ClassificationConfig;
\ No newline at end of file
+pub use classification_config::{ClassificationConfig, ExcludePattern};
+pub use domain_config::DomainDefinition;
+pub use pipeline_config::StageConfig;
+pub use source_config::{SourceDefinition, SourceRegistryConfig};
@@ -0,0 +1,166 @@
+//! # Classification Pipeline Configuration
+//!
+//! Describes the ordered stages a news item goes through during
+//! classification, with per-stage switches and thresholds.
+use serde::{Deserialize, Serialize};
+
+/// One configured stage of the classification pipeline
+///
+/// Serialized with a `stage` tag, e.g.
+/// `{"stage": "content_enrichment", "skip_if_content": true}`.
+/// Omitted fields take the defaults of the built-in funnel.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "stage", rename_all = "snake_case")]
+pub enum StageConfig {
+    /// Keyword scan of title and URL (and any content the item already carries)
+    Keyword {
+        #[serde(default = "default_enabled")]
+        enabled: bool,
+        /// A result at or above this confidence is accepted immediately
+        #[serde(default = "default_accept_threshold")]
+        accept_threshold: f32,
+    },
+    /// Domain tendency of the source or URL host (`source_tendency`)
+    ///
+    /// Source matches score 0.4 (name) or 0.5 (URL host), so at the default
+    /// threshold this stage never decides and only leaves a candidate for
+    /// `Fallback`; set `accept_threshold` to 0.4 or below to let it decide.
+    SourceTendency {
+        #[serde(default = "default_enabled")]
+        enabled: bool,
+        #[serde(default = "default_accept_threshold")]
+        accept_threshold: f32,
+    },
+    /// Fetch the article body for later stages
+    ContentEnrichment {
+        #[serde(default = "default_enabled")]
+        enabled: bool,
+        /// Keep the body the item already carries (e.g. an RSS summary) instead of fetching
+        #[serde(default)]
+        skip_if_content: bool,
+    },
+    /// Keyword scan including the fetched body
+    FullTextKeyword {
+        #[serde(default = "default_enabled")]
+        enabled: bool,
+        #[serde(default = "default_accept_threshold")]
+        accept_threshold: f32,
+    },
+    /// Ask the inference service (skipped when none is configured)
+    AiArbitration {
+        #[serde(default = "default_enabled")]
+        enabled: bool,
+        /// AI results below this confidence are kept but marked for review
+        #[serde(default = "default_accept_threshold")]
+        review_threshold: f32,
+        /// Items sent to the model in one request (1 asks about each item separately)
+        #[serde(default = "default_batch_size")]
+        batch_size: usize,
+    },
+    /// Accept the best weak candidate so far, marked for review
+    Fallback {
+        #[serde(default = "default_enabled")]
+        enabled: bool,
+        /// Candidates must score above this confidence
+        #[serde(default = "default_fallback_confidence")]
+        min_confidence: f32,
+    },
+}
+
+impl StageConfig {
+    /// The built-in funnel: keyword → source tendency → content → full text → AI → fallback
+    pub fn default_pipeline() -> Vec<StageConfig> {
+        vec![
+            StageConfig::Keyword {
+                enabled: true,
+                accept_threshold: default_accept_threshold(),
+            },
+            StageConfig::SourceTendency {
+                enabled: true,
+                accept_threshold: default_accept_threshold(),
+            },
+            StageConfig::ContentEnrichment {
+                enabled: true,
+                skip_if_content: false,
+            },
+            StageConfig::FullTextKeyword {
+                enabled: true,
+                accept_threshold: default_accept_threshold(),
+            },
+            StageConfig::AiArbitration {
+                enabled: true,
+                review_threshold: default_accept_threshold(),
+                batch_size: default_batch_size(),
+            },
+            StageConfig::Fallback {
+                enabled: true,
+                min_confidence: default_fallback_confidence(),
+            },
+        ]
+    }
+
+    /// Whether the stage is switched on
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            StageConfig::Keyword { enabled, .. }
+            | StageConfig::SourceTendency { enabled, .. }
+            | StageConfig::ContentEnrichment { enabled, .. }
+            | StageConfig::FullTextKeyword { enabled, .. }
+            | StageConfig::AiArbitration { enabled, .. }
+            | StageConfig::Fallback { enabled, .. } => *enabled,
+        }
+    }
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_accept_threshold() -> f32 {
+    0.7
+}
+
+fn default_fallback_confidence() -> f32 {
+    0.3
+}
+
+fn default_batch_size() -> usize {
+    10
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stage_defaults_when_fields_are_omitted() {
+        let stages: Vec<StageConfig> = serde_json::from_str(
+            r#"[
+                {"stage": "keyword", "accept_threshold": 0.8},
+                {"stage": "content_enrichment", "skip_if_content": true},
+                {"stage": "ai_arbitration", "enabled": false}
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            stages,
+            vec![
+                StageConfig::Keyword {
+                    enabled: true,
+                    accept_threshold: 0.8,
+                },
+                StageConfig::ContentEnrichment {
+                    enabled: true,
+                    skip_if_content: true,
+                },
+                StageConfig::AiArbitration {
+                    enabled: false,
+                    review_threshold: 0.7,
+                    batch_size: 10,
+                },
+            ]
+        );
+        assert!(!stages[2].is_enabled());
+    }
+}
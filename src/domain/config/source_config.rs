@@ -0,0 +1,199 @@
+//! # Source Registry Configuration
+//!
+//! Describes which news sources exist, how to build them and how much
+//! each one contributes to an aggregated fetch.
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// A registered news source
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceDefinition {
+    /// Unique source name, used by `--source` and recorded on fetched items
+    pub name: String,
+
+    /// Fetcher kind (e.g. "hackernews", "rss")
+    pub kind: String,
+
+    /// Kind-specific parameters (e.g. `{"url": "..."}` for feeds)
+    #[serde(default)]
+    pub params: serde_json::Map<String, serde_json::Value>,
+
+    /// Disabled sources are skipped when fetching from all sources
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+
+    /// Relative share of the fetch limit when aggregating sources
+    #[serde(default = "default_weight")]
+    pub weight: f32,
+
+    /// Polling interval in daemon mode, in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval_secs: Option<u64>,
+}
+
+impl SourceDefinition {
+    /// Polling interval used when no `interval_secs` is configured
+    pub const DEFAULT_INTERVAL_SECS: u64 = 3600;
+
+    /// Polling interval in daemon mode
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.interval_secs.unwrap_or(Self::DEFAULT_INTERVAL_SECS))
+    }
+
+    /// Read a string parameter
+    pub fn param_str(&self, key: &str) -> Option<&str> {
+        self.params.get(key).and_then(|v| v.as_str())
+    }
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_weight() -> f32 {
+    1.0
+}
+
+/// Registry of all configured news sources
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceRegistryConfig {
+    /// Registered sources
+    #[serde(default)]
+    pub sources: Vec<SourceDefinition>,
+}
+
+impl Default for SourceRegistryConfig {
+    fn default() -> Self {
+        Self {
+            sources: vec![SourceDefinition {
+                name: "hackernews".to_string(),
+                kind: "hackernews".to_string(),
+                params: serde_json::Map::new(),
+                enabled: true,
+                weight: 1.0,
+                interval_secs: None,
+            }],
+        }
+    }
+}
+
+impl SourceRegistryConfig {
+    /// Name that selects every enabled source
+    pub const ALL: &'static str = "all";
+
+    /// Load the registry from a JSON file
+    ///
+    /// A missing file falls back to the default registry (Hacker News only).
+    pub fn load_from_file<P: AsRef<Path>>(
+        path: P,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        if !path.as_ref().exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(path)?;
+        let config: Self = serde_json::from_str(&content)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Check that source names are unique and weights are usable
+    pub fn validate(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut names = HashSet::new();
+        for source in &self.sources {
+            if source.name.eq_ignore_ascii_case(Self::ALL) {
+                return Err(format!("Source name '{}' is reserved", Self::ALL).into());
+            }
+            if !names.insert(source.name.as_str()) {
+                return Err(format!("Duplicate source name '{}'", source.name).into());
+            }
+            if !source.weight.is_finite() || source.weight <= 0.0 {
+                return Err(format!(
+                    "Source '{}' must have a positive weight, got {}",
+                    source.name, source.weight
+                )
+                .into());
+            }
+            if source.interval_secs == Some(0) {
+                return Err(format!(
+                    "Source '{}' must have a positive interval_secs",
+                    source.name
+                )
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Find a source by name
+    pub fn get(&self, name: &str) -> Option<&SourceDefinition> {
+        self.sources.iter().find(|s| s.name == name)
+    }
+
+    /// All enabled sources, in configuration order
+    pub fn enabled(&self) -> impl Iterator<Item = &SourceDefinition> {
+        self.sources.iter().filter(|s| s.enabled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_registry_with_defaults() {
+        let json = r#"{
+            "sources": [
+                { "name": "hackernews", "kind": "hackernews", "interval_secs": 600 },
+                {
+                    "name": "openai-news",
+                    "kind": "rss",
+                    "params": { "url": "https://openai.com/news/rss.xml" },
+                    "enabled": false,
+                    "weight": 0.5
+                }
+            ]
+        }"#;
+
+        let config: SourceRegistryConfig = serde_json::from_str(json).unwrap();
+        config.validate().unwrap();
+
+        let hn = config.get("hackernews").unwrap();
+        assert!(hn.enabled);
+        assert_eq!(hn.weight, 1.0);
+        assert_eq!(hn.interval(), Duration::from_secs(600));
+
+        let feed = config.get("openai-news").unwrap();
+        assert_eq!(
+            feed.interval(),
+            Duration::from_secs(SourceDefinition::DEFAULT_INTERVAL_SECS)
+        );
+        assert_eq!(feed.param_str("url"), Some("https://openai.com/news/rss.xml"));
+        assert_eq!(config.enabled().count(), 1);
+    }
+
+    #[test]
+    fn test_validate_rejects_duplicates_and_reserved_names() {
+        let mut config = SourceRegistryConfig::default();
+        config.sources.push(config.sources[0].clone());
+        assert!(config.validate().is_err());
+
+        let mut config = SourceRegistryConfig::default();
+        config.sources[0].name = "all".to_string();
+        assert!(config.validate().is_err());
+
+        let mut config = SourceRegistryConfig::default();
+        config.sources[0].interval_secs = Some(0);
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_missing_file_yields_default_registry() {
+        let config = SourceRegistryConfig::load_from_file("config/does-not-exist.json").unwrap();
+        assert_eq!(config.sources.len(), 1);
+        assert_eq!(config.sources[0].kind, "hackernews");
+    }
+}
@@ -0,0 +1,142 @@
+//! # Classification Trace
+//!
+//! Records the path a news item took through the classification pipeline:
+//! every stage, whether it ran, what it found and how long it took.
+
+use super::{Domain, DomainLabel};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// What happened in one stage
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StageStatus {
+    /// The stage produced the final result
+    Decided,
+    /// The stage found a result below its threshold and passed it on
+    Candidate,
+    /// The stage ran without a result (e.g. no keyword matched, content fetched)
+    Completed,
+    /// The stage did not run (e.g. no inference service configured)
+    Skipped,
+    /// The stage failed (e.g. content extraction error); classification continued
+    Failed,
+}
+
+/// One stage of a classification trace
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StageTrace {
+    /// Stage name (e.g. "FastPass", "ContentEnrichment", "AI")
+    pub stage: String,
+    pub status: StageStatus,
+    /// Domain found by the stage, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<Domain>,
+    /// Secondary domains found alongside `domain`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub secondary_domains: Vec<DomainLabel>,
+    /// Confidence of the stage's result, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f32>,
+    /// What the stage found, or why it was skipped
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub detail: String,
+    /// Error message when the stage failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Time spent in the stage, in milliseconds
+    pub elapsed_ms: u64,
+}
+
+/// Full decision path of one classification
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassificationTrace {
+    /// When the classification ran
+    pub classified_at: DateTime<Utc>,
+    /// Stages in execution order
+    pub stages: Vec<StageTrace>,
+}
+
+impl ClassificationTrace {
+    /// Start an empty trace
+    pub fn new() -> Self {
+        Self {
+            classified_at: Utc::now(),
+            stages: Vec::new(),
+        }
+    }
+
+    /// Append a stage record
+    pub fn push(&mut self, stage: StageTrace) {
+        self.stages.push(stage);
+    }
+
+    /// The stage that produced the final result, if any
+    pub fn decided_by(&self) -> Option<&StageTrace> {
+        self.stages
+            .iter()
+            .rev()
+            .find(|s| s.status == StageStatus::Decided)
+    }
+
+    /// Serialize for storage
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("classification trace should serialize")
+    }
+
+    /// Parse a stored trace
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+impl Default for ClassificationTrace {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trace_round_trip_and_decided_stage() {
+        let mut trace = ClassificationTrace::new();
+        trace.push(StageTrace {
+            stage: "FastPass".to_string(),
+            status: StageStatus::Candidate,
+            domain: Some(Domain::new("Social")),
+            secondary_domains: Vec::new(),
+            confidence: Some(0.4),
+            detail: "Social score 0.30: 'viral' in title (weak)".to_string(),
+            error: None,
+            elapsed_ms: 0,
+        });
+        trace.push(StageTrace {
+            stage: "ContentEnrichment".to_string(),
+            status: StageStatus::Failed,
+            domain: None,
+            secondary_domains: Vec::new(),
+            confidence: None,
+            detail: String::new(),
+            error: Some("timeout".to_string()),
+            elapsed_ms: 5000,
+        });
+        trace.push(StageTrace {
+            stage: "AI".to_string(),
+            status: StageStatus::Decided,
+            domain: Some(Domain::new("Block")),
+            secondary_domains: vec![DomainLabel::new(Domain::new("AI"), 0.6)],
+            confidence: Some(0.9),
+            detail: "[AI:mock] bitcoin price".to_string(),
+            error: None,
+            elapsed_ms: 120,
+        });
+
+        let json = trace.to_json();
+        assert!(!json.contains("\"error\":null"));
+        let parsed = ClassificationTrace::from_json(&json).unwrap();
+        assert_eq!(parsed, trace);
+        assert_eq!(parsed.decided_by().unwrap().stage, "AI");
+    }
+}
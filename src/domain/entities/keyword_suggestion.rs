@@ -0,0 +1,99 @@
+//! # Keyword Suggestion Entity
+//!
+//! A keyword proposed by AI inference, waiting for a human to decide whether
+//! it should join the classification keyword lists.
+
+use super::Domain;
+use chrono::{DateTime, Utc};
+
+/// Review state of a suggested keyword
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeywordSuggestionStatus {
+    /// Waiting in the inbox
+    Pending,
+    /// Added to the strong keyword list
+    ApprovedStrong,
+    /// Added to the weak keyword list
+    ApprovedWeak,
+    /// Rejected; later suggestions of the same keyword are ignored
+    Rejected,
+}
+
+impl KeywordSuggestionStatus {
+    /// Parse the value stored in the database
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "Pending" => Some(Self::Pending),
+            "ApprovedStrong" => Some(Self::ApprovedStrong),
+            "ApprovedWeak" => Some(Self::ApprovedWeak),
+            "Rejected" => Some(Self::Rejected),
+            _ => None,
+        }
+    }
+}
+
+/// A keyword suggested by AI for a domain, aggregated over all news items
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeywordSuggestion {
+    /// Normalized (trimmed, lowercase) keyword
+    pub keyword: String,
+
+    /// Domain the keyword was suggested for
+    pub domain: Domain,
+
+    /// Review state
+    pub status: KeywordSuggestionStatus,
+
+    /// Number of distinct news items that produced this suggestion
+    pub occurrences: u32,
+
+    /// IDs of the news items that produced this suggestion (most recent first)
+    pub news_ids: Vec<String>,
+
+    /// When the keyword was first suggested
+    pub first_seen_at: DateTime<Utc>,
+
+    /// When the keyword was last suggested
+    pub last_seen_at: DateTime<Utc>,
+}
+
+impl KeywordSuggestion {
+    /// Normalize a raw AI keyword; returns `None` for blank input
+    pub fn normalize(keyword: &str) -> Option<String> {
+        let keyword = keyword.split_whitespace().collect::<Vec<_>>().join(" ");
+        if keyword.is_empty() {
+            None
+        } else {
+            Some(keyword.to_lowercase())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_keyword() {
+        assert_eq!(
+            KeywordSuggestion::normalize("  Vector   Database "),
+            Some("vector database".to_string())
+        );
+        assert_eq!(KeywordSuggestion::normalize(" \t"), None);
+    }
+
+    #[test]
+    fn test_status_round_trip() {
+        for status in [
+            KeywordSuggestionStatus::Pending,
+            KeywordSuggestionStatus::ApprovedStrong,
+            KeywordSuggestionStatus::ApprovedWeak,
+            KeywordSuggestionStatus::Rejected,
+        ] {
+            assert_eq!(
+                KeywordSuggestionStatus::parse(&format!("{:?}", status)),
+                Some(status)
+            );
+        }
+    }
+}
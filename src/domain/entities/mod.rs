@@ -1,33 +1,53 @@
+pub mod classification_trace;
+pub mod keyword_suggestion;
 pub mod news_item;
+pub mod review_label;
+pub mod story_snapshot;
 
+pub use classification_trace::{ClassificationTrace, StageStatus, StageTrace};
+pub use keyword_suggestion::{KeywordSuggestion, KeywordSuggestionStatus};
 pub use news_item::{NewsItem, NewsItemStatus};
+pub use review_label::{ReviewDecision, ReviewLabel};
+pub use story_snapshot::StorySnapshot;
+
+use std::sync::{Mutex, OnceLock};
 
 /// News domain/category
 ///
-/// Represents the three main domains this project focuses on:
-/// - AI: Artificial Intelligence and machine learning news
-/// - Block: Blockchain and cryptocurrency news
-/// - Social: Social media and social platform news
-#[derive(
-    Debug, Clone, Copy, PartialEq, Eq, Hash, clap::ValueEnum, serde::Serialize, serde::Deserialize,
-)]
-pub enum Domain {
-    /// AI-related news (ChatGPT, GPT, LLM, ML, etc.)
-    AI,
-    /// Blockchain-related news (Bitcoin, Ethereum, Web3, Crypto, etc.)
-    Block,
-    /// Social media news (Twitter, Facebook, Instagram, etc.)
-    Social,
-}
+/// Domains are defined in the classification config (see
+/// [`DomainDefinition`](crate::domain::config::DomainDefinition)); the
+/// built-in ones are AI, Block and Social. A `Domain` is the interned name,
+/// so it stays `Copy` and cheap to compare and hash.
+///
+/// Names are case-insensitive: `Domain::new("ai")` returns the same domain
+/// as `Domain::new("AI")`, spelled the way it was first seen.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Domain(&'static str);
 
 impl Domain {
-    /// Get display name for the domain
-    pub fn display_name(&self) -> &str {
-        match self {
-            Domain::AI => "AI",
-            Domain::Block => "Block",
-            Domain::Social => "Social",
+    /// The domain called `name`
+    ///
+    /// Every distinct name is stored once for the lifetime of the process;
+    /// resolve user input against the configured domains first
+    /// (`ClassificationConfig::resolve_domain`) instead of interning it blindly.
+    pub fn new(name: &str) -> Self {
+        static NAMES: OnceLock<Mutex<Vec<&'static str>>> = OnceLock::new();
+        let name = name.trim();
+        let mut names = NAMES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if let Some(&existing) = names.iter().find(|n| n.eq_ignore_ascii_case(name)) {
+            return Domain(existing);
         }
+        let interned: &'static str = Box::leak(name.to_string().into_boxed_str());
+        names.push(interned);
+        Domain(interned)
+    }
+
+    /// Get display name for the domain
+    pub fn display_name(&self) -> &'static str {
+        self.0
     }
 }
 
@@ -37,34 +57,95 @@ impl std::fmt::Display for Domain {
     }
 }
 
+impl std::fmt::Debug for Domain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+impl serde::Serialize for Domain {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Domain {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        if name.trim().is_empty() {
+            return Err(serde::de::Error::custom("domain name must not be empty"));
+        }
+        Ok(Domain::new(&name))
+    }
+}
+
+/// A domain assigned to a news item together with its confidence
+///
+/// Used for secondary domains: an article about an AI trading bot on
+/// Ethereum has AI as its primary domain and `DomainLabel { Block, 0.7 }`
+/// as a secondary one.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DomainLabel {
+    pub domain: Domain,
+    /// Confidence for this domain (0.0 - 1.0)
+    pub confidence: f32,
+}
+
+impl DomainLabel {
+    pub fn new(domain: Domain, confidence: f32) -> Self {
+        Self { domain, confidence }
+    }
+}
+
+impl std::fmt::Display for DomainLabel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({:.2})", self.domain, self.confidence)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_domain_display_names() {
-        assert_eq!(Domain::AI.display_name(), "AI");
-        assert_eq!(Domain::Block.display_name(), "Block");
-        assert_eq!(Domain::Social.display_name(), "Social");
+        assert_eq!(Domain::new("AI").display_name(), "AI");
+        assert_eq!(Domain::new("Block").display_name(), "Block");
+        assert_eq!(Domain::new("Social").display_name(), "Social");
     }
 
     #[test]
     fn test_domain_display_trait() {
-        assert_eq!(format!("{}", Domain::AI), "AI");
-        assert_eq!(format!("{}", Domain::Block), "Block");
-        assert_eq!(format!("{}", Domain::Social), "Social");
+        assert_eq!(format!("{}", Domain::new("AI")), "AI");
+        assert_eq!(format!("{}", Domain::new("Block")), "Block");
+        assert_eq!(format!("{}", Domain::new("Social")), "Social");
     }
 
     #[test]
     fn test_domain_equality() {
-        assert_eq!(Domain::AI, Domain::AI);
-        assert_ne!(Domain::AI, Domain::Block);
+        assert_eq!(Domain::new("AI"), Domain::new("AI"));
+        assert_ne!(Domain::new("AI"), Domain::new("Block"));
     }
 
     #[test]
     fn test_domain_copy() {
-        let domain = Domain::AI;
+        let domain = Domain::new("AI");
         let copied = domain;
         assert_eq!(domain, copied);
     }
+
+    #[test]
+    fn test_domain_names_are_case_insensitive() {
+        let security = Domain::new("Security");
+        assert_eq!(Domain::new("security"), security);
+        assert_eq!(Domain::new(" SECURITY ").display_name(), "Security");
+
+        let json = serde_json::to_string(&security).unwrap();
+        assert_eq!(json, "\"Security\"");
+        assert_eq!(
+            serde_json::from_str::<Domain>("\"security\"").unwrap(),
+            security
+        );
+        assert!(serde_json::from_str::<Domain>("\"\"").is_err());
+    }
 }
@@ -2,10 +2,10 @@
 //!
 //! Represents a single news item from any source.
 
-use super::Domain;
+use super::{ClassificationTrace, Domain, DomainLabel};
 use chrono::{DateTime, Utc};
 
-#[derive(Debug, Clone, PartialEq, Eq, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq, Copy, clap::ValueEnum, serde::Serialize)]
 pub enum NewsItemStatus {
     /// Freshly fetched, not yet classified
     Pending,
@@ -17,10 +17,12 @@ pub enum NewsItemStatus {
     Completed,
     /// Failed to classify
     Failed,
+    /// Classified as not belonging to any tracked domain
+    Irrelevant,
 }
 
 /// A news item that has been aggregated from various sources
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct NewsItem {
     /// Unique identifier for this news item
     pub id: String,
@@ -46,14 +48,38 @@ pub struct NewsItem {
     /// Current status of the news item
     pub status: NewsItemStatus,
 
-    /// The classified domain (optional, set after classification)
+    /// The classified (primary) domain (optional, set after classification)
     pub domain: Option<Domain>,
 
+    /// Further domains the item also belongs to, strongest first
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub secondary_domains: Vec<DomainLabel>,
+
     /// Classification confidence score (0.0 - 1.0)
     pub classification_confidence: Option<f32>,
 
     /// The basis/reason for classification (e.g., "Keyword matched: GPT", "AI analyzed")
     pub classification_reason: Option<String>,
+
+    /// Every pipeline stage the classification went through (see `trendarc show <id>`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classification_trace: Option<ClassificationTrace>,
+
+    /// Source-native popularity score (e.g. Hacker News points)
+    pub score: Option<u32>,
+
+    /// Number of comments in the source-native discussion
+    pub comment_count: Option<u32>,
+
+    /// URL of the source-native discussion page (e.g. the HN comments page)
+    pub discussion_url: Option<String>,
+
+    /// 1-based position in the source's listing at fetch time (not persisted)
+    #[serde(skip)]
+    pub source_rank: Option<u32>,
+
+    /// Story cluster shared with other coverage of the same event
+    pub cluster_id: Option<String>,
 }
 
 impl NewsItem {
@@ -76,8 +102,15 @@ impl NewsItem {
             published_at,
             status: NewsItemStatus::Pending,
             domain: None,
+            secondary_domains: Vec::new(),
             classification_confidence: None,
             classification_reason: None,
+            classification_trace: None,
+            score: None,
+            comment_count: None,
+            discussion_url: None,
+            source_rank: None,
+            cluster_id: None,
         }
     }
 
@@ -102,8 +135,15 @@ impl NewsItem {
             published_at,
             status: NewsItemStatus::Completed,
             domain: Some(domain),
+            secondary_domains: Vec::new(),
             classification_confidence: Some(confidence),
             classification_reason: None,
+            classification_trace: None,
+            score: None,
+            comment_count: None,
+            discussion_url: None,
+            source_rank: None,
+            cluster_id: None,
         }
     }
 
@@ -113,6 +153,42 @@ impl NewsItem {
         self
     }
 
+    /// Update engagement metrics (score and comment count)
+    pub fn with_engagement(mut self, score: Option<u32>, comment_count: Option<u32>) -> Self {
+        self.score = score;
+        self.comment_count = comment_count;
+        self
+    }
+
+    /// Update the source-native discussion URL
+    pub fn with_discussion_url(mut self, discussion_url: String) -> Self {
+        self.discussion_url = Some(discussion_url);
+        self
+    }
+
+    /// Update the position in the source's listing
+    pub fn with_source_rank(mut self, rank: u32) -> Self {
+        self.source_rank = Some(rank);
+        self
+    }
+
+    /// Whether the item carries any engagement metric worth tracking over time
+    pub fn has_engagement(&self) -> bool {
+        self.score.is_some() || self.comment_count.is_some()
+    }
+
+    /// Primary domain followed by secondary domains
+    pub fn domains(&self) -> impl Iterator<Item = Domain> + '_ {
+        self.domain
+            .into_iter()
+            .chain(self.secondary_domains.iter().map(|label| label.domain))
+    }
+
+    /// Whether the item belongs to `domain`, as primary or secondary domain
+    pub fn belongs_to(&self, domain: Domain) -> bool {
+        self.domains().any(|d| d == domain)
+    }
+
     /// Update classification reason
     pub fn with_reason(mut self, reason: String) -> Self {
         self.classification_reason = Some(reason);
@@ -143,5 +219,28 @@ mod tests {
         assert_eq!(news_item.author, "test-author");
         assert_eq!(news_item.published_at, published_at);
         assert!(news_item.classification_reason.is_none());
+        assert!(news_item.score.is_none());
+        assert!(news_item.comment_count.is_none());
+    }
+
+    #[test]
+    fn test_news_item_engagement() {
+        let news_item = NewsItem::new(
+            "42".to_string(),
+            "Test Title".to_string(),
+            "https://example.com/test".to_string(),
+            "hackernews".to_string(),
+            "test-author".to_string(),
+            Utc::now(),
+        )
+        .with_engagement(Some(120), Some(35))
+        .with_discussion_url("https://news.ycombinator.com/item?id=42".to_string());
+
+        assert_eq!(news_item.score, Some(120));
+        assert_eq!(news_item.comment_count, Some(35));
+        assert_eq!(
+            news_item.discussion_url.as_deref(),
+            Some("https://news.ycombinator.com/item?id=42")
+        );
     }
 }
@@ -0,0 +1,171 @@
+//! # Review Label Entity
+//!
+//! A human decision about a news item's domain, kept as ground truth for
+//! evaluating and tuning the classifier.
+
+use super::{ClassificationTrace, Domain, NewsItem, NewsItemStatus, StageStatus, StageTrace};
+use chrono::{DateTime, Utc};
+
+/// What the reviewer decided
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewDecision {
+    /// The predicted domain is correct
+    Accepted,
+    /// The item belongs to a different domain
+    Relabeled,
+    /// The item does not belong to any tracked domain
+    Irrelevant,
+}
+
+/// Ground-truth label recorded by a human reviewer
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReviewLabel {
+    /// ID of the reviewed news item
+    pub news_id: String,
+
+    /// The reviewer's decision
+    pub decision: ReviewDecision,
+
+    /// The correct domain (`None` when irrelevant)
+    pub domain: Option<Domain>,
+
+    /// Domain predicted by the classifier at review time
+    pub predicted_domain: Option<Domain>,
+
+    /// Confidence of the prediction at review time
+    pub predicted_confidence: Option<f32>,
+
+    /// When the decision was made
+    pub reviewed_at: DateTime<Utc>,
+}
+
+impl ReviewLabel {
+    /// Confirm the predicted domain of `news`
+    pub fn accept(news: &NewsItem) -> Self {
+        Self::new(news, ReviewDecision::Accepted, news.domain)
+    }
+
+    /// Assign `domain` to `news`; same as accepting when it matches the prediction
+    pub fn relabel(news: &NewsItem, domain: Domain) -> Self {
+        if news.domain == Some(domain) {
+            return Self::accept(news);
+        }
+        Self::new(news, ReviewDecision::Relabeled, Some(domain))
+    }
+
+    /// Mark `news` as not belonging to any tracked domain
+    pub fn irrelevant(news: &NewsItem) -> Self {
+        Self::new(news, ReviewDecision::Irrelevant, None)
+    }
+
+    fn new(news: &NewsItem, decision: ReviewDecision, domain: Option<Domain>) -> Self {
+        Self {
+            news_id: news.id.clone(),
+            decision,
+            domain,
+            predicted_domain: news.domain,
+            predicted_confidence: news.classification_confidence,
+            reviewed_at: Utc::now(),
+        }
+    }
+
+    /// Overwrite the classification of `news` with this decision
+    ///
+    /// The decision is appended to the classification trace, so the automatic
+    /// stages stay visible next to the human override.
+    pub fn apply_to(&self, news: &mut NewsItem) {
+        news.domain = self.domain;
+        match self.decision {
+            ReviewDecision::Irrelevant => {
+                news.secondary_domains.clear();
+                news.classification_confidence = Some(0.0);
+                news.classification_reason = Some("[HumanReview] irrelevant".to_string());
+                news.status = NewsItemStatus::Irrelevant;
+            }
+            ReviewDecision::Accepted | ReviewDecision::Relabeled => {
+                news.classification_confidence = Some(1.0);
+                news.classification_reason = Some(format!(
+                    "[HumanReview] {}",
+                    if self.decision == ReviewDecision::Accepted {
+                        "accepted"
+                    } else {
+                        "relabeled"
+                    }
+                ));
+                news.status = NewsItemStatus::Completed;
+                // A relabel may promote a secondary domain to primary
+                news.secondary_domains
+                    .retain(|label| Some(label.domain) != self.domain);
+            }
+        }
+
+        news.classification_trace
+            .get_or_insert_with(ClassificationTrace::new)
+            .push(StageTrace {
+                stage: "HumanReview".to_string(),
+                status: StageStatus::Decided,
+                domain: self.domain,
+                secondary_domains: news.secondary_domains.clone(),
+                confidence: news.classification_confidence,
+                detail: format!("{:?}", self.decision).to_lowercase(),
+                error: None,
+                elapsed_ms: 0,
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn needs_review() -> NewsItem {
+        let mut news = NewsItem::new_with_classification(
+            "1".to_string(),
+            "Title".to_string(),
+            "https://example.com".to_string(),
+            "hackernews".to_string(),
+            "author".to_string(),
+            Utc::now(),
+            Domain::new("AI"),
+            0.4,
+        );
+        news.status = NewsItemStatus::NeedsReview;
+        news
+    }
+
+    #[test]
+    fn test_relabel_to_predicted_domain_is_accept() {
+        let news = needs_review();
+        assert_eq!(
+            ReviewLabel::relabel(&news, Domain::new("AI")).decision,
+            ReviewDecision::Accepted
+        );
+
+        let label = ReviewLabel::relabel(&news, Domain::new("Block"));
+        assert_eq!(label.decision, ReviewDecision::Relabeled);
+        assert_eq!(label.predicted_domain, Some(Domain::new("AI")));
+        assert_eq!(label.predicted_confidence, Some(0.4));
+    }
+
+    #[test]
+    fn test_apply_to_overwrites_classification() {
+        let mut news = needs_review();
+        ReviewLabel::relabel(&news, Domain::new("Block")).apply_to(&mut news);
+        assert_eq!(news.domain, Some(Domain::new("Block")));
+        assert_eq!(news.status, NewsItemStatus::Completed);
+        assert_eq!(news.classification_confidence, Some(1.0));
+        let decided = news
+            .classification_trace
+            .as_ref()
+            .unwrap()
+            .decided_by()
+            .unwrap();
+        assert_eq!(decided.stage, "HumanReview");
+        assert_eq!(decided.detail, "relabeled");
+
+        let mut news = needs_review();
+        ReviewLabel::irrelevant(&news).apply_to(&mut news);
+        assert_eq!(news.domain, None);
+        assert_eq!(news.status, NewsItemStatus::Irrelevant);
+    }
+}
@@ -0,0 +1,66 @@
+//! # Story Snapshot Entity
+//!
+//! A point-in-time observation of a stored story's engagement, used to
+//! compute how fast a story is gaining points.
+
+use super::NewsItem;
+use chrono::{DateTime, Utc};
+
+/// Engagement of a stored news item at the moment it was observed
+#[derive(Debug, Clone, PartialEq)]
+pub struct StorySnapshot {
+    /// ID of the stored news item
+    pub news_id: String,
+
+    /// When the observation was made
+    pub captured_at: DateTime<Utc>,
+
+    /// Score at that moment
+    pub score: Option<u32>,
+
+    /// Comment count at that moment
+    pub comment_count: Option<u32>,
+
+    /// 1-based position in the source's listing at that moment
+    pub rank: Option<u32>,
+}
+
+impl StorySnapshot {
+    /// Capture the engagement of a freshly fetched item for the stored item `news_id`
+    pub fn capture(news_id: String, news: &NewsItem, captured_at: DateTime<Utc>) -> Self {
+        Self {
+            news_id,
+            captured_at,
+            score: news.score,
+            comment_count: news.comment_count,
+            rank: news.source_rank,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capture_copies_engagement() {
+        let now = Utc::now();
+        let news = NewsItem::new(
+            "fetched-id".to_string(),
+            "Title".to_string(),
+            "https://example.com".to_string(),
+            "hackernews".to_string(),
+            "author".to_string(),
+            now,
+        )
+        .with_engagement(Some(300), Some(42))
+        .with_source_rank(3);
+
+        let snapshot = StorySnapshot::capture("stored-id".to_string(), &news, now);
+
+        assert_eq!(snapshot.news_id, "stored-id");
+        assert_eq!(snapshot.score, Some(300));
+        assert_eq!(snapshot.comment_count, Some(42));
+        assert_eq!(snapshot.rank, Some(3));
+    }
+}
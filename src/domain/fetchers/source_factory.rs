@@ -1,38 +1,124 @@
 use crate::cli::DataSource;
-use crate::infrastructure::news_sources::HackerNewsSource;
+use crate::domain::config::{SourceDefinition, SourceRegistryConfig};
+use crate::infrastructure::news_sources::{HackerNewsSource, RssFeedSource};
 use crate::domain::NewsFetcher;
 use std::sync::Arc;
+use std::time::Duration;
+
+/// 带轮询间隔的数据源（daemon 模式使用）
+pub type ScheduledFetcher = (Arc<dyn NewsFetcher>, Duration);
 
 /// 数据源工厂
+///
+/// 数据源由 config/sources.json 注册表驱动，新增或下线数据源无需重新编译
 pub struct NewsSourceFactory;
 
 impl NewsSourceFactory {
-    /// 根据 DataSource 枚举创建对应的 NewsFetcher
-    pub fn create(source: DataSource) -> Arc<dyn NewsFetcher> {
+    /// 根据 DataSource 创建对应的 NewsFetcher
+    ///
+    /// - `DataSource::All`：由所有已启用的数据源组成 `CompositeNewsFetcher`，按权重分配配额
+    /// - `DataSource::Named`：按名称创建单个已注册数据源（即使该数据源未启用）
+    pub fn create(
+        source: &DataSource,
+        registry: &SourceRegistryConfig,
+    ) -> Result<Arc<dyn NewsFetcher>, Box<dyn std::error::Error + Send + Sync>> {
         match source {
             DataSource::All => {
-                // 当前只有 HackerNews，为未来扩展预留
-                let fetchers = vec![
-                    Arc::new(HackerNewsSource::new()) as Arc<dyn NewsFetcher>
-                ];
-                Arc::new(CompositeNewsFetcher::new(fetchers))
+                let fetchers = registry
+                    .enabled()
+                    .map(|def| Ok((Self::build(def)?, def.weight)))
+                    .collect::<Result<Vec<_>, Box<dyn std::error::Error + Send + Sync>>>()?;
+                if fetchers.is_empty() {
+                    return Err("没有已启用的数据源，请检查 config/sources.json".into());
+                }
+                Ok(Arc::new(CompositeNewsFetcher::with_weights(fetchers)))
             }
-            DataSource::HackerNews => {
-                Arc::new(HackerNewsSource::new())
+            DataSource::Named(name) => {
+                let def = registry.get(name).ok_or_else(|| {
+                    let known = registry
+                        .sources
+                        .iter()
+                        .map(|s| s.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    format!("未注册的数据源 '{}'（可用: all, {}）", name, known)
+                })?;
+                Self::build(def)
             }
         }
     }
+
+    /// 为 daemon 模式逐个创建数据源及其轮询间隔
+    ///
+    /// 与 `create` 不同，`DataSource::All` 不会合并成组合数据源，每个已启用的数据源单独调度
+    pub fn create_scheduled(
+        source: &DataSource,
+        registry: &SourceRegistryConfig,
+    ) -> Result<Vec<ScheduledFetcher>, Box<dyn std::error::Error + Send + Sync>> {
+        let defs: Vec<&SourceDefinition> = match source {
+            DataSource::All => registry.enabled().collect(),
+            DataSource::Named(name) => vec![
+                registry
+                    .get(name)
+                    .ok_or_else(|| format!("未注册的数据源 '{}'", name))?,
+            ],
+        };
+        if defs.is_empty() {
+            return Err("没有已启用的数据源，请检查 config/sources.json".into());
+        }
+
+        defs.into_iter()
+            .map(|def| Ok((Self::build(def)?, def.interval())))
+            .collect()
+    }
+
+    /// 根据数据源类型（kind）构建具体的 fetcher
+    fn build(
+        def: &SourceDefinition,
+    ) -> Result<Arc<dyn NewsFetcher>, Box<dyn std::error::Error + Send + Sync>> {
+        match def.kind.as_str() {
+            "hackernews" => {
+                let mut source = HackerNewsSource::new();
+                if let Some(api_base) = def.param_str("api_base") {
+                    source = source.with_api_base(api_base);
+                }
+                Ok(Arc::new(source))
+            }
+            "rss" | "atom" => {
+                let url = def
+                    .param_str("url")
+                    .ok_or_else(|| format!("数据源 '{}' 缺少参数 url", def.name))?;
+                Ok(Arc::new(RssFeedSource::new(def.name.clone(), url)))
+            }
+            other => Err(format!("数据源 '{}' 的类型 '{}' 不受支持", def.name, other).into()),
+        }
+    }
 }
 
 /// 组合数据源，支持从多个数据源并发抓取
 pub struct CompositeNewsFetcher {
     fetchers: Vec<Arc<dyn NewsFetcher>>,
+    /// 各数据源的权重，与 fetchers 一一对应
+    weights: Vec<f32>,
 }
 
 impl CompositeNewsFetcher {
-    /// 创建新的组合数据源
-    pub fn new(fetchers: Vec<Arc<dyn NewsFetcher>>) -> Self {
-        Self { fetchers }
+    /// 创建带权重的组合数据源，首轮抓取配额按权重比例分配
+    pub fn with_weights(weighted: Vec<(Arc<dyn NewsFetcher>, f32)>) -> Self {
+        let (fetchers, weights) = weighted.into_iter().unzip();
+        Self { fetchers, weights }
+    }
+
+    /// 按权重计算每个数据源的首轮配额
+    fn quotas(&self, limit: usize) -> Vec<usize> {
+        let total: f32 = self.weights.iter().sum();
+        if total <= 0.0 {
+            return vec![0; self.fetchers.len()];
+        }
+        self.weights
+            .iter()
+            .map(|w| (limit as f32 * w / total).ceil() as usize)
+            .collect()
     }
 }
 
@@ -55,17 +141,13 @@ impl NewsFetcher for CompositeNewsFetcher {
         let mut all_results = Vec::new();
         let mut remaining_limit = limit;
         
-        // 基础分配：每个数据源分配基础配额
-        let base_per_source = if !self.fetchers.is_empty() {
-            (limit as f32 / self.fetchers.len() as f32).ceil() as usize
-        } else {
-            0
-        };
+        // 基础分配：按权重为每个数据源分配基础配额
+        let quotas = self.quotas(limit);
         
         // 第一轮抓取
         let mut futures = Vec::new();
-        for fetcher in &self.fetchers {
-            let fetch_limit = base_per_source.min(remaining_limit);
+        for (fetcher, quota) in self.fetchers.iter().zip(quotas) {
+            let fetch_limit = quota.min(remaining_limit);
             if fetch_limit > 0 {
                 futures.push(fetcher.fetch(fetch_limit));
             }
@@ -126,4 +208,109 @@ impl NewsFetcher for CompositeNewsFetcher {
     fn source_name(&self) -> &str {
         "composite"
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use std::sync::Mutex;
+
+    /// 记录被请求数量的 Mock 数据源
+    struct RecordingFetcher {
+        name: String,
+        requested: Mutex<Vec<usize>>,
+    }
+
+    impl RecordingFetcher {
+        fn new(name: &str) -> Arc<Self> {
+            Arc::new(Self {
+                name: name.to_string(),
+                requested: Mutex::new(Vec::new()),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl NewsFetcher for RecordingFetcher {
+        async fn fetch(
+            &self,
+            limit: usize,
+        ) -> Result<Vec<NewsItem>, Box<dyn std::error::Error + Send + Sync>> {
+            self.requested.lock().unwrap().push(limit);
+            Ok((0..limit)
+                .map(|i| {
+                    NewsItem::new(
+                        format!("{}-{}", self.name, i),
+                        format!("{} {}", self.name, i),
+                        format!("https://{}.example.com/{}", self.name, i),
+                        self.name.clone(),
+                        "author".to_string(),
+                        Utc::now(),
+                    )
+                })
+                .collect())
+        }
+
+        fn source_name(&self) -> &str {
+            &self.name
+        }
+    }
+
+    fn registry(json: &str) -> SourceRegistryConfig {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_composite_allocates_quota_by_weight() {
+        let heavy = RecordingFetcher::new("heavy");
+        let light = RecordingFetcher::new("light");
+        let composite = CompositeNewsFetcher::with_weights(vec![
+            (heavy.clone() as Arc<dyn NewsFetcher>, 3.0),
+            (light.clone() as Arc<dyn NewsFetcher>, 1.0),
+        ]);
+
+        let items = composite.fetch(8).await.unwrap();
+
+        assert_eq!(items.len(), 8);
+        assert_eq!(heavy.requested.lock().unwrap()[0], 6);
+        assert_eq!(light.requested.lock().unwrap()[0], 2);
+    }
+
+    #[test]
+    fn test_create_named_source() {
+        let registry = registry(
+            r#"{ "sources": [
+                { "name": "ai-blog", "kind": "rss", "params": { "url": "https://example.com/rss" }, "enabled": false }
+            ] }"#,
+        );
+
+        let fetcher =
+            NewsSourceFactory::create(&DataSource::Named("ai-blog".to_string()), &registry)
+                .unwrap();
+        assert_eq!(fetcher.source_name(), "ai-blog");
+    }
+
+    #[test]
+    fn test_create_rejects_unknown_name_and_kind() {
+        let registry = registry(
+            r#"{ "sources": [
+                { "name": "mystery", "kind": "carrier-pigeon" },
+                { "name": "broken-feed", "kind": "rss" }
+            ] }"#,
+        );
+
+        for name in ["nope", "mystery", "broken-feed"] {
+            let result = NewsSourceFactory::create(&DataSource::Named(name.to_string()), &registry);
+            assert!(result.is_err(), "{} should not be buildable", name);
+        }
+    }
+
+    #[test]
+    fn test_create_all_requires_enabled_source() {
+        let registry = registry(
+            r#"{ "sources": [ { "name": "hackernews", "kind": "hackernews", "enabled": false } ] }"#,
+        );
+
+        assert!(NewsSourceFactory::create(&DataSource::All, &registry).is_err());
+    }
+}
@@ -6,8 +6,16 @@ pub mod services;
 pub mod strategies;
 
 // 重新导出常用的类型，方便使用
-pub use entities::{Domain, NewsItem, NewsItemStatus};
+pub use entities::{
+    ClassificationTrace, Domain, DomainLabel, KeywordSuggestion, KeywordSuggestionStatus, NewsItem,
+    NewsItemStatus, ReviewDecision, ReviewLabel, StageStatus, StageTrace, StorySnapshot,
+};
 pub use fetchers::NewsFetcher;
-pub use repositories::NewsRepository;
-pub use services::{NewsClassificationService, NewsDeduplicationService, NewsSortingService};
+pub use repositories::{
+    KeywordSuggestionRepository, NewsCursor, NewsQuery, NewsQueryOrder, NewsRepository, SearchHit,
+};
+pub use services::{
+    NewsClassificationService, NewsDeduplicationService, NewsSortingService, SortOrder,
+    StoryClusteringService, StoryVelocityService, UrlCanonicalizer,
+};
 pub use strategies::{ClassificationStrategy, KeywordBasedStrategy};
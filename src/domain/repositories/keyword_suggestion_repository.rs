@@ -0,0 +1,35 @@
+use crate::domain::{Domain, KeywordSuggestion, KeywordSuggestionStatus};
+use async_trait::async_trait;
+
+/// AI 建议关键词的仓库接口（关键词收件箱）
+///
+/// 每个（关键词, 领域）只保存一条记录，累计出现次数和来源新闻；
+/// 人工审核后记录状态，被拒绝的关键词以后不会再进入收件箱。
+#[async_trait]
+pub trait KeywordSuggestionRepository: Send + Sync {
+    /// 记录一条新闻产生的建议关键词
+    ///
+    /// 关键词会先规范化；同一条新闻重复记录同一关键词时不重复计数。
+    /// 已审核过的关键词只更新计数，不会回到待审核状态。
+    async fn record(
+        &self,
+        domain: Domain,
+        news_id: &str,
+        keywords: &[String],
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// 查询待审核的建议，按出现次数从多到少排序
+    async fn find_pending(
+        &self,
+        domain: Option<Domain>,
+        limit: usize,
+    ) -> Result<Vec<KeywordSuggestion>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// 更新建议的审核状态
+    async fn set_status(
+        &self,
+        keyword: &str,
+        domain: Domain,
+        status: KeywordSuggestionStatus,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+}
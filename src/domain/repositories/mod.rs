@@ -1,3 +1,7 @@
+pub mod keyword_suggestion_repository;
+pub mod news_query;
 pub mod news_repository;
 
-pub use news_repository::NewsRepository;
\ No newline at end of file
+pub use keyword_suggestion_repository::KeywordSuggestionRepository;
+pub use news_query::{NewsCursor, NewsQuery, NewsQueryOrder, SearchHit};
+pub use news_repository::NewsRepository;
@@ -0,0 +1,284 @@
+use crate::domain::{Domain, NewsItem, NewsItemStatus, SortOrder};
+use chrono::{DateTime, Utc};
+
+/// 新闻查询条件
+///
+/// 所有过滤条件之间是"与"关系，集合类条件内部是"或"关系，未设置的条件不参与过滤；
+/// 结果按 `order` 排序，再按 `cursor` 或 `offset` / `limit` 分页。
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewsQuery {
+    /// 返回数量上限
+    pub limit: usize,
+    /// 跳过的条数（分页）
+    pub offset: usize,
+    /// 游标分页：只返回排在该位置之后的新闻（仅支持按发布时间排序）
+    pub cursor: Option<NewsCursor>,
+    /// 排序方式
+    pub order: NewsQueryOrder,
+    /// 只返回这些领域的新闻（为空表示不限）
+    pub domains: Vec<Domain>,
+    /// 只返回这些来源的新闻（为空表示不限）
+    pub sources: Vec<String>,
+    /// 只返回该作者的新闻
+    pub author: Option<String>,
+    /// 只返回这些状态的新闻（为空表示不限）
+    pub statuses: Vec<NewsItemStatus>,
+    /// 最低分类置信度（包含）
+    pub min_confidence: Option<f32>,
+    /// 最高分类置信度（包含）
+    pub max_confidence: Option<f32>,
+    /// 只返回置信度低于该值（不包含）或尚未分类（置信度为空）的新闻
+    pub confidence_below: Option<f32>,
+    /// 发布时间下限（包含）
+    pub published_after: Option<DateTime<Utc>>,
+    /// 发布时间上限（不包含）
+    pub published_before: Option<DateTime<Utc>>,
+    /// 标题或正文包含该文本（不区分大小写）
+    pub text: Option<String>,
+    /// 排除已有人工审核标签的新闻
+    pub exclude_reviewed: bool,
+}
+
+/// 数据库查询的排序方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NewsQueryOrder {
+    /// 发布时间从新到旧
+    #[default]
+    Newest,
+    /// 发布时间从旧到新
+    Oldest,
+    /// 分类置信度从高到低
+    Confidence,
+    /// 来源热度分数从高到低
+    Score,
+    /// 按来源名称分组，组内发布时间从新到旧
+    Source,
+}
+
+impl NewsQueryOrder {
+    /// 是否按发布时间排序（游标分页只支持这两种排序）
+    pub fn is_by_published_at(&self) -> bool {
+        matches!(self, NewsQueryOrder::Newest | NewsQueryOrder::Oldest)
+    }
+
+    /// 对应的数据库排序方式
+    ///
+    /// `Hotness` 随时间衰减，无法在 SQL 中排序，返回 `None`
+    pub fn from_sort_order(order: SortOrder) -> Option<Self> {
+        match order {
+            SortOrder::Recency => Some(NewsQueryOrder::Newest),
+            SortOrder::Confidence => Some(NewsQueryOrder::Confidence),
+            SortOrder::Source => Some(NewsQueryOrder::Source),
+            SortOrder::Hotness => None,
+        }
+    }
+}
+
+impl std::str::FromStr for NewsQueryOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "newest" => Ok(NewsQueryOrder::Newest),
+            "oldest" => Ok(NewsQueryOrder::Oldest),
+            "confidence" => Ok(NewsQueryOrder::Confidence),
+            "score" => Ok(NewsQueryOrder::Score),
+            "source" => Ok(NewsQueryOrder::Source),
+            _ => Err(format!("未知排序方式: {}", s)),
+        }
+    }
+}
+
+/// 游标分页的位置：上一页最后一条新闻的发布时间和 ID
+///
+/// 文本形式为 `<RFC3339 时间>/<新闻 ID>`，可以原样传回给下一次查询。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewsCursor {
+    pub published_at: DateTime<Utc>,
+    pub id: String,
+}
+
+impl NewsCursor {
+    /// 以某条新闻为游标（下一页从它之后开始）
+    pub fn after(news: &NewsItem) -> Self {
+        Self {
+            published_at: news.published_at,
+            id: news.id.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for NewsCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.published_at.to_rfc3339(), self.id)
+    }
+}
+
+impl std::str::FromStr for NewsCursor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (time, id) = s
+            .split_once('/')
+            .ok_or_else(|| format!("无效的游标: {}", s))?;
+        let published_at = DateTime::parse_from_rfc3339(time)
+            .map_err(|e| format!("无效的游标时间 {}: {}", time, e))?
+            .with_timezone(&Utc);
+        if id.is_empty() {
+            return Err(format!("无效的游标: {}", s));
+        }
+        Ok(Self {
+            published_at,
+            id: id.to_string(),
+        })
+    }
+}
+
+impl Default for NewsQuery {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_LIMIT)
+    }
+}
+
+impl NewsQuery {
+    /// 默认返回数量
+    pub const DEFAULT_LIMIT: usize = 20;
+
+    /// 创建只限制数量的查询
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            offset: 0,
+            cursor: None,
+            order: NewsQueryOrder::default(),
+            domains: Vec::new(),
+            sources: Vec::new(),
+            author: None,
+            statuses: Vec::new(),
+            min_confidence: None,
+            max_confidence: None,
+            confidence_below: None,
+            published_after: None,
+            published_before: None,
+            text: None,
+            exclude_reviewed: false,
+        }
+    }
+
+    /// 设置分页偏移
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// 按页码分页（从 1 开始，每页 `limit` 条）
+    pub fn with_page(self, page: usize) -> Self {
+        let offset = page.saturating_sub(1) * self.limit;
+        self.with_offset(offset)
+    }
+
+    /// 设置游标分页位置
+    pub fn with_cursor(mut self, cursor: NewsCursor) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// 设置排序方式
+    pub fn with_order(mut self, order: NewsQueryOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// 设置领域过滤
+    pub fn with_domains(mut self, domains: Vec<Domain>) -> Self {
+        self.domains = domains;
+        self
+    }
+
+    /// 设置来源过滤
+    pub fn with_sources(mut self, sources: Vec<String>) -> Self {
+        self.sources = sources;
+        self
+    }
+
+    /// 设置作者过滤
+    pub fn with_author(mut self, author: impl Into<String>) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    /// 设置状态过滤
+    pub fn with_statuses(mut self, statuses: Vec<NewsItemStatus>) -> Self {
+        self.statuses = statuses;
+        self
+    }
+
+    /// 设置分类置信度范围（两端都包含）
+    pub fn confidence_between(mut self, min: Option<f32>, max: Option<f32>) -> Self {
+        self.min_confidence = min;
+        self.max_confidence = max;
+        self
+    }
+
+    /// 只返回置信度低于 `threshold`（不包含）或从未分类的新闻
+    pub fn with_confidence_below(mut self, threshold: f32) -> Self {
+        self.confidence_below = Some(threshold);
+        self
+    }
+
+    /// 设置发布时间范围（`after` 包含，`before` 不包含）
+    pub fn published_between(
+        mut self,
+        after: Option<DateTime<Utc>>,
+        before: Option<DateTime<Utc>>,
+    ) -> Self {
+        self.published_after = after;
+        self.published_before = before;
+        self
+    }
+
+    /// 设置标题或正文包含的文本
+    pub fn containing(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// 排除已有人工审核标签的新闻
+    pub fn without_reviewed(mut self) -> Self {
+        self.exclude_reviewed = true;
+        self
+    }
+}
+
+/// 全文检索的一条结果
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    /// 命中的新闻
+    pub news: NewsItem,
+    /// 命中片段，匹配词用 `[` `]` 标出
+    pub snippet: String,
+    /// 相关度（bm25 原始值，越小越相关）
+    pub relevance: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_translates_to_offset() {
+        assert_eq!(NewsQuery::new(10).with_page(1).offset, 0);
+        assert_eq!(NewsQuery::new(10).with_page(3).offset, 20);
+        assert_eq!(NewsQuery::new(10).with_page(0).offset, 0);
+    }
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let cursor: NewsCursor = "2025-01-02T03:04:05+00:00/hn-42".parse().unwrap();
+        assert_eq!(cursor.id, "hn-42");
+        assert_eq!(cursor.to_string().parse::<NewsCursor>().unwrap(), cursor);
+
+        assert!("hn-42".parse::<NewsCursor>().is_err());
+        assert!("2025-01-02T03:04:05+00:00/".parse::<NewsCursor>().is_err());
+    }
+}
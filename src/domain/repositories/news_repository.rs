@@ -1,5 +1,8 @@
-use crate::domain::{Domain, NewsItem};
+use crate::domain::repositories::{NewsQuery, SearchHit};
+use crate::domain::{Domain, NewsItem, ReviewLabel, StorySnapshot};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
 
 /// 新闻仓库接口
 ///
@@ -17,6 +20,23 @@ pub trait NewsRepository: Send + Sync {
         news_items: &[NewsItem],
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
 
+    /// 原地更新新闻的分类结果（领域、置信度、分类依据和状态）
+    ///
+    /// 不存在的新闻会被忽略
+    async fn update_classifications(
+        &self,
+        news_items: &[NewsItem],
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// 保存人工审核结果：记录审核标签，并用审核后的分类覆盖新闻
+    ///
+    /// `news` 应已通过 `ReviewLabel::apply_to` 应用了该标签；同一新闻再次审核时覆盖旧标签
+    async fn save_review(
+        &self,
+        news: &NewsItem,
+        label: &ReviewLabel,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
     /// 根据 ID 查询新闻
     async fn find_by_id(
         &self,
@@ -43,17 +63,51 @@ pub trait NewsRepository: Send + Sync {
         limit: usize,
     ) -> Result<Vec<NewsItem>, Box<dyn std::error::Error + Send + Sync>>;
 
-    /// 根据 URL 查询新闻（用于去重）
+    /// 按组合条件查询新闻（按发布时间降序，支持分页）
+    async fn find(
+        &self,
+        query: &NewsQuery,
+    ) -> Result<Vec<NewsItem>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// 全文检索标题、正文和分类依据（按相关度排序，附带高亮片段）
+    async fn search(
+        &self,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<SearchHit>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// 根据 URL 查询新闻（用于去重，按规范化 URL 比较）
     async fn find_by_url(
         &self,
         url: &str,
     ) -> Result<Option<NewsItem>, Box<dyn std::error::Error + Send + Sync>>;
 
-    /// 批量检查 URL 是否已存在
-    async fn find_existing_urls(
+    /// 批量查询已存在 URL 对应的新闻 ID（传入的 URL -> ID）
+    ///
+    /// URL 按规范化形式比较（见 `UrlCanonicalizer`），`http://` 与 `https://`、
+    /// 跟踪参数等差异不会被视为不同的新闻。
+    async fn find_ids_by_urls(
         &self,
         urls: &[String],
-    ) -> Result<Vec<String>, Box<dyn std::error::Error + Send + Sync>>;
+    ) -> Result<HashMap<String, String>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// 追加热度快照，并将新闻的最新分数 / 评论数同步为快照中的值
+    async fn append_snapshots(
+        &self,
+        snapshots: &[StorySnapshot],
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// 查询某条新闻的全部快照（按时间升序）
+    async fn find_snapshots(
+        &self,
+        news_id: &str,
+    ) -> Result<Vec<StorySnapshot>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// 查询某时间点之后的所有快照（按新闻、时间升序）
+    async fn find_snapshots_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<StorySnapshot>, Box<dyn std::error::Error + Send + Sync>>;
 
     /// 统计新闻总数
     async fn count(&self) -> Result<usize, Box<dyn std::error::Error + Send + Sync>>;
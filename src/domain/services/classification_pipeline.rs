@@ -0,0 +1,539 @@
+use crate::domain::config::{ClassificationConfig, StageConfig};
+use crate::domain::services::{ContentExtractor, InferenceResult, NewsInferenceService};
+use crate::domain::strategies::source_based_strategy::SourceBasedStrategy;
+use crate::domain::{
+    ClassificationStrategy, ClassificationTrace, Domain, DomainLabel, KeywordBasedStrategy,
+    KeywordSuggestionRepository, NewsItem, StageStatus, StageTrace,
+};
+use async_trait::async_trait;
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+
+/// 分类结果（替代裸元组，提升可读性和可维护性）
+#[derive(Debug, Clone)]
+pub struct ClassificationOutcome {
+    pub domain: Option<Domain>,
+    /// 次要领域（按置信度从高到低）
+    pub secondary_domains: Vec<DomainLabel>,
+    pub confidence: f32,
+    pub reason: String,
+    pub is_relevant: bool,
+    /// 结果不够可靠，需要人工审核
+    pub needs_review: bool,
+    /// 各阶段的执行记录
+    pub trace: ClassificationTrace,
+}
+
+impl ClassificationOutcome {
+    /// 判定为相关的结果
+    fn relevant(domain: Domain, confidence: f32, reason: String, needs_review: bool) -> Self {
+        Self {
+            domain: Some(domain),
+            secondary_domains: Vec::new(),
+            confidence,
+            reason,
+            is_relevant: true,
+            needs_review,
+            trace: ClassificationTrace::new(),
+        }
+    }
+
+    /// 判定为无关的结果
+    pub fn irrelevant(reason: String) -> Self {
+        Self {
+            domain: None,
+            secondary_domains: Vec::new(),
+            confidence: 0.0,
+            reason,
+            is_relevant: false,
+            needs_review: false,
+            trace: ClassificationTrace::new(),
+        }
+    }
+
+    /// 设置次要领域（去掉与主领域相同的项）
+    fn with_secondary_domains(mut self, secondary_domains: Vec<DomainLabel>) -> Self {
+        self.secondary_domains = secondary_domains
+            .into_iter()
+            .filter(|label| Some(label.domain) != self.domain)
+            .collect();
+        self
+    }
+}
+
+/// 尚未达到阈值的最佳候选（供兜底阶段使用）
+#[derive(Debug, Clone)]
+pub struct Candidate {
+    pub domain: Domain,
+    pub secondary_domains: Vec<DomainLabel>,
+    pub confidence: f32,
+    pub reason: String,
+}
+
+/// 一条新闻流经流水线时的状态，以及各阶段可用的依赖
+pub struct StageContext<'a> {
+    /// 原始新闻
+    pub news: &'a NewsItem,
+    /// 补充了正文的新闻（正文抓取阶段之后才有新内容）
+    pub augmented: NewsItem,
+    /// 目前为止的最佳候选
+    pub best: Option<Candidate>,
+    pub config: &'a ClassificationConfig,
+    pub strategy: &'a KeywordBasedStrategy,
+    pub extractor: &'a dyn ContentExtractor,
+    pub inference_service: Option<&'a dyn NewsInferenceService>,
+    pub keyword_suggestions: Option<&'a dyn KeywordSuggestionRepository>,
+    /// 批量推理得到的结果，AI 阶段优先使用它而不再单独请求
+    pub prefetched_inference: Option<Result<InferenceResult, String>>,
+}
+
+impl StageContext<'_> {
+    /// 置信度更高时替换最佳候选
+    fn offer(&mut self, candidate: Candidate) {
+        if self
+            .best
+            .as_ref()
+            .is_none_or(|b| candidate.confidence > b.confidence)
+        {
+            self.best = Some(candidate);
+        }
+    }
+}
+
+/// 一个阶段的执行结果（同时写入分类轨迹）
+pub enum StageOutput {
+    /// 得出最终结果，流水线到此结束
+    Decided(ClassificationOutcome),
+    /// 结果未达阈值，作为候选交给后续阶段
+    Candidate(Candidate),
+    /// 执行完毕但没有得出结果（附说明）
+    Completed(String),
+    /// 没有执行（附原因）
+    Skipped(String),
+    /// 执行失败，流水线继续（附错误）
+    Failed(String),
+}
+
+/// 分类流水线的一个阶段
+///
+/// 除 `Decided` 外的结果都会交给下一阶段（阶段也可以通过 `StageContext` 留下正文）。
+#[async_trait]
+pub trait PipelineStage: Send + Sync {
+    async fn run(&self, ctx: &mut StageContext<'_>) -> StageOutput;
+
+    /// 阶段名称（用于日志）
+    fn name(&self) -> &str;
+
+    /// 需要推理服务的阶段返回每批的新闻数量，流水线在它之前暂停等待批量推理
+    fn inference_batch_size(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// 按配置顺序执行的分类阶段
+pub struct ClassificationPipeline {
+    stages: Vec<Box<dyn PipelineStage>>,
+}
+
+impl ClassificationPipeline {
+    /// 根据阶段配置构建流水线（跳过关闭的阶段）
+    pub fn from_config(stages: &[StageConfig], config: &ClassificationConfig) -> Self {
+        let stages = stages
+            .iter()
+            .filter(|stage| stage.is_enabled())
+            .map(|stage| -> Box<dyn PipelineStage> {
+                match *stage {
+                    StageConfig::Keyword {
+                        accept_threshold, ..
+                    } => Box::new(KeywordStage {
+                        accept_threshold,
+                        full_text: false,
+                    }),
+                    StageConfig::SourceTendency {
+                        accept_threshold, ..
+                    } => Box::new(SourceTendencyStage {
+                        strategy: SourceBasedStrategy::from_config(config.clone()),
+                        accept_threshold,
+                    }),
+                    StageConfig::ContentEnrichment {
+                        skip_if_content, ..
+                    } => Box::new(ContentEnrichmentStage { skip_if_content }),
+                    StageConfig::FullTextKeyword {
+                        accept_threshold, ..
+                    } => Box::new(KeywordStage {
+                        accept_threshold,
+                        full_text: true,
+                    }),
+                    StageConfig::AiArbitration {
+                        review_threshold,
+                        batch_size,
+                        ..
+                    } => Box::new(AiArbitrationStage {
+                        review_threshold,
+                        batch_size,
+                    }),
+                    StageConfig::Fallback { min_confidence, .. } => {
+                        Box::new(FallbackStage { min_confidence })
+                    }
+                }
+            })
+            .collect();
+        Self { stages }
+    }
+
+    /// 依次执行各阶段，直到某个阶段给出结果；每个阶段都记入分类轨迹
+    ///
+    /// 遇到需要推理服务的阶段时暂停，返回 `AwaitingInference`，
+    /// 由调用方把多条新闻合并成一次批量推理后通过 `resume` 继续。
+    pub async fn start<'a>(&self, ctx: StageContext<'a>) -> PipelineRun<'a> {
+        self.advance(ctx, ClassificationTrace::new(), 0, Duration::ZERO)
+            .await
+    }
+
+    /// 带着推理结果从暂停处继续（`elapsed` 为该条新闻分摊的推理耗时，计入 AI 阶段）
+    pub async fn resume<'a>(
+        &self,
+        paused: PausedRun<'a>,
+        inference: Result<InferenceResult, String>,
+        elapsed: Duration,
+    ) -> PipelineRun<'a> {
+        let PausedRun {
+            mut ctx,
+            trace,
+            next_stage,
+            ..
+        } = paused;
+        ctx.prefetched_inference = Some(inference);
+        self.advance(ctx, trace, next_stage, elapsed).await
+    }
+
+    /// 从第 `from` 个阶段开始执行
+    async fn advance<'a>(
+        &self,
+        mut ctx: StageContext<'a>,
+        mut trace: ClassificationTrace,
+        from: usize,
+        mut carried: Duration,
+    ) -> PipelineRun<'a> {
+        for (index, stage) in self.stages.iter().enumerate().skip(from) {
+            if let Some(batch_size) = stage.inference_batch_size()
+                && ctx.inference_service.is_some()
+                && ctx.prefetched_inference.is_none()
+            {
+                return PipelineRun::AwaitingInference(Box::new(PausedRun {
+                    ctx,
+                    trace,
+                    next_stage: index,
+                    batch_size,
+                }));
+            }
+
+            let started = Instant::now();
+            let output = stage.run(&mut ctx).await;
+            let elapsed = started.elapsed() + std::mem::take(&mut carried);
+            let mut record = StageTrace {
+                stage: stage.name().to_string(),
+                status: StageStatus::Completed,
+                domain: None,
+                secondary_domains: Vec::new(),
+                confidence: None,
+                detail: String::new(),
+                error: None,
+                elapsed_ms: elapsed.as_millis() as u64,
+            };
+            match output {
+                StageOutput::Decided(mut outcome) => {
+                    record.status = StageStatus::Decided;
+                    record.domain = outcome.domain;
+                    record.secondary_domains = outcome.secondary_domains.clone();
+                    record.confidence = Some(outcome.confidence);
+                    record.detail = outcome.reason.clone();
+                    trace.push(record);
+                    outcome.trace = trace;
+                    return PipelineRun::Finished(outcome);
+                }
+                StageOutput::Candidate(candidate) => {
+                    record.status = StageStatus::Candidate;
+                    record.domain = Some(candidate.domain);
+                    record.secondary_domains = candidate.secondary_domains.clone();
+                    record.confidence = Some(candidate.confidence);
+                    record.detail = candidate.reason.clone();
+                    ctx.offer(candidate);
+                }
+                StageOutput::Completed(detail) => record.detail = detail,
+                StageOutput::Skipped(reason) => {
+                    record.status = StageStatus::Skipped;
+                    record.detail = reason;
+                }
+                StageOutput::Failed(error) => {
+                    record.status = StageStatus::Failed;
+                    record.error = Some(error);
+                }
+            }
+            trace.push(record);
+        }
+
+        let mut outcome = ClassificationOutcome::irrelevant(
+            "Unclassifiable (No confident match and content unavailable/irrelevant)".to_string(),
+        );
+        outcome.trace = trace;
+        PipelineRun::Finished(outcome)
+    }
+}
+
+/// 流水线的执行状态
+pub enum PipelineRun<'a> {
+    /// 已得出结果
+    Finished(ClassificationOutcome),
+    /// 在需要推理服务的阶段前暂停
+    AwaitingInference(Box<PausedRun<'a>>),
+}
+
+/// 暂停在推理阶段之前的一条新闻
+pub struct PausedRun<'a> {
+    ctx: StageContext<'a>,
+    trace: ClassificationTrace,
+    /// 暂停处的阶段
+    next_stage: usize,
+    /// 该阶段每批推理的新闻数量
+    pub batch_size: usize,
+}
+
+impl PausedRun<'_> {
+    /// 送去推理的新闻（包含抓取到的正文）
+    pub fn news(&self) -> &NewsItem {
+        &self.ctx.augmented
+    }
+}
+
+/// 关键词扫描：标题 / URL（`full_text` 时包含抓取到的正文）
+struct KeywordStage {
+    accept_threshold: f32,
+    full_text: bool,
+}
+
+#[async_trait]
+impl PipelineStage for KeywordStage {
+    async fn run(&self, ctx: &mut StageContext<'_>) -> StageOutput {
+        let news = if self.full_text {
+            &ctx.augmented
+        } else {
+            ctx.news
+        };
+        let Some(result) = ctx.strategy.classify(news) else {
+            return StageOutput::Completed("no keyword matched".to_string());
+        };
+        if result.confidence >= self.accept_threshold {
+            return StageOutput::Decided(
+                ClassificationOutcome::relevant(
+                    result.domain,
+                    result.confidence,
+                    format!("[{}] {}", self.name(), result.reason),
+                    false,
+                )
+                .with_secondary_domains(result.secondary_domains),
+            );
+        }
+        StageOutput::Candidate(Candidate {
+            domain: result.domain,
+            secondary_domains: result.secondary_domains,
+            confidence: result.confidence,
+            reason: result.reason,
+        })
+    }
+
+    fn name(&self) -> &str {
+        if self.full_text {
+            "FullContentScan"
+        } else {
+            "FastPass"
+        }
+    }
+}
+
+/// 来源倾向：按来源名称或 URL 域名映射领域
+struct SourceTendencyStage {
+    strategy: SourceBasedStrategy,
+    accept_threshold: f32,
+}
+
+#[async_trait]
+impl PipelineStage for SourceTendencyStage {
+    async fn run(&self, ctx: &mut StageContext<'_>) -> StageOutput {
+        let Some(result) = self.strategy.classify(ctx.news) else {
+            return StageOutput::Completed(format!(
+                "no tendency configured for source '{}'",
+                ctx.news.source
+            ));
+        };
+        if result.confidence >= self.accept_threshold {
+            return StageOutput::Decided(ClassificationOutcome::relevant(
+                result.domain,
+                result.confidence,
+                format!("[SourceTendency] {}", result.reason),
+                false,
+            ));
+        }
+        StageOutput::Candidate(Candidate {
+            domain: result.domain,
+            secondary_domains: result.secondary_domains,
+            confidence: result.confidence,
+            reason: result.reason,
+        })
+    }
+
+    fn name(&self) -> &str {
+        "SourceTendency"
+    }
+}
+
+/// 正文抓取：为后续阶段补充正文
+struct ContentEnrichmentStage {
+    skip_if_content: bool,
+}
+
+#[async_trait]
+impl PipelineStage for ContentEnrichmentStage {
+    async fn run(&self, ctx: &mut StageContext<'_>) -> StageOutput {
+        if self.skip_if_content && ctx.news.content.as_deref().is_some_and(|c| !c.is_empty()) {
+            return StageOutput::Skipped("item already carries content".to_string());
+        }
+
+        info!("🌐 抓取全文内容: {}", ctx.news.url);
+        match ctx.extractor.extract(&ctx.news.url).await {
+            Ok(content) => {
+                let detail = format!("fetched {} chars", content.text.chars().count());
+                ctx.augmented.content = Some(content.text);
+                StageOutput::Completed(detail)
+            }
+            Err(e) => {
+                // 抓取失败不直接丢弃——保留已有弱命中，
+                // 继续尝试 AI 仲裁（仅用标题/来源），否则进入兜底逻辑。
+                warn!(
+                    "⚠️ 无法提取全文，降级处理（保留已有弱命中）: {} | 错误: {}",
+                    ctx.news.title, e
+                );
+                StageOutput::Failed(e.to_string())
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        "ContentEnrichment"
+    }
+}
+
+/// AI 仲裁：未配置推理服务时跳过
+struct AiArbitrationStage {
+    review_threshold: f32,
+    batch_size: usize,
+}
+
+#[async_trait]
+impl PipelineStage for AiArbitrationStage {
+    async fn run(&self, ctx: &mut StageContext<'_>) -> StageOutput {
+        let Some(ai) = ctx.inference_service else {
+            return StageOutput::Skipped("no inference service configured".to_string());
+        };
+        let result = match ctx.prefetched_inference.take() {
+            Some(result) => result,
+            None => {
+                info!("🤖 触发 AI 深度推理: {}", ctx.news.title);
+                ai.infer(&ctx.augmented).await.map_err(|e| e.to_string())
+            }
+        };
+        let result = match result {
+            Ok(result) => result,
+            Err(e) => {
+                warn!("❌ AI 仲裁失败: {}", e);
+                // AI 失败时不直接丢弃，继续进入兜底逻辑
+                return StageOutput::Failed(e);
+            }
+        };
+
+        let ai_reason = format!("[AI:{}] {}", ai.name(), result.reason);
+        let Some(domain) = result.domain.filter(|_| result.is_relevant) else {
+            return StageOutput::Decided(ClassificationOutcome::irrelevant(ai_reason));
+        };
+
+        // AI 建议的关键词噪声较大，不直接学习，先进入收件箱等待人工审核
+        record_suggested_keywords(ctx, domain, &result.suggested_keywords).await;
+
+        // AI 置信度偏低时保留结果，但交给人工审核
+        StageOutput::Decided(
+            ClassificationOutcome::relevant(
+                domain,
+                result.confidence,
+                ai_reason,
+                result.confidence < self.review_threshold,
+            )
+            .with_secondary_domains(result.secondary_domains),
+        )
+    }
+
+    fn name(&self) -> &str {
+        "AI"
+    }
+
+    fn inference_batch_size(&self) -> Option<usize> {
+        Some(self.batch_size.max(1))
+    }
+}
+
+/// 把 AI 建议的关键词记入收件箱（跳过配置中已有的关键词）
+///
+/// 记录失败只打印警告，不影响分类结果。
+async fn record_suggested_keywords(ctx: &StageContext<'_>, domain: Domain, keywords: &[String]) {
+    let new_keywords: Vec<String> = keywords
+        .iter()
+        .filter(|k| !ctx.config.contains_keyword(domain, k))
+        .cloned()
+        .collect();
+    if new_keywords.is_empty() {
+        return;
+    }
+
+    match ctx.keyword_suggestions {
+        Some(repository) => {
+            info!("🔍 AI建议关键词（已记入收件箱）: {:?}", new_keywords);
+            if let Err(e) = repository.record(domain, &ctx.news.id, &new_keywords).await {
+                warn!("⚠️ 记录建议关键词失败: {}", e);
+            }
+        }
+        None => info!("🔍 AI建议关键词（未保存）: {:?}", new_keywords),
+    }
+}
+
+/// 兜底：接受目前为止的最佳弱命中，交给人工审核
+struct FallbackStage {
+    min_confidence: f32,
+}
+
+#[async_trait]
+impl PipelineStage for FallbackStage {
+    async fn run(&self, ctx: &mut StageContext<'_>) -> StageOutput {
+        let Some(best) = ctx.best.as_ref() else {
+            return StageOutput::Completed("no candidate".to_string());
+        };
+        if best.confidence <= self.min_confidence {
+            return StageOutput::Completed(format!(
+                "best candidate {} {:.2} is not above {:.2}",
+                best.domain, best.confidence, self.min_confidence
+            ));
+        }
+        // 只有弱命中的结果不够可靠，交给人工审核
+        StageOutput::Decided(
+            ClassificationOutcome::relevant(
+                best.domain,
+                best.confidence,
+                format!("[WeakMatchFallback] {}", best.reason),
+                true,
+            )
+            .with_secondary_domains(best.secondary_domains.clone()),
+        )
+    }
+
+    fn name(&self) -> &str {
+        "WeakMatchFallback"
+    }
+}
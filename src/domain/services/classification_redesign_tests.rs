@@ -1,8 +1,12 @@
-use crate::domain::services::{InferenceResult, NewsInferenceService};
-use crate::domain::{Domain, NewsClassificationService, NewsItem};
+use crate::domain::config::{ClassificationConfig, StageConfig};
+use crate::domain::services::{
+    ContentExtractor, ExtractedContent, InferenceResult, NewsInferenceService,
+};
+use crate::domain::{Domain, NewsClassificationService, NewsItem, NewsItemStatus};
 use async_trait::async_trait;
 use chrono::Utc;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
 /// Mock AI 服务用于测试
 struct MockInferenceService {
@@ -19,6 +23,7 @@ impl NewsInferenceService for MockInferenceService {
         Ok(InferenceResult {
             is_relevant: self.is_relevant,
             domain: self.domain,
+            secondary_domains: Vec::new(),
             confidence: 0.95,
             reason: "Mock AI analysis".to_string(),
             suggested_keywords: vec!["mock".to_string()],
@@ -30,6 +35,92 @@ impl NewsInferenceService for MockInferenceService {
     }
 }
 
+/// 记录每次批量请求条数的 Mock AI 服务
+#[derive(Default)]
+struct BatchRecordingInference {
+    batches: Mutex<Vec<usize>>,
+    single_calls: AtomicUsize,
+}
+
+#[async_trait]
+impl NewsInferenceService for BatchRecordingInference {
+    async fn infer(
+        &self,
+        _news: &NewsItem,
+    ) -> Result<InferenceResult, Box<dyn std::error::Error + Send + Sync>> {
+        self.single_calls.fetch_add(1, Ordering::SeqCst);
+        Ok(InferenceResult {
+            is_relevant: true,
+            domain: Some(Domain::new("Social")),
+            secondary_domains: Vec::new(),
+            confidence: 0.9,
+            reason: "single".to_string(),
+            suggested_keywords: Vec::new(),
+        })
+    }
+
+    async fn infer_batch(
+        &self,
+        news: &[NewsItem],
+    ) -> Vec<Result<InferenceResult, Box<dyn std::error::Error + Send + Sync>>> {
+        self.batches.lock().unwrap().push(news.len());
+        news.iter()
+            .map(|_| {
+                Ok(InferenceResult {
+                    is_relevant: true,
+                    domain: Some(Domain::new("Block")),
+                    secondary_domains: Vec::new(),
+                    confidence: 0.9,
+                    reason: "batched".to_string(),
+                    suggested_keywords: Vec::new(),
+                })
+            })
+            .collect()
+    }
+
+    fn name(&self) -> &str {
+        "batch-ai"
+    }
+}
+
+/// 记录调用次数的正文提取器，返回固定正文
+struct CountingExtractor {
+    text: String,
+    calls: AtomicUsize,
+}
+
+impl CountingExtractor {
+    fn new(text: &str) -> Arc<Self> {
+        Arc::new(Self {
+            text: text.to_string(),
+            calls: AtomicUsize::new(0),
+        })
+    }
+
+    fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl ContentExtractor for CountingExtractor {
+    async fn extract(
+        &self,
+        url: &str,
+    ) -> Result<ExtractedContent, Box<dyn std::error::Error + Send + Sync>> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Ok(ExtractedContent {
+            title: String::new(),
+            text: self.text.clone(),
+            source_url: url.to_string(),
+        })
+    }
+
+    fn name(&self) -> &str {
+        "counting"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -60,7 +151,7 @@ mod tests {
         service.classify_batch_and_filter(items).await;
 
         assert_eq!(items.len(), 1);
-        assert_eq!(items[0].domain, Some(Domain::AI));
+        assert_eq!(items[0].domain, Some(Domain::new("AI")));
     }
 
     #[tokio::test]
@@ -71,7 +162,7 @@ mod tests {
         // 配置 Mock AI，判定为 Block
         let mock_ai = Arc::new(MockInferenceService {
             is_relevant: true,
-            domain: Some(Domain::Block),
+            domain: Some(Domain::new("Block")),
         });
 
         let service = NewsClassificationService::new().with_inference_service(mock_ai);
@@ -80,7 +171,7 @@ mod tests {
         service.classify_batch_and_filter(items).await;
 
         assert_eq!(items.len(), 1);
-        assert_eq!(items[0].domain, Some(Domain::Block));
+        assert_eq!(items[0].domain, Some(Domain::new("Block")));
         assert!(
             items[0]
                 .classification_reason
@@ -109,4 +200,167 @@ mod tests {
         // 预期新闻被丢弃
         assert_eq!(items.len(), 0);
     }
+
+    #[tokio::test]
+    async fn test_source_tendency_feeds_fallback() {
+        let news = create_test_news("Quarterly results", "coindesk", None);
+        let mut config = ClassificationConfig::default();
+        config
+            .source_tendency
+            .insert("coindesk".to_string(), Domain::new("Block"));
+        let service = NewsClassificationService::new()
+            .with_config(config)
+            .with_content_extractor(CountingExtractor::new("Nothing to see here."));
+
+        let items = &mut [news];
+        service.classify_batch(items).await;
+
+        assert_eq!(items[0].domain, Some(Domain::new("Block")));
+        assert_eq!(items[0].status, NewsItemStatus::NeedsReview);
+        assert!(
+            items[0]
+                .classification_reason
+                .as_ref()
+                .unwrap()
+                .starts_with("[WeakMatchFallback] Source 'coindesk'")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_source_profile_skips_content_fetch_and_ai() {
+        let news = create_test_news(
+            "Weekly update",
+            "ethereum-blog",
+            Some("The bitcoin and ethereum networks upgraded."),
+        );
+        let mut config = ClassificationConfig::default();
+        config.source_profiles.insert(
+            "ethereum-blog".to_string(),
+            vec![
+                StageConfig::ContentEnrichment {
+                    enabled: true,
+                    skip_if_content: true,
+                },
+                StageConfig::FullTextKeyword {
+                    enabled: true,
+                    accept_threshold: 0.7,
+                },
+                StageConfig::AiArbitration {
+                    enabled: false,
+                    review_threshold: 0.7,
+                    batch_size: 10,
+                },
+            ],
+        );
+        let extractor = CountingExtractor::new("unused");
+        let mock_ai = Arc::new(MockInferenceService {
+            is_relevant: true,
+            domain: Some(Domain::new("Social")),
+        });
+        let service = NewsClassificationService::new()
+            .with_config(config)
+            .with_content_extractor(extractor.clone())
+            .with_inference_service(mock_ai);
+
+        let items = &mut [news];
+        service.classify_batch(items).await;
+
+        assert_eq!(extractor.calls(), 0);
+        assert_eq!(items[0].domain, Some(Domain::new("Block")));
+        assert!(
+            items[0]
+                .classification_reason
+                .as_ref()
+                .unwrap()
+                .starts_with("[FullContentScan]")
+        );
+
+        // 其他来源仍然使用默认流水线，会抓取正文
+        let other = create_test_news("Weekly update", "hackernews", None);
+        let items = &mut [other];
+        service.classify_batch(items).await;
+        assert_eq!(extractor.calls(), 1);
+        assert_eq!(items[0].domain, Some(Domain::new("Social")));
+    }
+
+    #[tokio::test]
+    async fn test_ai_arbitration_batches_requests() {
+        let mut config = ClassificationConfig::default();
+        for stage in &mut config.pipeline {
+            if let StageConfig::AiArbitration { batch_size, .. } = stage {
+                *batch_size = 2;
+            }
+        }
+        let mock_ai = Arc::new(BatchRecordingInference::default());
+        let service = NewsClassificationService::new()
+            .with_config(config)
+            .with_inference_service(mock_ai.clone());
+
+        let items = &mut [
+            create_test_news("The future of tech", "hackernews", None),
+            create_test_news("New GPT-4 features announced", "hackernews", None),
+            create_test_news("A quiet week", "hackernews", None),
+            create_test_news("Notes from the road", "hackernews", None),
+        ];
+        service.classify_batch(items).await;
+
+        // 关键词命中的条目不进入 AI；其余 3 条按每批 2 条发送
+        assert_eq!(*mock_ai.batches.lock().unwrap(), vec![2, 1]);
+        assert_eq!(mock_ai.single_calls.load(Ordering::SeqCst), 0);
+        assert_eq!(items[1].domain, Some(Domain::new("AI")));
+        for i in [0, 2, 3] {
+            assert_eq!(items[i].domain, Some(Domain::new("Block")));
+            assert!(
+                items[i]
+                    .classification_reason
+                    .as_ref()
+                    .unwrap()
+                    .contains("AI:batch-ai")
+            );
+        }
+    }
+
+    #[test]
+    fn test_config_file_changes_are_reloaded() {
+        use crate::domain::config::DomainDefinition;
+        use std::time::{Duration, SystemTime};
+
+        let path = std::env::temp_dir().join(format!(
+            "trendarc-reload-config-{}.json",
+            std::process::id()
+        ));
+        let mut config = ClassificationConfig::default();
+        config.save_to_file(&path).unwrap();
+        let bump_mtime = |secs: u64| {
+            std::fs::File::options()
+                .write(true)
+                .open(&path)
+                .unwrap()
+                .set_modified(SystemTime::now() + Duration::from_secs(secs))
+                .unwrap();
+        };
+
+        let classifier = NewsClassificationService::from_config_file(&path);
+        let has_science = |classifier: &NewsClassificationService| {
+            classifier
+                .domains()
+                .iter()
+                .any(|d| d.name == Domain::new("Science"))
+        };
+        assert!(!has_science(&classifier));
+
+        config
+            .domains
+            .push(DomainDefinition::new(Domain::new("Science")));
+        config.save_to_file(&path).unwrap();
+        bump_mtime(10);
+        assert!(has_science(&classifier));
+
+        // 无效的新配置不会替换当前快照
+        std::fs::write(&path, "not json").unwrap();
+        bump_mtime(20);
+        assert!(has_science(&classifier));
+
+        std::fs::remove_file(&path).unwrap();
+    }
 }
@@ -1,3 +1,4 @@
+use crate::domain::config::DomainDefinition;
 use async_trait::async_trait;
 
 /// Discord 消息结构体
@@ -17,10 +18,26 @@ pub struct DiscordMessage {
     pub published_at: String,
     /// 领域分类
     pub domain: Option<String>,
+    /// 次要领域
+    pub secondary_domains: Vec<String>,
+    /// 领域图标（标题前缀）
+    pub domain_emoji: String,
+    /// embed 颜色
+    pub color: u32,
     /// 分类依据
     pub classification_reason: Option<String>,
     /// 分类置信度
     pub classification_confidence: Option<f32>,
+    /// 热度分数（如 HN points）
+    pub score: Option<u32>,
+    /// 评论数
+    pub comment_count: Option<u32>,
+    /// 原站讨论页链接
+    pub discussion_url: Option<String>,
+    /// 同一事件的其他报道数量
+    pub related_count: usize,
+    /// 其他报道的来源（不含主新闻的来源）
+    pub related_sources: Vec<String>,
 }
 
 impl DiscordMessage {
@@ -41,17 +58,78 @@ impl DiscordMessage {
             author: news.author.clone(),
             published_at,
             domain: domain_str,
+            secondary_domains: news
+                .secondary_domains
+                .iter()
+                .map(|label| label.domain.to_string())
+                .collect(),
+            domain_emoji: "📰".to_string(),
+            color: 0x3498db, // Discord 蓝色
             classification_reason: reason,
             classification_confidence: confidence,
+            score: news.score,
+            comment_count: news.comment_count,
+            discussion_url: news.discussion_url.clone(),
+            related_count: 0,
+            related_sources: Vec::new(),
         }
     }
 
+    /// 从报道聚类创建 Discord 消息（展示主新闻，并注明其他报道）
+    pub fn from_story(story: &crate::domain::services::StoryCluster) -> Self {
+        let mut message = Self::from_news_item(&story.primary);
+        message.related_count = story.related.len();
+        message.related_sources = story
+            .related_sources()
+            .into_iter()
+            .map(|s| s.to_string())
+            .collect();
+        message
+    }
+
+    /// 使用领域配置中的图标和颜色
+    pub fn with_domain_style(mut self, definition: &DomainDefinition) -> Self {
+        self.domain_emoji = definition.emoji.clone();
+        self.color = definition.color_value();
+        self
+    }
+
+    /// 格式化其他报道字段，例如 "另有 2 个来源报道: coindesk, theblock"
+    fn format_related(&self) -> Option<String> {
+        if self.related_count == 0 {
+            return None;
+        }
+        if self.related_sources.is_empty() {
+            return Some(format!("另有 {} 篇相关报道", self.related_count));
+        }
+        Some(format!(
+            "另有 {} 个来源报道: {}",
+            self.related_sources.len(),
+            self.related_sources.join(", ")
+        ))
+    }
+
+    /// 格式化热度字段，例如 "▲ 120 | 💬 [35](https://news.ycombinator.com/item?id=1)"
+    fn format_engagement(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        if let Some(score) = self.score {
+            parts.push(format!("▲ {}", score));
+        }
+        match (self.comment_count, &self.discussion_url) {
+            (Some(comments), Some(url)) => parts.push(format!("💬 [{}]({})", comments, url)),
+            (Some(comments), None) => parts.push(format!("💬 {}", comments)),
+            (None, Some(url)) => parts.push(format!("💬 [讨论]({})", url)),
+            (None, None) => {}
+        }
+        (!parts.is_empty()).then(|| parts.join(" | "))
+    }
+
     /// 转换为 Discord webhook 的 embeds JSON 结构
     pub fn to_embed_json(&self) -> serde_json::Value {
         let mut embed = serde_json::json!({
             "title": self.title,
             "url": self.url,
-            "color": 0x3498db, // Discord 蓝色
+            "color": self.color,
             "fields": [
                 {
                     "name": "来源",
@@ -67,24 +145,50 @@ impl DiscordMessage {
         });
 
         // 添加领域信息
-        if let Some(domain) = &self.domain {
-            let domain_emoji = match domain.as_str() {
-                "AI" => "🤖",
-                "Block" => "⛓️",
-                "Social" => "📱",
-                _ => "📰",
+        if let Some(domain) = &self.domain
+            && let Some(embed_obj) = embed.as_object_mut()
+        {
+            embed_obj.insert(
+                "title".to_string(),
+                serde_json::Value::String(format!("{} {}", self.domain_emoji, self.title)),
+            );
+
+            let fields = embed_obj.get_mut("fields").unwrap().as_array_mut().unwrap();
+            // 次要领域附在主领域后面，例如 "AI (+Block)"
+            let value = if self.secondary_domains.is_empty() {
+                domain.clone()
+            } else {
+                format!("{} (+{})", domain, self.secondary_domains.join(", "))
             };
+            fields.push(serde_json::json!({
+                "name": "领域",
+                "value": value,
+                "inline": true
+            }));
+        }
 
-            if let Some(embed_obj) = embed.as_object_mut() {
-                embed_obj.insert("title".to_string(), serde_json::Value::String(format!("{} {}", domain_emoji, self.title)));
-                
-                let mut fields = embed_obj.get_mut("fields").unwrap().as_array_mut().unwrap();
-                fields.push(serde_json::json!({
-                    "name": "领域",
-                    "value": domain,
-                    "inline": true
-                }));
-            }
+        // 添加热度信息（如果存在）
+        if let Some(engagement) = self.format_engagement()
+            && let Some(embed_obj) = embed.as_object_mut()
+        {
+            let fields = embed_obj.get_mut("fields").unwrap().as_array_mut().unwrap();
+            fields.push(serde_json::json!({
+                "name": "热度",
+                "value": engagement,
+                "inline": true
+            }));
+        }
+
+        // 添加其他报道（如果存在）
+        if let Some(related) = self.format_related()
+            && let Some(embed_obj) = embed.as_object_mut()
+        {
+            let fields = embed_obj.get_mut("fields").unwrap().as_array_mut().unwrap();
+            fields.push(serde_json::json!({
+                "name": "其他报道",
+                "value": related,
+                "inline": false
+            }));
         }
 
         // 添加分类依据（如果存在）
@@ -105,7 +209,7 @@ impl DiscordMessage {
                 let mut fields = embed_obj.get_mut("fields").unwrap().as_array_mut().unwrap();
                 let confidence_percent = (confidence * 100.0).round();
                 let confidence_bar = create_confidence_bar(confidence);
-                
+
                 fields.push(serde_json::json!({
                     "name": "分类置信度",
                     "value": format!("{}% {}", confidence_percent, confidence_bar),
@@ -123,7 +227,10 @@ impl DiscordMessage {
             };
 
             if let Some(embed_obj) = embed.as_object_mut() {
-                embed_obj.insert("description".to_string(), serde_json::Value::String(description_preview));
+                embed_obj.insert(
+                    "description".to_string(),
+                    serde_json::Value::String(description_preview),
+                );
             }
         }
 
@@ -136,7 +243,7 @@ fn create_confidence_bar(confidence: f32) -> String {
     let bars = 10;
     let filled = (confidence * bars as f32).round() as usize;
     let empty = bars - filled;
-    
+
     format!("{}{}", "█".repeat(filled), "░".repeat(empty))
 }
 
@@ -144,10 +251,16 @@ fn create_confidence_bar(confidence: f32) -> String {
 #[async_trait]
 pub trait DiscordService: Send + Sync {
     /// 发送单条消息到 Discord
-    async fn send_message(&self, message: &DiscordMessage) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
-    
+    async fn send_message(
+        &self,
+        message: &DiscordMessage,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
     /// 批量发送消息到 Discord
-    async fn send_batch(&self, messages: &[DiscordMessage]) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+    async fn send_batch(
+        &self,
+        messages: &[DiscordMessage],
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
 }
 
 #[cfg(test)]
@@ -171,7 +284,7 @@ mod tests {
     fn test_discord_message_from_news_item() {
         let news = create_test_news_item();
         let message = DiscordMessage::from_news_item(&news);
-        
+
         assert_eq!(message.title, "Test Title");
         assert_eq!(message.url, "https://example.com/test");
         assert_eq!(message.source, "test-source");
@@ -183,7 +296,7 @@ mod tests {
         let news = create_test_news_item();
         let message = DiscordMessage::from_news_item(&news);
         let embed = message.to_embed_json();
-        
+
         assert!(embed.is_object());
         let obj = embed.as_object().unwrap();
         assert!(obj.contains_key("title"));
@@ -192,6 +305,79 @@ mod tests {
         assert!(obj.contains_key("fields"));
     }
 
+    #[test]
+    fn test_discord_embed_includes_engagement() {
+        let news = create_test_news_item()
+            .with_engagement(Some(120), Some(35))
+            .with_discussion_url("https://news.ycombinator.com/item?id=1".to_string());
+        let embed = DiscordMessage::from_news_item(&news).to_embed_json();
+
+        let fields = embed["fields"].as_array().unwrap();
+        let engagement = fields.iter().find(|f| f["name"] == "热度").unwrap();
+        assert_eq!(
+            engagement["value"],
+            "▲ 120 | 💬 [35](https://news.ycombinator.com/item?id=1)"
+        );
+    }
+
+    #[test]
+    fn test_discord_embed_without_engagement() {
+        let embed = DiscordMessage::from_news_item(&create_test_news_item()).to_embed_json();
+        let fields = embed["fields"].as_array().unwrap();
+        assert!(fields.iter().all(|f| f["name"] != "热度"));
+    }
+
+    #[test]
+    fn test_discord_embed_includes_related_coverage() {
+        let mut related = create_test_news_item();
+        related.source = "coindesk".to_string();
+        let story = crate::domain::services::StoryCluster {
+            primary: create_test_news_item(),
+            related: vec![related.clone(), related],
+        };
+
+        let message = DiscordMessage::from_story(&story);
+        assert_eq!(message.related_count, 2);
+        assert_eq!(message.related_sources, vec!["coindesk".to_string()]);
+
+        let embed = message.to_embed_json();
+        let fields = embed["fields"].as_array().unwrap();
+        let field = fields.iter().find(|f| f["name"] == "其他报道").unwrap();
+        assert_eq!(field["value"], "另有 1 个来源报道: coindesk");
+    }
+
+    #[test]
+    fn test_discord_embed_includes_secondary_domains() {
+        let mut news = create_test_news_item();
+        news.domain = Some(crate::domain::Domain::new("AI"));
+        news.secondary_domains = vec![crate::domain::DomainLabel::new(
+            crate::domain::Domain::new("Block"),
+            0.7,
+        )];
+        let embed = DiscordMessage::from_news_item(&news).to_embed_json();
+
+        let fields = embed["fields"].as_array().unwrap();
+        let field = fields.iter().find(|f| f["name"] == "领域").unwrap();
+        assert_eq!(field["value"], "AI (+Block)");
+    }
+
+    #[test]
+    fn test_discord_embed_uses_domain_style() {
+        let mut news = create_test_news_item();
+        news.domain = Some(crate::domain::Domain::new("Security"));
+        let definition = DomainDefinition {
+            emoji: "🔐".to_string(),
+            color: "#e74c3c".to_string(),
+            ..DomainDefinition::new(crate::domain::Domain::new("Security"))
+        };
+        let embed = DiscordMessage::from_news_item(&news)
+            .with_domain_style(&definition)
+            .to_embed_json();
+
+        assert_eq!(embed["color"], 0xe74c3c);
+        assert!(embed["title"].as_str().unwrap().starts_with("🔐 "));
+    }
+
     #[test]
     fn test_confidence_bar() {
         assert_eq!(create_confidence_bar(0.0), "░░░░░░░░░░");
@@ -1,16 +1,25 @@
+pub mod classification_pipeline;
 pub mod content_extractor;
 pub mod discord_service;
 pub mod news_classification_service;
 pub mod news_deduplication_service;
 pub mod news_inference_service;
 pub mod news_sorting_service;
+pub mod story_clustering_service;
+pub mod story_velocity_service;
+pub mod title_similarity;
+pub mod url_canonicalizer;
 
-pub use content_extractor::{ContentExtractor, DefaultContentExtractor};
+pub use content_extractor::{ContentExtractor, DefaultContentExtractor, ExtractedContent};
 pub use discord_service::{DiscordMessage, DiscordService};
 pub use news_classification_service::NewsClassificationService;
 pub use news_deduplication_service::NewsDeduplicationService;
 pub use news_inference_service::{InferenceResult, NewsInferenceService};
-pub use news_sorting_service::NewsSortingService;
+pub use news_sorting_service::{NewsSortingService, SortOrder};
+pub use story_clustering_service::{StoryCluster, StoryClusteringService};
+pub use story_velocity_service::StoryVelocityService;
+pub use title_similarity::TitleSignature;
+pub use url_canonicalizer::UrlCanonicalizer;
 
 #[cfg(test)]
 mod classification_redesign_tests;
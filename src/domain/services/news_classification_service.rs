@@ -1,50 +1,123 @@
-use crate::domain::config::ClassificationConfig;
+use crate::domain::config::{ClassificationConfig, DomainDefinition};
+use crate::domain::services::classification_pipeline::{
+    ClassificationOutcome, ClassificationPipeline, PausedRun, PipelineRun, StageContext,
+};
 use crate::domain::services::{ContentExtractor, DefaultContentExtractor, NewsInferenceService};
+use crate::domain::strategies::exclusion_rules::ExclusionRules;
 use crate::domain::{
-    ClassificationStrategy, Domain, KeywordBasedStrategy, NewsItem, NewsItemStatus,
+    Domain, KeywordBasedStrategy, KeywordSuggestionRepository, NewsItem, NewsItemStatus,
+    StageStatus, StageTrace,
 };
 use futures::future::join_all;
-use futures::stream::{self, StreamExt};
-use std::collections::HashMap;
-use std::path::PathBuf;
-use std::sync::{Arc, RwLock};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Instant, SystemTime};
 use tracing::{info, warn};
 
-/// 分类结果（替代裸元组，提升可读性和可维护性）
-struct ClassificationOutcome {
-    domain: Option<Domain>,
-    confidence: f32,
-    reason: String,
-    is_relevant: bool,
+/// 一份分类配置及由它构建的关键词策略、排除规则和分类流水线
+///
+/// 关键词自动机和正则只在配置变化时构建一次，所有新闻共享同一份快照。
+struct ConfigSnapshot {
+    config: ClassificationConfig,
+    strategy: KeywordBasedStrategy,
+    exclusions: ExclusionRules,
+    /// 默认流水线
+    pipeline: ClassificationPipeline,
+    /// 按来源名称覆盖的流水线
+    source_pipelines: HashMap<String, ClassificationPipeline>,
+}
+
+impl ConfigSnapshot {
+    fn new(config: ClassificationConfig) -> Arc<Self> {
+        let strategy = KeywordBasedStrategy::from_config(config.clone());
+        let exclusions = ExclusionRules::from_config(&config);
+        let pipeline = ClassificationPipeline::from_config(&config.pipeline, &config);
+        let source_pipelines = config
+            .source_profiles
+            .iter()
+            .map(|(source, stages)| {
+                (
+                    source.clone(),
+                    ClassificationPipeline::from_config(stages, &config),
+                )
+            })
+            .collect();
+        Arc::new(Self {
+            config,
+            strategy,
+            exclusions,
+            pipeline,
+            source_pipelines,
+        })
+    }
+
+    /// 新闻来源对应的流水线
+    fn pipeline_for(&self, source: &str) -> &ClassificationPipeline {
+        self.source_pipelines.get(source).unwrap_or(&self.pipeline)
+    }
+}
+
+/// 配置文件及上次加载时的修改时间，用于检测配置变化
+struct ConfigFile {
+    path: PathBuf,
+    modified: Mutex<Option<SystemTime>>,
 }
 
-/// 分类服务：负责协调静态规则、正文抓取和 AI 仲裁
+impl ConfigFile {
+    fn new(path: PathBuf) -> Self {
+        let modified = Mutex::new(Self::modified_at(&path));
+        Self { path, modified }
+    }
+
+    fn modified_at(path: &Path) -> Option<SystemTime> {
+        std::fs::metadata(path).and_then(|m| m.modified()).ok()
+    }
+
+    /// 修改时间与上次不同则记录新的修改时间并返回 true
+    fn changed(&self) -> bool {
+        let current = Self::modified_at(&self.path);
+        let mut modified = self.modified.lock().unwrap();
+        if current.is_none() || *modified == current {
+            return false;
+        }
+        *modified = current;
+        true
+    }
+}
+
+/// 分类服务：按配置的流水线协调静态规则、来源倾向、正文抓取和 AI 仲裁
 pub struct NewsClassificationService {
-    /// 动态关键词配置 (共享读写)
-    config: Arc<RwLock<ClassificationConfig>>,
-    /// 配置文件路径
-    config_path: PathBuf,
+    /// 当前配置快照 (共享读写，配置文件变化时整体替换)
+    config: Arc<RwLock<Arc<ConfigSnapshot>>>,
+    /// 配置来源文件（`with_config` 指定的配置不会重新加载）
+    config_file: Option<ConfigFile>,
     /// 正文提取器
     extractor: Arc<dyn ContentExtractor>,
     /// AI 仲裁服务 (可选)
     inference_service: Option<Arc<dyn NewsInferenceService>>,
-    /// 置信度阈值，低于此值将触发下一阶段
-    confidence_threshold: f32,
+    /// AI 建议关键词收件箱 (可选，人工审核后才写入配置)
+    keyword_suggestions: Option<Arc<dyn KeywordSuggestionRepository>>,
 }
 
 impl NewsClassificationService {
-    /// 创建新的分类服务
+    /// 创建新的分类服务（使用 `config/classification.json`）
     pub fn new() -> Self {
-        let config_path = PathBuf::from("config/classification.json");
-        let config = ClassificationConfig::load_from_file(&config_path)
+        Self::from_config_file(ClassificationConfig::DEFAULT_PATH)
+    }
+
+    /// 从配置文件创建分类服务；文件修改后，下一次分类前自动重新加载
+    pub fn from_config_file(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let config = ClassificationConfig::load_from_file(&path)
             .unwrap_or_else(|_| ClassificationConfig::default());
 
         Self {
-            config: Arc::new(RwLock::new(config)),
-            config_path,
+            config: Arc::new(RwLock::new(ConfigSnapshot::new(config))),
+            config_file: Some(ConfigFile::new(path)),
             extractor: Arc::new(DefaultContentExtractor::new()),
             inference_service: None,
-            confidence_threshold: 0.7,
+            keyword_suggestions: None,
         }
     }
 
@@ -54,201 +127,234 @@ impl NewsClassificationService {
         self
     }
 
-    /// 核心分类逻辑（改进型五阶漏斗方案）
-    async fn classify_item(&self, news: NewsItem) -> ClassificationOutcome {
-        let mut best_domain = None;
-        let mut best_confidence = 0.0_f32;
+    /// 使用指定的分类配置（替代 `config/classification.json`，例如评估候选配置）
+    pub fn with_config(mut self, config: ClassificationConfig) -> Self {
+        self.config = Arc::new(RwLock::new(ConfigSnapshot::new(config)));
+        self.config_file = None;
+        self
+    }
 
-        // 获取当前快照配置
-        let config = self.config.read().unwrap().clone();
+    /// 替换正文提取器（例如离线评估时从数据集读取正文）
+    pub fn with_content_extractor(mut self, extractor: Arc<dyn ContentExtractor>) -> Self {
+        self.extractor = extractor;
+        self
+    }
 
-        // --- 第一阶段：静态规则 (Title/URL 扫描) ---
-        let strategy = KeywordBasedStrategy::from_config(config.clone());
-        if let Some(result) = strategy.classify(&news) {
-            if result.confidence >= self.confidence_threshold {
-                return ClassificationOutcome {
-                    domain: Some(result.domain),
-                    confidence: result.confidence,
-                    reason: format!("[FastPass] {}", result.reason),
-                    is_relevant: true,
-                };
-            }
-            // 保存备选结果（第一阶段弱命中）
-            best_domain = Some(result.domain);
-            best_confidence = result.confidence;
+    /// 注入建议关键词收件箱，记录 AI 建议的关键词供人工审核
+    pub fn with_keyword_suggestions(
+        mut self,
+        repository: Arc<dyn KeywordSuggestionRepository>,
+    ) -> Self {
+        self.keyword_suggestions = Some(repository);
+        self
+    }
+
+    /// 核心分类逻辑：先检查全局排除规则，再按来源对应的流水线逐阶段分类
+    ///
+    /// 流水线在 AI 阶段之前暂停，由 `classify_batch` 合并推理请求后继续。
+    async fn start_item<'a>(
+        &'a self,
+        snapshot: &'a ConfigSnapshot,
+        news: &'a NewsItem,
+    ) -> PipelineRun<'a> {
+        // 排除规则：垃圾站点 / 标题模式，在任何网络请求之前丢弃
+        if let Some(pattern) = snapshot.exclusions.matching(news) {
+            let mut outcome = ClassificationOutcome::irrelevant(format!("[Excluded] {}", pattern));
+            outcome.trace.push(StageTrace {
+                stage: "Excluded".to_string(),
+                status: StageStatus::Decided,
+                domain: None,
+                secondary_domains: Vec::new(),
+                confidence: None,
+                detail: pattern.to_string(),
+                error: None,
+                elapsed_ms: 0,
+            });
+            return PipelineRun::Finished(outcome);
         }
 
-        // --- 第二阶段：正文抓取 (Content Enrichment) ---
-        info!("🌐 抓取全文内容: {}", news.url);
-        let mut augmented_news = news.clone();
-        match self.extractor.extract(&news.url).await {
-            Ok(content) => {
-                let limit = 2000;
-                let text_len = content.text.len();
-                let end = if text_len > limit {
-                    let mut e = limit;
-                    while !content.text.is_char_boundary(e) && e > 0 {
-                        e -= 1;
-                    }
-                    e
-                } else {
-                    text_len
-                };
-
-                let preview = if text_len > limit {
-                    format!("{}...", &content.text[..end].replace('\n', " "))
-                } else {
-                    content.text.replace('\n', " ")
-                };
-  
-                augmented_news.content = Some(content.text);
-            }
-            Err(e) => {
-                // 【修复】抓取失败不再直接丢弃——保留第一阶段弱命中结果，
-                // 继续尝试 AI 仲裁（仅用标题/来源），否则进入兜底逻辑。
-                warn!(
-                    "⚠️ 无法提取全文，降级处理（保留已有弱命中）: {} | 错误: {}",
-                    news.title, e
-                );
-                // augmented_news 此时无 content，后续阶段会感知到这一点
+        let ctx = StageContext {
+            news,
+            augmented: news.clone(),
+            best: None,
+            config: &snapshot.config,
+            strategy: &snapshot.strategy,
+            extractor: self.extractor.as_ref(),
+            inference_service: self.inference_service.as_deref(),
+            keyword_suggestions: self.keyword_suggestions.as_deref(),
+            prefetched_inference: None,
+        };
+        snapshot.pipeline_for(&news.source).start(ctx).await
+    }
+
+    /// 把暂停在 AI 阶段的新闻按批次大小分组，每批只发一次推理请求，再各自继续流水线
+    ///
+    /// 继续后的流水线可能再次暂停（配置了多个 AI 阶段），直到全部得出结果。
+    async fn finish_with_batched_inference<'a>(
+        &self,
+        snapshot: &ConfigSnapshot,
+        runs: Vec<PipelineRun<'a>>,
+    ) -> Vec<ClassificationOutcome> {
+        let mut outcomes: Vec<Option<ClassificationOutcome>> = Vec::with_capacity(runs.len());
+        let mut paused = Vec::new();
+        for (index, run) in runs.into_iter().enumerate() {
+            match run {
+                PipelineRun::Finished(outcome) => outcomes.push(Some(outcome)),
+                PipelineRun::AwaitingInference(run) => {
+                    outcomes.push(None);
+                    paused.push((index, run));
+                }
             }
         }
 
-        // --- 第三阶段：基于全文的关键词扫描 ---
-        if let Some(result) = strategy.classify(&augmented_news) {
-            if result.confidence >= self.confidence_threshold {
-                return ClassificationOutcome {
-                    domain: Some(result.domain),
-                    confidence: result.confidence,
-                    reason: format!("[FullContentScan] {}", result.reason),
-                    is_relevant: true,
-                };
-            }
-            if result.confidence > best_confidence {
-                best_domain = Some(result.domain);
-                best_confidence = result.confidence;
+        // 只有配置了推理服务时流水线才会暂停
+        let Some(ai) = self.inference_service.as_deref() else {
+            return outcomes.into_iter().flatten().collect();
+        };
+        while !paused.is_empty() {
+            let mut batches: BTreeMap<usize, Vec<(usize, Box<PausedRun<'a>>)>> = BTreeMap::new();
+            for (index, run) in paused.drain(..) {
+                batches
+                    .entry(run.batch_size)
+                    .or_default()
+                    .push((index, run));
             }
-        }
 
-        // --- 第四阶段：AI 仲裁 ---
-        if let Some(ref ai) = self.inference_service {
-            info!("🤖 触发 AI 深度推理: {}", news.title);
-            match ai.infer(&augmented_news).await {
-                Ok(result) => {
-                    let ai_reason = format!("[AI:{}] {}", ai.name(), result.reason);
-
-                    if !result.is_relevant || result.domain.is_none() {
-                        return ClassificationOutcome {
-                            domain: None,
-                            confidence: 0.0,
-                            reason: ai_reason,
-                            is_relevant: false,
-                        };
-                    }
+            for (batch_size, runs) in batches {
+                let mut runs = runs.into_iter().peekable();
+                while runs.peek().is_some() {
+                    let chunk: Vec<(usize, Box<PausedRun<'a>>)> =
+                        runs.by_ref().take(batch_size).collect();
+                    let news: Vec<NewsItem> =
+                        chunk.iter().map(|(_, run)| run.news().clone()).collect();
+                    info!("🤖 批量 AI 推理: {} 条新闻", news.len());
+                    let started = Instant::now();
+                    let results = ai.infer_batch(&news).await;
+                    // 每条新闻的 AI 阶段耗时记为整批耗时的平均值
+                    let elapsed = started.elapsed() / news.len() as u32;
 
-                    let domain = result.domain.unwrap();
-
-                    // 【重要】暂时禁用自动学习关键词，因为AI关键词不准
-                    // 分类置信度与关键词质量无关，需要人工审核
-                    if !result.suggested_keywords.is_empty() {
-                        info!(
-                            "🔍 AI建议关键词（需人工审核）: {:?}",
-                            result.suggested_keywords
-                        );
-                        // 暂不学习，记录日志供人工审核
-                        // self.append_keywords(domain, result.suggested_keywords);
+                    let mut results = results.into_iter();
+                    for (index, run) in chunk {
+                        let result = results
+                            .next()
+                            .unwrap_or_else(|| Err("missing batch inference result".into()))
+                            .map_err(|e| e.to_string());
+                        let pipeline = snapshot.pipeline_for(&run.news().source);
+                        match pipeline.resume(*run, result, elapsed).await {
+                            PipelineRun::Finished(outcome) => outcomes[index] = Some(outcome),
+                            PipelineRun::AwaitingInference(run) => paused.push((index, run)),
+                        }
                     }
-
-                    return ClassificationOutcome {
-                        domain: Some(domain),
-                        confidence: result.confidence,
-                        reason: ai_reason,
-                        is_relevant: true,
-                    };
-                }
-                Err(e) => {
-                    warn!("❌ AI 仲裁失败: {}", e);
-                    // AI 失败时不直接丢弃，继续进入兜底逻辑
                 }
             }
         }
 
-        // --- 第五阶段：兜底 ---
-        if let Some(domain) = best_domain {
-            if best_confidence > 0.3 {
-                return ClassificationOutcome {
-                    domain: Some(domain),
-                    confidence: best_confidence,
-                    reason: "[WeakMatchFallback]".to_string(),
-                    is_relevant: true,
-                };
-            }
+        outcomes
+            .into_iter()
+            .map(|outcome| outcome.expect("every paused run is resumed"))
+            .collect()
+    }
+
+    /// 批量分类，结果原地写回每条新闻（不丢弃任何条目）
+    ///
+    /// 相关的新闻标记为 `Completed`（结果不够可靠时标记为 `NeedsReview`），
+    /// 无关的新闻清空领域并标记为 `Irrelevant`，都会记录置信度和分类依据。
+    ///
+    /// 执行分两步：
+    /// - 每条新闻依次执行 AI 阶段之前的各阶段（关键词、来源倾向、正文抓取）
+    /// - 需要 AI 仲裁的新闻按 `batch_size` 合并，每批一次推理请求，再各自完成剩余阶段
+    pub async fn classify_batch(&self, items: &mut [NewsItem]) {
+        // 获取当前配置快照（只克隆 Arc，不重建关键词自动机）
+        let snapshot = self.snapshot();
+
+        let mut runs = Vec::with_capacity(items.len());
+        for item in items.iter() {
+            runs.push(self.start_item(&snapshot, item).await);
         }
+        let outcomes = self.finish_with_batched_inference(&snapshot, runs).await;
 
-        ClassificationOutcome {
-            domain: None,
-            confidence: 0.0,
-            reason: "Unclassifiable (No confident match and content unavailable/irrelevant)"
-                .to_string(),
-            is_relevant: false,
+        for (item, outcome) in items.iter_mut().zip(outcomes) {
+            item.domain = outcome.domain;
+            item.secondary_domains = outcome.secondary_domains;
+            item.classification_confidence = Some(outcome.confidence);
+            item.classification_reason = Some(outcome.reason);
+            item.classification_trace = Some(outcome.trace);
+            item.status = match (outcome.is_relevant, outcome.needs_review) {
+                (false, _) => NewsItemStatus::Irrelevant,
+                (true, true) => NewsItemStatus::NeedsReview,
+                (true, false) => NewsItemStatus::Completed,
+            };
         }
     }
 
-    /// 批量并发处理并过滤
-    ///
-    /// 并发策略：
-    /// - 全文抓取（HTTP IO）对所有条目并发执行
-    /// - Ollama 推理请求并发发出（Ollama 内部按 OLLAMA_NUM_PARALLEL 排队）
-    /// - 相比串行，IO 等待时间大幅减少
+    /// 批量并发处理并过滤（无关的新闻直接丢弃）
     pub async fn classify_batch_and_filter(&self, items: &mut Vec<NewsItem>) {
-        // 1. 取出所有条目所有权
-        let all_items: Vec<NewsItem> = items.drain(..).collect();
+        self.classify_batch(items).await;
+        items.retain(|item| {
+            let relevant = item.status != NewsItemStatus::Irrelevant;
+            if !relevant {
+                info!(
+                    "🗑️ 丢弃无关项: {} | 依据: {}",
+                    item.title,
+                    item.classification_reason.as_deref().unwrap_or_default()
+                );
+            }
+            relevant
+        });
+    }
 
-        // 2. 为每条新闻创建分类 future，全部并发执行
-        let outcomes: Vec<ClassificationOutcome> = stream::iter(all_items.clone().into_iter())
-            .map(|item| self.classify_item(item))
-            .buffer_unordered(1) // 此处应该可以设置为环境变量. 用于控制AI的并发请求;
-            .collect()
-            .await;
-
-        // 3. 根据结果重建过滤后的列表
-        let mut filtered_items = Vec::new();
-        for (mut item, outcome) in all_items.into_iter().zip(outcomes) {
-            if outcome.is_relevant {
-                item.domain = outcome.domain;
-                item.classification_confidence = Some(outcome.confidence);
-                item.classification_reason = Some(outcome.reason);
-                item.status = NewsItemStatus::Completed;
-                filtered_items.push(item);
-            } else {
-                info!("🗑️ 丢弃无关项: {} | 依据: {}", item.title, outcome.reason);
+    /// 丢弃命中全局排除规则的新闻，返回丢弃的数量
+    ///
+    /// 抓取后立即调用，被排除的新闻不会再去重、抓取正文或入库（无论是否执行分类）
+    pub fn drop_excluded(&self, items: &mut Vec<NewsItem>) -> usize {
+        let snapshot = self.snapshot();
+        let before = items.len();
+        items.retain(|item| match snapshot.exclusions.matching(item) {
+            Some(pattern) => {
+                info!("🚫 排除: {} | 规则: {}", item.title, pattern);
+                false
             }
-        }
-        *items = filtered_items;
+            None => true,
+        });
+        before - items.len()
+    }
+
+    /// 当前配置中的领域（按配置顺序）
+    pub fn domains(&self) -> Vec<DomainDefinition> {
+        self.snapshot().config.domains.clone()
     }
 
-    /// 暂时禁用的关键词学习方法
-    #[allow(dead_code)]
-    fn append_keywords(&self, domain: Domain, keywords: Vec<String>) {
-        // 当前AI关键词不准，暂时禁用自动学习
-        // 只记录日志供人工审核
-        if !keywords.is_empty() {
-            info!("🔍 AI建议关键词（暂不学习）: {:?}", keywords);
+    /// 当前配置快照；配置文件修改过则先重新加载并替换快照
+    ///
+    /// 新配置加载失败时保留旧快照，直到文件再次修改
+    fn snapshot(&self) -> Arc<ConfigSnapshot> {
+        if let Some(ref file) = self.config_file
+            && file.changed()
+        {
+            match ClassificationConfig::load_from_file(&file.path) {
+                Ok(config) => {
+                    info!("🔄 配置文件已修改，重新加载: {}", file.path.display());
+                    *self.config.write().unwrap() = ConfigSnapshot::new(config);
+                }
+                Err(e) => warn!("⚠️ 重新加载配置失败，继续使用旧配置: {}", e),
+            }
         }
+        Arc::clone(&self.config.read().unwrap())
     }
 
+    /// 按领域分组；有次要领域的新闻会同时出现在多个分组中
+    ///
+    /// 配置中的每个领域都有分组（可能为空）；旧数据中已不在配置里的领域也会单独成组
     pub fn group_by_domain(&self, news_items: &[NewsItem]) -> HashMap<Domain, Vec<NewsItem>> {
-        let mut grouped = HashMap::new();
-        grouped.insert(Domain::AI, Vec::new());
-        grouped.insert(Domain::Block, Vec::new());
-        grouped.insert(Domain::Social, Vec::new());
+        let mut grouped: HashMap<Domain, Vec<NewsItem>> = self
+            .domains()
+            .into_iter()
+            .map(|definition| (definition.name, Vec::new()))
+            .collect();
 
         for item in news_items {
-            if let Some(domain) = item.domain {
-                grouped
-                    .entry(domain)
-                    .or_insert_with(Vec::new)
-                    .push(item.clone());
+            for domain in item.domains() {
+                grouped.entry(domain).or_default().push(item.clone());
             }
         }
         grouped
@@ -1,8 +1,14 @@
 //! # News Deduplication Service
 //!
 //! Provides deduplication logic for news items based on various criteria.
+//!
+//! Only exact duplicates are dropped here. Near-duplicate titles used to be
+//! removed by a fuzzy title mode; that mode was superseded by
+//! `StoryClusteringService`, which keeps such items as related coverage of
+//! the same story instead of discarding them.
 
 use crate::domain::NewsItem;
+use crate::domain::services::UrlCanonicalizer;
 use std::collections::HashSet;
 
 /// Service for deduplicating news items
@@ -11,13 +17,14 @@ pub struct NewsDeduplicationService;
 impl NewsDeduplicationService {
     /// Deduplicate news items by URL
     ///
+    /// URLs are compared in canonical form (see `UrlCanonicalizer`).
     /// Keeps only the first occurrence of each unique URL.
     pub fn deduplicate_by_url(news: Vec<NewsItem>) -> Vec<NewsItem> {
         let mut seen: HashSet<String> = HashSet::new();
         let mut result = Vec::new();
 
         for item in news {
-            if seen.insert(item.url.clone()) {
+            if seen.insert(UrlCanonicalizer::canonicalize(&item.url)) {
                 result.push(item);
             }
         }
@@ -103,6 +110,21 @@ mod tests {
         assert_eq!(result[0].title, "Title 1"); // Should keep the first one
     }
 
+    #[test]
+    fn test_deduplicate_by_url_compares_canonical_form() {
+        let news = vec![
+            create_test_news_item("1", "https://example.com/post", "Title 1"),
+            create_test_news_item("2", "http://www.example.com/post/", "Title 2"),
+            create_test_news_item("3", "https://example.com/post?utm_source=x#top", "Title 3"),
+        ];
+
+        let result = NewsDeduplicationService::deduplicate_by_url(news);
+
+        assert_eq!(result.len(), 1);
+        // The original URL is kept for display
+        assert_eq!(result[0].url, "https://example.com/post");
+    }
+
     #[test]
     fn test_deduplicate_by_title_removes_duplicates() {
         let news = vec![
@@ -2,7 +2,7 @@
 //!
 //! Service interface for deep analysis of news items using AI/ML.
 
-use crate::domain::{Domain, NewsItem};
+use crate::domain::{Domain, DomainLabel, NewsItem};
 use async_trait::async_trait;
 
 /// Results of an AI inference analysis
@@ -11,9 +11,12 @@ pub struct InferenceResult {
     /// Whether the news item is relevant to our target domains
     pub is_relevant: bool,
 
-    /// The detected domain
+    /// The detected (primary) domain
     pub domain: Option<Domain>,
 
+    /// Further domains the item also belongs to, with their own confidence
+    pub secondary_domains: Vec<DomainLabel>,
+
     /// Confidence score (0.0 - 1.0)
     pub confidence: f32,
 
@@ -33,6 +36,21 @@ pub trait NewsInferenceService: Send + Sync {
         news: &NewsItem,
     ) -> Result<InferenceResult, Box<dyn std::error::Error + Send + Sync>>;
 
+    /// Analyze several news items, returning one result per item in input order
+    ///
+    /// The default asks about each item in turn; services that can pack
+    /// several items into one request override this.
+    async fn infer_batch(
+        &self,
+        news: &[NewsItem],
+    ) -> Vec<Result<InferenceResult, Box<dyn std::error::Error + Send + Sync>>> {
+        let mut results = Vec::with_capacity(news.len());
+        for item in news {
+            results.push(self.infer(item).await);
+        }
+        results
+    }
+
     /// Analysis name (e.g., "openai-gpt4", "mock-inference")
     fn name(&self) -> &str;
 }
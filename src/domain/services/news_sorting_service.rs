@@ -3,11 +3,79 @@
 //! Provides sorting logic for news items based on various criteria.
 
 use crate::domain::NewsItem;
+use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+
+/// Ordering applied to a list of news items
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum SortOrder {
+    /// Newest first
+    #[default]
+    Recency,
+    /// Time-decayed engagement score, hottest first
+    Hotness,
+    /// Highest classification confidence first
+    Confidence,
+    /// Grouped by source name, newest first within each source
+    Source,
+}
 
 /// Service for sorting news items
 pub struct NewsSortingService;
 
 impl NewsSortingService {
+    /// Gravity of the hotness formula; higher values make stories decay faster
+    pub const HOTNESS_GRAVITY: f64 = 1.8;
+
+    /// Sort news items by the given order
+    pub fn sort(news: Vec<NewsItem>, order: SortOrder) -> Vec<NewsItem> {
+        match order {
+            SortOrder::Recency => Self::sort_by_published_at_desc(news),
+            SortOrder::Hotness => Self::sort_by_hotness_desc(news, Utc::now()),
+            SortOrder::Confidence => Self::sort_by_confidence_desc(news),
+            // Stable sort keeps the recency order inside each source
+            SortOrder::Source => Self::sort_by_source(Self::sort_by_published_at_desc(news)),
+        }
+    }
+
+    /// Hacker News style hotness: `(points - 1) / (age_hours + 2) ^ gravity`
+    ///
+    /// Items without an engagement score count as zero points.
+    pub fn hotness_score(news: &NewsItem, now: DateTime<Utc>) -> f64 {
+        let points = news.score.unwrap_or(0) as f64;
+        let age_hours = (now - news.published_at).num_seconds().max(0) as f64 / 3600.0;
+        (points - 1.0).max(0.0) / (age_hours + 2.0).powf(Self::HOTNESS_GRAVITY)
+    }
+
+    /// Sort news items by hotness at `now` (hottest first, ties broken by recency)
+    pub fn sort_by_hotness_desc(news: Vec<NewsItem>, now: DateTime<Utc>) -> Vec<NewsItem> {
+        let mut scored: Vec<(f64, NewsItem)> = news
+            .into_iter()
+            .map(|item| (Self::hotness_score(&item, now), item))
+            .collect();
+        scored.sort_by(|(score_a, a), (score_b, b)| {
+            score_b
+                .partial_cmp(score_a)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| b.published_at.cmp(&a.published_at))
+        });
+        scored.into_iter().map(|(_, item)| item).collect()
+    }
+
+    /// Sort news items by classification confidence (highest first, unclassified last)
+    pub fn sort_by_confidence_desc(news: Vec<NewsItem>) -> Vec<NewsItem> {
+        let mut sorted = news;
+        sorted.sort_by(|a, b| {
+            let conf_a = a.classification_confidence.unwrap_or(-1.0);
+            let conf_b = b.classification_confidence.unwrap_or(-1.0);
+            conf_b
+                .partial_cmp(&conf_a)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| b.published_at.cmp(&a.published_at))
+        });
+        sorted
+    }
+
     /// Sort news items by publication date (newest first)
     pub fn sort_by_published_at_desc(news: Vec<NewsItem>) -> Vec<NewsItem> {
         let mut sorted = news;
@@ -138,6 +206,70 @@ mod tests {
         assert_eq!(result[2].author, "Zack");
     }
 
+    #[test]
+    fn test_hotness_prefers_engagement_over_recency() {
+        let now = Utc::now();
+        let mut fresh = create_test_news_item("1", "Fresh", 0);
+        fresh.published_at = now - Duration::minutes(10);
+        fresh.score = Some(5);
+        let mut popular = create_test_news_item("2", "Popular", 0);
+        popular.published_at = now - Duration::hours(3);
+        popular.score = Some(400);
+        let mut unscored = create_test_news_item("3", "Unscored", 0);
+        unscored.published_at = now;
+
+        let result = NewsSortingService::sort_by_hotness_desc(vec![fresh, unscored, popular], now);
+
+        assert_eq!(result[0].title, "Popular");
+        assert_eq!(result[1].title, "Fresh");
+        assert_eq!(result[2].title, "Unscored");
+    }
+
+    #[test]
+    fn test_hotness_decays_with_age() {
+        let now = Utc::now();
+        let mut item = create_test_news_item("1", "Story", 0);
+        item.score = Some(100);
+        item.published_at = now - Duration::hours(1);
+        let young = NewsSortingService::hotness_score(&item, now);
+        item.published_at = now - Duration::hours(24);
+        let old = NewsSortingService::hotness_score(&item, now);
+
+        assert!(young > old);
+        assert!(old > 0.0);
+    }
+
+    #[test]
+    fn test_sort_by_confidence_puts_unclassified_last() {
+        let mut high = create_test_news_item("1", "High", -1);
+        high.classification_confidence = Some(0.9);
+        let mut low = create_test_news_item("2", "Low", 0);
+        low.classification_confidence = Some(0.4);
+        let none = create_test_news_item("3", "None", 1);
+
+        let result = NewsSortingService::sort_by_confidence_desc(vec![none, low, high]);
+
+        assert_eq!(result[0].title, "High");
+        assert_eq!(result[1].title, "Low");
+        assert_eq!(result[2].title, "None");
+    }
+
+    #[test]
+    fn test_sort_by_source_order_keeps_recency_within_source() {
+        let mut older = create_test_news_item("1", "Older", -2);
+        older.source = "b".to_string();
+        let mut newer = create_test_news_item("2", "Newer", 0);
+        newer.source = "b".to_string();
+        let mut other = create_test_news_item("3", "Other", -5);
+        other.source = "a".to_string();
+
+        let result = NewsSortingService::sort(vec![older, other, newer], SortOrder::Source);
+
+        assert_eq!(result[0].title, "Other");
+        assert_eq!(result[1].title, "Newer");
+        assert_eq!(result[2].title, "Older");
+    }
+
     #[test]
     fn test_sort_empty_vector() {
         let news: Vec<NewsItem> = vec![];
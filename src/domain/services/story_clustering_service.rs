@@ -0,0 +1,320 @@
+//! # Story Clustering Service
+//!
+//! Groups news items that cover the same event into story clusters, keeping
+//! the related coverage instead of dropping it.
+
+use crate::domain::NewsItem;
+use crate::domain::services::{TitleSignature, UrlCanonicalizer};
+use chrono::{DateTime, Duration, Utc};
+
+/// One event and all the items covering it
+#[derive(Debug, Clone)]
+pub struct StoryCluster {
+    /// The item shown for the story
+    pub primary: NewsItem,
+
+    /// Other coverage of the same story
+    pub related: Vec<NewsItem>,
+}
+
+impl StoryCluster {
+    /// Cluster id shared by all items of the story
+    pub fn cluster_id(&self) -> &str {
+        self.primary
+            .cluster_id
+            .as_deref()
+            .unwrap_or(&self.primary.id)
+    }
+
+    /// Whether the story was started by an item outside this cluster
+    ///
+    /// True for late coverage joining a story that was already persisted (and
+    /// announced) in an earlier fetch.
+    pub fn continues_stored_story(&self) -> bool {
+        let cluster_id = self.cluster_id();
+        self.primary.id != cluster_id && self.related.iter().all(|n| n.id != cluster_id)
+    }
+
+    /// Distinct sources of the related coverage, excluding the primary item's source
+    pub fn related_sources(&self) -> Vec<&str> {
+        let mut sources: Vec<&str> = Vec::new();
+        for item in &self.related {
+            if item.source != self.primary.source && !sources.contains(&item.source.as_str()) {
+                sources.push(&item.source);
+            }
+        }
+        sources
+    }
+}
+
+/// Service for clustering news items into stories
+///
+/// Two items belong to the same story when they share a canonical URL, or
+/// when their titles are similar enough and they were published within the
+/// time window of each other.
+pub struct StoryClusteringService {
+    title_similarity: f32,
+    window: Duration,
+}
+
+impl Default for StoryClusteringService {
+    fn default() -> Self {
+        Self::new(
+            Self::DEFAULT_TITLE_SIMILARITY,
+            Duration::hours(Self::DEFAULT_WINDOW_HOURS),
+        )
+    }
+}
+
+impl StoryClusteringService {
+    /// Default similarity above which two titles count as the same story
+    pub const DEFAULT_TITLE_SIMILARITY: f32 = 0.6;
+
+    /// Default maximum distance between publish times of the same story
+    pub const DEFAULT_WINDOW_HOURS: i64 = 48;
+
+    /// Create a clustering service with a title similarity threshold and time window
+    pub fn new(title_similarity: f32, window: Duration) -> Self {
+        Self {
+            title_similarity,
+            window,
+        }
+    }
+
+    /// Assign a cluster id to every item of `news` that does not have one yet
+    ///
+    /// Items join the cluster of the first matching item in `stored` (recently
+    /// persisted items) or earlier in `news`; items matching nothing start a
+    /// new cluster named after their own id.
+    pub fn assign_cluster_ids(&self, news: &mut [NewsItem], stored: &[NewsItem]) {
+        let mut members: Vec<ClusterMember> = stored.iter().map(ClusterMember::new).collect();
+
+        for item in news.iter_mut() {
+            let mut member = ClusterMember::new(item);
+            if item.cluster_id.is_none() {
+                let cluster_id = members
+                    .iter()
+                    .find(|m| self.same_story(m, &member))
+                    .map(|m| m.cluster_id.clone())
+                    .unwrap_or_else(|| item.id.clone());
+                member.cluster_id = cluster_id.clone();
+                item.cluster_id = Some(cluster_id);
+            }
+            members.push(member);
+        }
+    }
+
+    /// Group items into stories by cluster id, keeping the input order
+    ///
+    /// The first item of each cluster becomes the primary item, so sort the
+    /// input beforehand. Items without a cluster id form their own story.
+    pub fn group(news: Vec<NewsItem>) -> Vec<StoryCluster> {
+        let mut clusters: Vec<StoryCluster> = Vec::new();
+
+        for item in news {
+            let cluster_id = item.cluster_id.clone().unwrap_or_else(|| item.id.clone());
+            match clusters.iter_mut().find(|c| c.cluster_id() == cluster_id) {
+                Some(cluster) => cluster.related.push(item),
+                None => clusters.push(StoryCluster {
+                    primary: item,
+                    related: Vec::new(),
+                }),
+            }
+        }
+
+        clusters
+    }
+
+    fn same_story(&self, a: &ClusterMember, b: &ClusterMember) -> bool {
+        if a.canonical_url == b.canonical_url {
+            return true;
+        }
+        (a.published_at - b.published_at).abs() <= self.window
+            && a.signature.similarity(&b.signature) >= self.title_similarity
+    }
+}
+
+/// Precomputed comparison keys of a clustered item
+struct ClusterMember {
+    cluster_id: String,
+    canonical_url: String,
+    signature: TitleSignature,
+    published_at: DateTime<Utc>,
+}
+
+impl ClusterMember {
+    fn new(item: &NewsItem) -> Self {
+        Self {
+            cluster_id: item.cluster_id.clone().unwrap_or_else(|| item.id.clone()),
+            canonical_url: UrlCanonicalizer::canonicalize(&item.url),
+            signature: TitleSignature::new(&item.title),
+            published_at: item.published_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn news(id: &str, source: &str, url: &str, title: &str, hours_ago: i64) -> NewsItem {
+        NewsItem::new(
+            id.to_string(),
+            title.to_string(),
+            url.to_string(),
+            source.to_string(),
+            "author".to_string(),
+            Utc::now() - Duration::hours(hours_ago),
+        )
+    }
+
+    #[test]
+    fn test_assign_cluster_ids_by_title_and_url() {
+        let service = StoryClusteringService::default();
+        let mut batch = vec![
+            news(
+                "1",
+                "hackernews",
+                "https://a.com/gpt",
+                "OpenAI releases GPT-5 model",
+                0,
+            ),
+            news(
+                "2",
+                "techblog",
+                "https://b.com/x",
+                "OpenAI releases the GPT-5 model!",
+                1,
+            ),
+            news(
+                "3",
+                "coindesk",
+                "http://www.a.com/gpt/",
+                "Unrelated headline",
+                1,
+            ),
+            news(
+                "4",
+                "coindesk",
+                "https://c.com/eth",
+                "Ethereum completes upgrade",
+                0,
+            ),
+        ];
+
+        service.assign_cluster_ids(&mut batch, &[]);
+
+        let ids: Vec<&str> = batch
+            .iter()
+            .map(|n| n.cluster_id.as_deref().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["1", "1", "1", "4"]);
+    }
+
+    #[test]
+    fn test_version_bumps_are_separate_stories() {
+        let service = StoryClusteringService::default();
+        let mut batch = vec![
+            news(
+                "1",
+                "hackernews",
+                "https://a.com/4",
+                "OpenAI releases GPT-4",
+                0,
+            ),
+            news(
+                "2",
+                "techblog",
+                "https://b.com/5",
+                "OpenAI releases GPT-5",
+                0,
+            ),
+        ];
+
+        service.assign_cluster_ids(&mut batch, &[]);
+
+        assert_eq!(batch[1].cluster_id.as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn test_assign_cluster_ids_joins_stored_story_within_window() {
+        let service = StoryClusteringService::default();
+        let mut stored = news(
+            "old",
+            "hackernews",
+            "https://a.com/1",
+            "Bitcoin ETF sees record inflows",
+            5,
+        );
+        stored.cluster_id = Some("story-1".to_string());
+        let stale = news(
+            "stale",
+            "hackernews",
+            "https://a.com/2",
+            "Apple unveils new iPhone",
+            100,
+        );
+
+        let mut batch = vec![
+            news(
+                "1",
+                "coindesk",
+                "https://b.com/1",
+                "Bitcoin ETF sees record inflows",
+                0,
+            ),
+            news(
+                "2",
+                "verge",
+                "https://b.com/2",
+                "Apple unveils new iPhone",
+                0,
+            ),
+        ];
+        service.assign_cluster_ids(&mut batch, &[stored, stale]);
+
+        assert_eq!(batch[0].cluster_id.as_deref(), Some("story-1"));
+        // Outside the time window: a new story
+        assert_eq!(batch[1].cluster_id.as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn test_group_keeps_related_coverage() {
+        let mut items = vec![
+            news("1", "hackernews", "https://a.com/1", "Story", 0),
+            news("2", "techblog", "https://b.com/1", "Story", 0),
+            news("3", "techblog", "https://c.com/1", "Story", 0),
+            news("4", "hackernews", "https://d.com/1", "Other", 0),
+        ];
+        for item in &mut items[..3] {
+            item.cluster_id = Some("1".to_string());
+        }
+
+        let clusters = StoryClusteringService::group(items);
+
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].primary.id, "1");
+        assert_eq!(clusters[0].related.len(), 2);
+        assert_eq!(clusters[0].related_sources(), vec!["techblog"]);
+        assert_eq!(clusters[1].cluster_id(), "4");
+        assert!(clusters[1].related.is_empty());
+        assert!(!clusters[0].continues_stored_story());
+        assert!(!clusters[1].continues_stored_story());
+    }
+
+    #[test]
+    fn test_late_coverage_continues_stored_story() {
+        let mut items = vec![
+            news("2", "techblog", "https://b.com/1", "Story", 0),
+            news("3", "coindesk", "https://c.com/1", "Story", 0),
+        ];
+        for item in &mut items {
+            item.cluster_id = Some("1".to_string());
+        }
+
+        let clusters = StoryClusteringService::group(items);
+
+        assert_eq!(clusters.len(), 1);
+        assert!(clusters[0].continues_stored_story());
+    }
+}
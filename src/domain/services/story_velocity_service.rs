@@ -0,0 +1,148 @@
+//! # Story Velocity Service
+//!
+//! Derives how fast stored stories are gaining engagement from their snapshots.
+
+use crate::domain::StorySnapshot;
+use chrono::Duration;
+use std::collections::HashMap;
+
+/// Engagement gained by one story across a window of snapshots
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoryVelocity {
+    /// ID of the stored news item
+    pub news_id: String,
+
+    /// Points gained between the first and last snapshot
+    pub score_gain: i64,
+
+    /// Comments gained between the first and last snapshot
+    pub comment_gain: i64,
+
+    /// Time between the first and last snapshot
+    pub elapsed: Duration,
+
+    /// Score in the most recent snapshot
+    pub latest_score: Option<u32>,
+
+    /// Rank in the most recent snapshot
+    pub latest_rank: Option<u32>,
+}
+
+impl StoryVelocity {
+    /// Points gained per hour (0 when the snapshots share a timestamp)
+    pub fn points_per_hour(&self) -> f64 {
+        let hours = self.elapsed.num_seconds() as f64 / 3600.0;
+        if hours > 0.0 {
+            self.score_gain as f64 / hours
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Service for computing story velocity
+pub struct StoryVelocityService;
+
+impl StoryVelocityService {
+    /// Compute velocity per story, fastest-rising first
+    ///
+    /// Stories are ranked by points gained per hour, so a story observed over
+    /// a short span is not outranked by one that merely had more time to
+    /// accumulate points. Stories need at least two snapshots to have a velocity; snapshots may
+    /// be passed in any order.
+    pub fn compute(snapshots: &[StorySnapshot]) -> Vec<StoryVelocity> {
+        let mut by_story: HashMap<&str, Vec<&StorySnapshot>> = HashMap::new();
+        for snapshot in snapshots {
+            by_story
+                .entry(snapshot.news_id.as_str())
+                .or_default()
+                .push(snapshot);
+        }
+
+        let mut velocities: Vec<StoryVelocity> = by_story
+            .into_values()
+            .filter(|history| history.len() >= 2)
+            .map(|mut history| {
+                history.sort_by_key(|s| s.captured_at);
+                let first = history[0];
+                let last = history[history.len() - 1];
+
+                StoryVelocity {
+                    news_id: last.news_id.clone(),
+                    score_gain: gain(first.score, last.score),
+                    comment_gain: gain(first.comment_count, last.comment_count),
+                    elapsed: last.captured_at - first.captured_at,
+                    latest_score: last.score,
+                    latest_rank: last.rank,
+                }
+            })
+            .collect();
+
+        velocities.sort_by(|a, b| {
+            b.points_per_hour()
+                .total_cmp(&a.points_per_hour())
+                .then_with(|| b.score_gain.cmp(&a.score_gain))
+                .then_with(|| b.comment_gain.cmp(&a.comment_gain))
+                .then_with(|| a.news_id.cmp(&b.news_id))
+        });
+        velocities
+    }
+}
+
+fn gain(first: Option<u32>, last: Option<u32>) -> i64 {
+    last.unwrap_or(0) as i64 - first.unwrap_or(0) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+
+    fn snapshot(news_id: &str, minutes_ago: i64, score: u32, now: DateTime<Utc>) -> StorySnapshot {
+        StorySnapshot {
+            news_id: news_id.to_string(),
+            captured_at: now - Duration::minutes(minutes_ago),
+            score: Some(score),
+            comment_count: Some(score / 10),
+            rank: Some(1),
+        }
+    }
+
+    #[test]
+    fn test_compute_orders_by_points_per_hour() {
+        let now = Utc::now();
+        let snapshots = vec![
+            snapshot("slow", 60, 100, now),
+            snapshot("slow", 0, 120, now),
+            // Smaller total gain, but over a much shorter span
+            snapshot("burst", 10, 100, now),
+            snapshot("burst", 0, 200, now),
+            // Out of order on purpose
+            snapshot("fast", 0, 400, now),
+            snapshot("fast", 60, 100, now),
+            // A single observation has no velocity
+            snapshot("once", 30, 999, now),
+        ];
+
+        let velocities = StoryVelocityService::compute(&snapshots);
+
+        assert_eq!(velocities.len(), 3);
+        assert_eq!(velocities[0].news_id, "burst");
+        assert_eq!(velocities[0].points_per_hour(), 600.0);
+        assert_eq!(velocities[1].news_id, "fast");
+        assert_eq!(velocities[1].score_gain, 300);
+        assert_eq!(velocities[1].comment_gain, 30);
+        assert_eq!(velocities[1].latest_score, Some(400));
+        assert_eq!(velocities[1].points_per_hour(), 300.0);
+        assert_eq!(velocities[2].news_id, "slow");
+    }
+
+    #[test]
+    fn test_points_per_hour_with_zero_elapsed() {
+        let now = Utc::now();
+        let snapshots = vec![snapshot("a", 0, 10, now), snapshot("a", 0, 20, now)];
+
+        let velocities = StoryVelocityService::compute(&snapshots);
+        assert_eq!(velocities[0].points_per_hour(), 0.0);
+    }
+}
@@ -0,0 +1,204 @@
+//! # Title Similarity
+//!
+//! MinHash signatures over normalized headline tokens, used to spot the same
+//! story published under slightly different titles.
+//!
+//! Token overlap alone cannot tell "GPT-4" from "GPT-5": a single changed
+//! version number leaves most tokens shared, more than a reworded headline
+//! of the same story often does. Titles whose numbers have nothing in
+//! common are therefore never considered similar.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeSet, HashSet};
+use std::hash::{Hash, Hasher};
+
+/// Number of hash functions in a signature; the estimate error is about `1 / sqrt(N)`
+const NUM_HASHES: usize = 128;
+
+/// Words that carry no meaning for headline comparison
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "by", "for", "from", "in", "is", "it", "of", "on", "or",
+    "the", "to", "with",
+];
+
+/// MinHash signature of a title
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TitleSignature {
+    mins: Vec<u64>,
+    numbers: BTreeSet<String>,
+}
+
+impl TitleSignature {
+    /// Compute the signature of `title`
+    ///
+    /// Titles without any meaningful token get an empty signature, which is
+    /// never similar to anything.
+    pub fn new(title: &str) -> Self {
+        let shingles = shingles(title);
+        if shingles.is_empty() {
+            return Self {
+                mins: Vec::new(),
+                numbers: BTreeSet::new(),
+            };
+        }
+
+        let mins = (0..NUM_HASHES as u64)
+            .map(|seed| {
+                shingles
+                    .iter()
+                    .map(|shingle| seeded_hash(seed, shingle))
+                    .min()
+                    .unwrap_or(u64::MAX)
+            })
+            .collect();
+        Self {
+            mins,
+            numbers: numbers(title),
+        }
+    }
+
+    /// Estimated Jaccard similarity of the two titles' token sets (0.0 - 1.0)
+    ///
+    /// Titles that both contain numbers but share none of them (e.g. version
+    /// numbers) score 0.0.
+    pub fn similarity(&self, other: &TitleSignature) -> f32 {
+        if self.mins.is_empty() || other.mins.is_empty() {
+            return 0.0;
+        }
+        if !self.numbers.is_empty()
+            && !other.numbers.is_empty()
+            && self.numbers.is_disjoint(&other.numbers)
+        {
+            return 0.0;
+        }
+
+        let matching = self
+            .mins
+            .iter()
+            .zip(&other.mins)
+            .filter(|(a, b)| a == b)
+            .count();
+        matching as f32 / NUM_HASHES as f32
+    }
+}
+
+/// Normalized token shingles of a title
+///
+/// Titles are lowercased and split on anything that is not alphanumeric;
+/// stopwords are dropped. CJK text has no spaces, so every non-ASCII
+/// character becomes its own token.
+fn shingles(title: &str) -> HashSet<String> {
+    let mut tokens = HashSet::new();
+    let mut word = String::new();
+
+    for c in title.chars().flat_map(char::to_lowercase) {
+        if c.is_ascii_alphanumeric() {
+            word.push(c);
+            continue;
+        }
+        push_word(&mut tokens, &mut word);
+        if c.is_alphanumeric() {
+            tokens.insert(c.to_string());
+        }
+    }
+    push_word(&mut tokens, &mut word);
+
+    tokens
+}
+
+/// Digit runs of a title, e.g. `{"4"}` for "GPT-4o" and `{"17"}` for "iPhone 17"
+fn numbers(title: &str) -> BTreeSet<String> {
+    title
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|run| !run.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn push_word(tokens: &mut HashSet<String>, word: &mut String) {
+    if !word.is_empty() && !STOPWORDS.contains(&word.as_str()) {
+        tokens.insert(word.clone());
+    }
+    word.clear();
+}
+
+fn seeded_hash(seed: u64, shingle: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    seed.hash(&mut hasher);
+    shingle.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn similarity(a: &str, b: &str) -> f32 {
+        TitleSignature::new(a).similarity(&TitleSignature::new(b))
+    }
+
+    #[test]
+    fn test_identical_titles_after_normalization() {
+        assert_eq!(
+            similarity("OpenAI releases GPT-5!", "openai Releases gpt 5"),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_near_duplicate_headlines_score_high() {
+        let score = similarity(
+            "Apple announces new iPhone 17 at September event",
+            "Apple announces iPhone 17 at its September event",
+        );
+        assert!(score > 0.6, "score = {}", score);
+    }
+
+    #[test]
+    fn test_unrelated_headlines_score_low() {
+        let score = similarity(
+            "Bitcoin hits a new all-time high",
+            "Show HN: A tiny Rust web framework",
+        );
+        assert!(score < 0.2, "score = {}", score);
+    }
+
+    #[test]
+    fn test_headlines_differing_only_by_version_are_not_similar() {
+        assert_eq!(
+            similarity("OpenAI releases GPT-4", "OpenAI releases GPT-5"),
+            0.0
+        );
+        assert_eq!(
+            similarity(
+                "Apple announces iPhone 16 at September event",
+                "Apple announces iPhone 17 at September event"
+            ),
+            0.0
+        );
+        // A shared number, or a number on one side only, does not block the match
+        assert!(
+            similarity(
+                "Apple unveils iPhone 17",
+                "Apple unveils iPhone 17 in 5 colors"
+            ) > 0.4
+        );
+        assert!(
+            similarity(
+                "Bitcoin ETF sees record inflows",
+                "Bitcoin ETF sees $1B record inflows"
+            ) > 0.6
+        );
+    }
+
+    #[test]
+    fn test_cjk_titles_are_tokenized_per_character() {
+        assert!(similarity("以太坊完成升级", "以太坊升级完成") > 0.9);
+    }
+
+    #[test]
+    fn test_empty_title_is_never_similar() {
+        assert_eq!(similarity("", ""), 0.0);
+        assert_eq!(similarity("the of a", "the of a"), 0.0);
+    }
+}
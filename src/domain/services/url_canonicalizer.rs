@@ -0,0 +1,121 @@
+//! # URL Canonicalizer
+//!
+//! Normalizes article URLs so that trivially different links to the same
+//! page compare equal.
+
+use url::Url;
+
+/// Query parameters that only track where a click came from
+const TRACKING_PARAMS: &[&str] = &["ref", "fbclid", "gclid"];
+
+/// Service for canonicalizing news URLs
+pub struct UrlCanonicalizer;
+
+impl UrlCanonicalizer {
+    /// Canonical form of `raw` used for deduplication and storage lookups
+    ///
+    /// - `http` is upgraded to `https`, the host is lowercased and `www.` is dropped
+    /// - default ports, fragments and tracking parameters (`utm_*`, `ref`, `fbclid`, `gclid`) are removed
+    /// - remaining query parameters are sorted and a trailing slash is trimmed
+    ///
+    /// Input that is not an absolute http(s) URL is returned trimmed but otherwise unchanged.
+    pub fn canonicalize(raw: &str) -> String {
+        let trimmed = raw.trim();
+        let Ok(mut url) = Url::parse(trimmed) else {
+            return trimmed.to_string();
+        };
+        if !matches!(url.scheme(), "http" | "https") || url.host_str().is_none() {
+            return trimmed.to_string();
+        }
+
+        // Switching between special schemes cannot fail
+        let _ = url.set_scheme("https");
+        if url.port() == Some(443) {
+            let _ = url.set_port(None);
+        }
+
+        if let Some(host) = url.host_str().and_then(|h| h.strip_prefix("www."))
+            && !host.is_empty()
+        {
+            let host = host.to_string();
+            let _ = url.set_host(Some(&host));
+        }
+
+        url.set_fragment(None);
+
+        let mut params: Vec<(String, String)> = url
+            .query_pairs()
+            .filter(|(key, _)| !is_tracking_param(key))
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+        if params.is_empty() {
+            url.set_query(None);
+        } else {
+            params.sort();
+            url.query_pairs_mut().clear().extend_pairs(&params);
+        }
+
+        let path = url.path();
+        if path.len() > 1 && path.ends_with('/') {
+            let path = path.trim_end_matches('/').to_string();
+            url.set_path(&path);
+        }
+
+        url.to_string()
+    }
+}
+
+fn is_tracking_param(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    key.starts_with("utm_") || TRACKING_PARAMS.contains(&key.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equivalent_urls_share_canonical_form() {
+        let canonical = "https://example.com/post?id=7";
+        for raw in [
+            "https://example.com/post?id=7",
+            "http://example.com/post?id=7",
+            "https://www.Example.com/post/?id=7",
+            "https://example.com:443/post?id=7#comments",
+            "https://example.com/post?utm_source=hn&id=7&utm_medium=social",
+            "https://example.com/post?id=7&ref=frontpage&fbclid=abc&gclid=xyz",
+            "  https://example.com/post?id=7  ",
+        ] {
+            assert_eq!(UrlCanonicalizer::canonicalize(raw), canonical, "{}", raw);
+        }
+    }
+
+    #[test]
+    fn test_query_params_are_sorted_and_kept() {
+        assert_eq!(
+            UrlCanonicalizer::canonicalize("https://example.com/search?q=rust&page=2"),
+            "https://example.com/search?page=2&q=rust"
+        );
+    }
+
+    #[test]
+    fn test_root_path_and_non_default_port() {
+        assert_eq!(
+            UrlCanonicalizer::canonicalize("http://www.example.com"),
+            "https://example.com/"
+        );
+        assert_eq!(
+            UrlCanonicalizer::canonicalize("https://example.com:8443/a/"),
+            "https://example.com:8443/a"
+        );
+    }
+
+    #[test]
+    fn test_unparseable_input_is_returned_trimmed() {
+        assert_eq!(UrlCanonicalizer::canonicalize(" url1 "), "url1");
+        assert_eq!(
+            UrlCanonicalizer::canonicalize("mailto:someone@example.com"),
+            "mailto:someone@example.com"
+        );
+    }
+}
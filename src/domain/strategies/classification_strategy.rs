@@ -2,7 +2,41 @@
 //!
 //! Defines the interface for all classification strategies.
 
-use crate::domain::{Domain, NewsItem};
+use super::keyword_matcher::KeywordStrength;
+use crate::domain::{Domain, DomainLabel, NewsItem};
+
+/// Where in a news item a keyword was found
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MatchLocation {
+    Title,
+    Url,
+    Content,
+}
+
+impl std::fmt::Display for MatchLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatchLocation::Title => write!(f, "title"),
+            MatchLocation::Url => write!(f, "URL"),
+            MatchLocation::Content => write!(f, "content"),
+        }
+    }
+}
+
+/// A keyword hit that supports (or competes with) a classification
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchEvidence {
+    /// The matched keyword (lowercase)
+    pub keyword: String,
+    /// Domain the keyword counts towards
+    pub domain: Domain,
+    /// Whether it is a strong, weak or negative keyword for that domain
+    pub strength: KeywordStrength,
+    /// Where it was found
+    pub location: MatchLocation,
+    /// How many times it was found there
+    pub count: usize,
+}
 
 /// Result of a classification attempt
 #[derive(Debug, Clone, PartialEq)]
@@ -17,6 +51,10 @@ pub struct ClassificationResult {
     pub needs_ai_refinement: bool,
     /// Reason for this classification
     pub reason: String,
+    /// Every keyword hit considered, for all domains (empty for non-keyword strategies)
+    pub evidence: Vec<MatchEvidence>,
+    /// Further domains the item also belongs to, strongest first
+    pub secondary_domains: Vec<DomainLabel>,
 }
 
 impl ClassificationResult {
@@ -28,6 +66,8 @@ impl ClassificationResult {
             strategy_name,
             needs_ai_refinement: false,
             reason: String::new(),
+            evidence: Vec::new(),
+            secondary_domains: Vec::new(),
         }
     }
 
@@ -39,6 +79,8 @@ impl ClassificationResult {
             reason: format!("High confidence match from {}", strategy_name),
             strategy_name,
             needs_ai_refinement: false,
+            evidence: Vec::new(),
+            secondary_domains: Vec::new(),
         }
     }
 
@@ -50,6 +92,8 @@ impl ClassificationResult {
             reason: format!("Medium confidence match from {}", strategy_name),
             strategy_name,
             needs_ai_refinement: false,
+            evidence: Vec::new(),
+            secondary_domains: Vec::new(),
         }
     }
 
@@ -61,6 +105,8 @@ impl ClassificationResult {
             reason: format!("Low confidence match from {}", strategy_name),
             strategy_name,
             needs_ai_refinement: true, // Low confidence results might need AI help
+            evidence: Vec::new(),
+            secondary_domains: Vec::new(),
         }
     }
 
@@ -75,6 +121,41 @@ impl ClassificationResult {
         self.reason = reason;
         self
     }
+
+    /// Set the keyword evidence
+    pub fn with_evidence(mut self, evidence: Vec<MatchEvidence>) -> Self {
+        self.evidence = evidence;
+        self
+    }
+
+    /// Set the secondary domains
+    pub fn with_secondary_domains(mut self, secondary_domains: Vec<DomainLabel>) -> Self {
+        self.secondary_domains = secondary_domains;
+        self
+    }
+
+    /// Human-readable list of the evidence for the classified domain,
+    /// e.g. `'openai' in title (strong), 'llm' ×3 in content (strong)`
+    pub fn evidence_summary(&self) -> String {
+        self.evidence
+            .iter()
+            .filter(|e| e.domain == self.domain)
+            .map(|e| {
+                let count = if e.count > 1 {
+                    format!(" ×{}", e.count)
+                } else {
+                    String::new()
+                };
+                let strength = match e.strength {
+                    KeywordStrength::Strong => "strong",
+                    KeywordStrength::Weak => "weak",
+                    KeywordStrength::Negative => "negative",
+                };
+                format!("'{}'{} in {} ({})", e.keyword, count, e.location, strength)
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
 }
 
 /// Trait for news classification strategies
@@ -99,22 +180,23 @@ mod tests {
 
     #[test]
     fn test_classification_result_creation() {
-        let result = ClassificationResult::new(Domain::AI, 0.85, "test".to_string());
-        assert_eq!(result.domain, Domain::AI);
+        let result = ClassificationResult::new(Domain::new("AI"), 0.85, "test".to_string());
+        assert_eq!(result.domain, Domain::new("AI"));
         assert_eq!(result.confidence, 0.85);
         assert_eq!(result.strategy_name, "test");
     }
 
     #[test]
     fn test_high_confidence_result() {
-        let result = ClassificationResult::high_confidence(Domain::AI, "test".to_string());
+        let result = ClassificationResult::high_confidence(Domain::new("AI"), "test".to_string());
         assert_eq!(result.confidence, 0.9);
         assert!(!result.needs_ai_refinement);
     }
 
     #[test]
     fn test_low_confidence_result() {
-        let result = ClassificationResult::low_confidence(Domain::Social, "test".to_string());
+        let result =
+            ClassificationResult::low_confidence(Domain::new("Social"), "test".to_string());
         assert_eq!(result.confidence, 0.3);
         assert!(result.needs_ai_refinement);
     }
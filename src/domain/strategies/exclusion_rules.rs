@@ -0,0 +1,105 @@
+//! # Exclusion Rules
+//!
+//! Global rules that drop news before classification does any network work,
+//! such as spam hosts or clickbait title patterns.
+
+use crate::domain::NewsItem;
+use crate::domain::config::{ClassificationConfig, ExcludePattern};
+use regex::Regex;
+use tracing::warn;
+
+/// Compiled form of the `exclude_patterns` of a config snapshot
+#[derive(Debug, Clone, Default)]
+pub struct ExclusionRules {
+    hosts: Vec<(String, ExcludePattern)>,
+    titles: Vec<(Regex, ExcludePattern)>,
+}
+
+impl ExclusionRules {
+    /// Compile the rules of `config`; invalid title patterns are skipped with a warning
+    pub fn from_config(config: &ClassificationConfig) -> Self {
+        let mut rules = Self::default();
+        for pattern in &config.exclude_patterns {
+            match pattern {
+                ExcludePattern::Host(host) => {
+                    let host = host.trim().trim_start_matches('.').to_lowercase();
+                    if !host.is_empty() {
+                        rules.hosts.push((host, pattern.clone()));
+                    }
+                }
+                ExcludePattern::Title(regex) => match Regex::new(regex) {
+                    Ok(compiled) => rules.titles.push((compiled, pattern.clone())),
+                    Err(e) => warn!("⚠️ 忽略无效的排除规则 {}: {}", pattern, e),
+                },
+            }
+        }
+        rules
+    }
+
+    /// The first rule that excludes `news`, if any
+    pub fn matching(&self, news: &NewsItem) -> Option<&ExcludePattern> {
+        if let Some(host) = url::Url::parse(&news.url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_lowercase))
+            && let Some((_, pattern)) = self.hosts.iter().find(|(excluded, _)| {
+                host == *excluded || host.ends_with(&format!(".{}", excluded))
+            })
+        {
+            return Some(pattern);
+        }
+
+        self.titles
+            .iter()
+            .find(|(regex, _)| regex.is_match(&news.title))
+            .map(|(_, pattern)| pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn news(title: &str, url: &str) -> NewsItem {
+        NewsItem::new(
+            "1".to_string(),
+            title.to_string(),
+            url.to_string(),
+            "hackernews".to_string(),
+            "author".to_string(),
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn test_host_and_title_rules() {
+        let mut config = ClassificationConfig::empty();
+        config.exclude_patterns = vec![
+            ExcludePattern::Host("spam.example".to_string()),
+            ExcludePattern::Title("(?i)^sponsored:".to_string()),
+            ExcludePattern::Title("(unclosed".to_string()),
+        ];
+        let rules = ExclusionRules::from_config(&config);
+        assert_eq!(rules.titles.len(), 1);
+
+        assert_eq!(
+            rules.matching(&news("OpenAI news", "https://blog.spam.example/a")),
+            Some(&ExcludePattern::Host("spam.example".to_string()))
+        );
+        assert!(
+            rules
+                .matching(&news("OpenAI news", "https://notspam.example/a"))
+                .is_none()
+        );
+        assert!(
+            rules
+                .matching(&news("SPONSORED: Buy tokens", "https://example.com/a"))
+                .is_some()
+        );
+        assert!(
+            rules
+                .matching(&news("Why sponsored posts work", "https://example.com/a"))
+                .is_none()
+        );
+    }
+}
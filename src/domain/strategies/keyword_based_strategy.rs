@@ -2,16 +2,87 @@
 //!
 //! Classifies news based on keyword matching in title, URL, and content.
 
-use super::{ClassificationResult, ClassificationStrategy};
+use super::keyword_matcher::{KeywordHit, KeywordMatcher, KeywordStrength};
+use super::{ClassificationResult, ClassificationStrategy, MatchEvidence, MatchLocation};
 use crate::domain::config::ClassificationConfig;
-use crate::domain::{Domain, NewsItem};
+use crate::domain::{Domain, DomainLabel, NewsItem};
+use std::sync::Arc;
 
 /// Strategy that classifies news based on keyword matching
+///
+/// Every strong and weak keyword hit is collected as [`MatchEvidence`] and
+/// scored per domain, weighted by location (title > URL > content), strength
+/// and frequency; the domain with the highest score wins.
+///
+/// Other domains with strong evidence and a score close to the winner's are
+/// kept as secondary domains, e.g. Block for "AI trading bot on Ethereum".
+///
+/// Negative keywords work against their domain: a keyword hit inside a
+/// negative hit (e.g. "eth" in "ethernet" within a URL) is discarded, and any
+/// other negative hit halves the domain's score.
+///
+/// Holds a shared [`KeywordMatcher`], so cloning the strategy or building it
+/// from a cached matcher does not rebuild the automaton.
+#[derive(Clone)]
 pub struct KeywordBasedStrategy {
-    /// Strong keywords (high confidence)
-    strong_keywords: std::collections::HashMap<Domain, Vec<String>>,
-    /// Weak keywords (low confidence)
-    weak_keywords: std::collections::HashMap<Domain, Vec<String>>,
+    matcher: Arc<KeywordMatcher>,
+}
+
+/// Score and confidence multiplier for a domain that has negative keyword hits
+const NEGATIVE_PENALTY: f32 = 0.5;
+
+/// A domain needs at least this share of the winner's score to become a secondary domain
+const SECONDARY_MIN_SCORE_RATIO: f32 = 0.4;
+
+/// A secondary domain needs strong keyword evidence (title, URL or content)
+const SECONDARY_MIN_TIER: f32 = 0.8;
+
+/// Whether a negative keyword of `domain` covers `hit`, e.g. "ethernet" covering "eth"
+fn is_suppressed(hit: &KeywordHit<'_>, domain: Domain, hits: &[KeywordHit<'_>]) -> bool {
+    hits.iter().any(|other| {
+        other.start <= hit.start
+            && hit.end <= other.end
+            && other.keyword != hit.keyword
+            && other
+                .targets
+                .iter()
+                .any(|t| t.domain == domain && t.strength == KeywordStrength::Negative)
+    })
+}
+
+/// Score contributed by one occurrence of a keyword
+///
+/// Weak keywords are not counted in URLs: short words like "ai" or "ml"
+/// appear inside unrelated path segments too often.
+fn weight(strength: KeywordStrength, location: MatchLocation) -> f32 {
+    match (strength, location) {
+        (KeywordStrength::Strong, MatchLocation::Title) => 1.0,
+        (KeywordStrength::Strong, MatchLocation::Url) => 0.8,
+        (KeywordStrength::Strong, MatchLocation::Content) => 0.5,
+        (KeywordStrength::Weak, MatchLocation::Title) => 0.3,
+        (KeywordStrength::Weak, MatchLocation::Url) => 0.0,
+        (KeywordStrength::Weak, MatchLocation::Content) => 0.1,
+        (KeywordStrength::Negative, _) => 0.0,
+    }
+}
+
+/// Confidence of the strongest kind of evidence, before accounting for competing domains
+fn tier_confidence(strength: KeywordStrength, location: MatchLocation) -> f32 {
+    match (strength, location) {
+        (KeywordStrength::Strong, MatchLocation::Title | MatchLocation::Url) => 0.9,
+        (KeywordStrength::Strong, MatchLocation::Content) => 0.8,
+        (KeywordStrength::Weak, MatchLocation::Title) => 0.4,
+        (KeywordStrength::Weak, _) => 0.3,
+        (KeywordStrength::Negative, _) => 0.0,
+    }
+}
+
+/// Accumulated score of one domain
+#[derive(Debug, Clone, Copy)]
+struct DomainScore {
+    domain: Domain,
+    score: f32,
+    tier: f32,
 }
 
 impl KeywordBasedStrategy {
@@ -21,46 +92,106 @@ impl KeywordBasedStrategy {
     }
 
     pub fn from_config(config: ClassificationConfig) -> Self {
-        Self {
-            strong_keywords: config.strong_keywords,
-            weak_keywords: config.weak_keywords,
-        }
+        Self::from_matcher(Arc::new(KeywordMatcher::from_config(&config)))
+    }
+
+    /// Use an already built matcher
+    pub fn from_matcher(matcher: Arc<KeywordMatcher>) -> Self {
+        Self { matcher }
     }
 
-    /// Check if a text contains a keyword as a standalone word
-    fn contains_word(text: &str, word: &str) -> bool {
-        let text_low = text.to_lowercase();
-        let word_low = word.to_lowercase();
-
-        if let Some(start_idx) = text_low.find(&word_low) {
-            let end_idx = start_idx + word_low.len();
-
-            // Check start boundary
-            let start_ok = if start_idx == 0 {
-                true
-            } else {
-                let prev_char = text_low.as_bytes()[start_idx - 1] as char;
-                !prev_char.is_alphanumeric()
-            };
-
-            // Check end boundary
-            let end_ok = if end_idx == text_low.len() {
-                true
-            } else {
-                let next_char = text_low.as_bytes()[end_idx] as char;
-                !next_char.is_alphanumeric()
-            };
-
-            if start_ok && end_ok {
-                return true;
+    /// Collect every keyword hit in the title, URL and content, grouped by keyword and location
+    fn collect_evidence(&self, news: &NewsItem) -> Vec<MatchEvidence> {
+        let title = KeywordMatcher::normalize(&news.title);
+        let url = KeywordMatcher::normalize(&news.url);
+        let content = news.content.as_deref().map(KeywordMatcher::normalize);
+
+        let mut evidence: Vec<MatchEvidence> = Vec::new();
+        let mut add_hits = |hits: Vec<KeywordHit<'_>>, location: MatchLocation| {
+            for hit in &hits {
+                for target in hit.targets {
+                    if target.strength != KeywordStrength::Negative
+                        && (weight(target.strength, location) == 0.0
+                            || is_suppressed(hit, target.domain, &hits))
+                    {
+                        continue;
+                    }
+                    match evidence.iter_mut().find(|e| {
+                        e.keyword == hit.keyword
+                            && e.domain == target.domain
+                            && e.location == location
+                    }) {
+                        Some(existing) => existing.count += 1,
+                        None => evidence.push(MatchEvidence {
+                            keyword: hit.keyword.to_string(),
+                            domain: target.domain,
+                            strength: target.strength,
+                            location,
+                            count: 1,
+                        }),
+                    }
+                }
             }
+        };
 
-            // If boundaries didn't match, we should continue searching for other occurrences
-            // Recurse on the rest of the string
-            return Self::contains_word(&text[end_idx..], word);
+        add_hits(self.matcher.find_words(&title), MatchLocation::Title);
+        // URL usually doesn't have word boundaries in the same way, but it uses separators
+        add_hits(self.matcher.find_substrings(&url), MatchLocation::Url);
+        if let Some(content) = &content {
+            add_hits(self.matcher.find_words(content), MatchLocation::Content);
         }
+        evidence
+    }
+
+    /// Score each domain that has evidence, best first
+    fn score(&self, evidence: &[MatchEvidence]) -> Vec<DomainScore> {
+        let mut scores: Vec<DomainScore> = self
+            .matcher
+            .domains()
+            .iter()
+            .filter_map(|&domain| {
+                let hits: Vec<&MatchEvidence> =
+                    evidence.iter().filter(|e| e.domain == domain).collect();
+                // Repeated mentions add up, with diminishing returns
+                let mut score: f32 = hits
+                    .iter()
+                    .map(|e| weight(e.strength, e.location) * (1.0 + (e.count as f32).ln()))
+                    .sum();
+                if score <= 0.0 {
+                    return None;
+                }
+                let mut tier = hits
+                    .iter()
+                    .map(|e| tier_confidence(e.strength, e.location))
+                    .fold(0.0, f32::max);
+                if hits.iter().any(|e| e.strength == KeywordStrength::Negative) {
+                    score *= NEGATIVE_PENALTY;
+                    tier *= NEGATIVE_PENALTY;
+                }
+                Some(DomainScore {
+                    domain,
+                    score,
+                    tier,
+                })
+            })
+            .collect();
 
-        false
+        // Stable sort keeps the configured domain order for exact ties
+        scores.sort_by(|a, b| b.score.total_cmp(&a.score).then(b.tier.total_cmp(&a.tier)));
+        scores
+    }
+
+    /// Runner-up domains strong enough to count as secondary domains
+    ///
+    /// Confidence is the domain's evidence tier scaled by its share of the winner's score.
+    fn secondary_domains(best: DomainScore, others: &[DomainScore]) -> Vec<DomainLabel> {
+        others
+            .iter()
+            .filter(|s| {
+                s.tier >= SECONDARY_MIN_TIER && s.score >= best.score * SECONDARY_MIN_SCORE_RATIO
+            })
+            .map(|s| DomainLabel::new(s.domain, s.tier * s.score / best.score))
+            .collect()
     }
 }
 
@@ -72,79 +203,220 @@ impl Default for KeywordBasedStrategy {
 
 impl ClassificationStrategy for KeywordBasedStrategy {
     fn classify(&self, news: &NewsItem) -> Option<ClassificationResult> {
-        let mut best_domain = None;
-        let mut max_confidence = 0.0;
-        let mut matched_keyword = String::new();
-        let mut location = "";
-
-        // Check strong keywords
-        for (domain, keywords) in &self.strong_keywords {
-            for keyword in keywords {
-                if Self::contains_word(&news.title, keyword) {
-                    return Some(
-                        ClassificationResult::high_confidence(*domain, "keyword-based".to_string())
-                            .with_reason(format!("Strong keyword in title: {}", keyword)),
-                    );
-                }
-                // URL usually doesn't have word boundaries in the same way, but it uses separators
-                let url_lower = news.url.to_lowercase();
-                if url_lower.contains(&keyword.to_lowercase()) {
-                    return Some(
-                        ClassificationResult::high_confidence(*domain, "keyword-based".to_string())
-                            .with_reason(format!("Strong keyword in URL: {}", keyword)),
-                    );
-                }
-                if let Some(content) = &news.content {
-                    if Self::contains_word(content, keyword) {
-                        if 0.8 > max_confidence {
-                            max_confidence = 0.8;
-                            best_domain = Some(*domain);
-                            matched_keyword = keyword.clone();
-                            location = "content";
-                        }
-                    }
-                }
-            }
-        }
+        let evidence = self.collect_evidence(news);
+        let scores = self.score(&evidence);
+        let best = *scores.first()?;
 
-        // Check weak keywords
-        for (domain, keywords) in &self.weak_keywords {
-            for keyword in keywords {
-                if Self::contains_word(&news.title, keyword) {
-                    if 0.4 > max_confidence {
-                        max_confidence = 0.4;
-                        best_domain = Some(*domain);
-                        matched_keyword = keyword.clone();
-                        location = "title (weak)";
-                    }
-                }
-                if let Some(content) = &news.content {
-                    if Self::contains_word(content, keyword) {
-                        if 0.3 > max_confidence {
-                            max_confidence = 0.3;
-                            best_domain = Some(*domain);
-                            matched_keyword = keyword.clone();
-                            location = "content (weak)";
-                        }
-                    }
-                }
+        // A close runner-up makes the result ambiguous: an even split halves the confidence
+        let confidence = match scores.get(1) {
+            Some(runner_up) => {
+                let margin = (best.score - runner_up.score) / best.score;
+                best.tier * (0.5 + 0.5 * margin)
             }
+            None => best.tier,
+        };
+
+        let secondary_domains = Self::secondary_domains(best, &scores[1..]);
+        let mut result =
+            ClassificationResult::new(best.domain, confidence, "keyword-based".to_string())
+                .with_evidence(evidence)
+                .with_secondary_domains(secondary_domains);
+        let mut reason = format!(
+            "{} score {:.2}: {}",
+            best.domain,
+            best.score,
+            result.evidence_summary()
+        );
+        if let Some(runner_up) = scores.get(1) {
+            reason.push_str(&format!(
+                "; runner-up {} {:.2}",
+                runner_up.domain, runner_up.score
+            ));
         }
+        if !result.secondary_domains.is_empty() {
+            let labels: Vec<String> = result
+                .secondary_domains
+                .iter()
+                .map(|label| label.to_string())
+                .collect();
+            reason.push_str(&format!("; secondary {}", labels.join(", ")));
+        }
+        result.reason = reason;
+        Some(result)
+    }
+
+    fn name(&self) -> &str {
+        "keyword-based"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn news(title: &str, url: &str, content: Option<&str>) -> NewsItem {
+        let mut news = NewsItem::new(
+            "1".to_string(),
+            title.to_string(),
+            url.to_string(),
+            "hackernews".to_string(),
+            "author".to_string(),
+            Utc::now(),
+        );
+        news.content = content.map(String::from);
+        news
+    }
 
-        if let Some(domain) = best_domain {
-            return Some(
-                ClassificationResult::new(domain, max_confidence, "keyword-based".to_string())
-                    .with_reason(format!(
-                        "Matched keyword '{}' in {}",
-                        matched_keyword, location
-                    )),
+    #[test]
+    fn test_strong_title_keyword_is_high_confidence() {
+        let strategy = KeywordBasedStrategy::new();
+        let result = strategy
+            .classify(&news(
+                "OpenAI ships a new model",
+                "https://example.com/a",
+                None,
+            ))
+            .unwrap();
+
+        assert_eq!(result.domain, Domain::new("AI"));
+        assert_eq!(result.confidence, 0.9);
+        assert!(result.evidence.iter().any(|e| e.keyword == "openai"
+            && e.location == MatchLocation::Title
+            && e.strength == KeywordStrength::Strong));
+        assert!(
+            result.reason.contains("'openai' in title (strong)"),
+            "{}",
+            result.reason
+        );
+    }
+
+    #[test]
+    fn test_competing_domains_are_scored_deterministically() {
+        let strategy = KeywordBasedStrategy::new();
+        let item = news(
+            "OpenAI and Bitcoin",
+            "https://example.com/a",
+            Some("Bitcoin miners, bitcoin ETFs and more bitcoin news."),
+        );
+
+        for _ in 0..10 {
+            let result = strategy.classify(&item).unwrap();
+            assert_eq!(result.domain, Domain::new("Block"));
+            // 两个领域都有强信号，置信度降低，交给后续阶段
+            assert!(result.confidence < 0.7, "{}", result.confidence);
+            assert!(
+                result
+                    .evidence
+                    .iter()
+                    .any(|e| e.domain == Domain::new("AI"))
             );
+            let content = result
+                .evidence
+                .iter()
+                .find(|e| e.keyword == "bitcoin" && e.location == MatchLocation::Content)
+                .unwrap();
+            assert_eq!(content.count, 3);
         }
+    }
+
+    #[test]
+    fn test_strong_runner_up_becomes_secondary_domain() {
+        let strategy = KeywordBasedStrategy::new();
+        let result = strategy
+            .classify(&news(
+                "AI trading bot on Ethereum built with ChatGPT",
+                "https://example.com/a",
+                None,
+            ))
+            .unwrap();
 
-        None
+        assert_eq!(result.domain, Domain::new("AI"));
+        assert_eq!(result.secondary_domains.len(), 1);
+        let secondary = result.secondary_domains[0];
+        assert_eq!(secondary.domain, Domain::new("Block"));
+        assert!(secondary.confidence > 0.3 && secondary.confidence <= 0.9);
+        assert!(
+            result.reason.contains("secondary Block"),
+            "{}",
+            result.reason
+        );
+
+        // 单一领域或只有弱信号的次要领域不会被标注
+        let result = strategy
+            .classify(&news(
+                "OpenAI ships a viral model",
+                "https://example.com/a",
+                None,
+            ))
+            .unwrap();
+        assert!(result.secondary_domains.is_empty());
     }
 
-    fn name(&self) -> &str {
-        "keyword-based"
+    #[test]
+    fn test_weak_only_and_no_match() {
+        let strategy = KeywordBasedStrategy::new();
+        let result = strategy
+            .classify(&news(
+                "A viral moment",
+                "https://example.com/ai-detail",
+                None,
+            ))
+            .unwrap();
+        assert_eq!(result.domain, Domain::new("Social"));
+        assert_eq!(result.confidence, 0.4);
+        // 弱关键词不在 URL 中计分
+        assert!(
+            result
+                .evidence
+                .iter()
+                .all(|e| e.location != MatchLocation::Url)
+        );
+
+        assert!(
+            strategy
+                .classify(&news("Local bakery opens", "https://example.com/b", None))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_negative_keywords_suppress_and_down_weight() {
+        let strategy = KeywordBasedStrategy::new();
+
+        // "eth" inside "ethernet" in the URL is not a blockchain signal
+        let item = news(
+            "Faster switches for the home lab",
+            "https://example.com/ethernet-switches",
+            None,
+        );
+        assert!(strategy.classify(&item).is_none());
+
+        // "j'ai" is French, not AI
+        let item = news(
+            "J'ai testé le nouveau vélo",
+            "https://example.com/velo",
+            None,
+        );
+        assert!(strategy.classify(&item).is_none());
+
+        // A negative hit elsewhere halves the domain's score and confidence,
+        // leaving the decision to later stages
+        let item = news(
+            "Meta changes its metadata policy",
+            "https://example.com/a",
+            None,
+        );
+        let result = strategy.classify(&item).unwrap();
+        assert_eq!(result.domain, Domain::new("Social"));
+        assert!(result.reason.contains("score 0.50"), "{}", result.reason);
+        // Below the default accept_threshold of 0.7
+        assert!(result.confidence < 0.7, "{}", result.confidence);
+        assert!(
+            result
+                .evidence
+                .iter()
+                .any(|e| e.keyword == "metadata" && e.strength == KeywordStrength::Negative)
+        );
     }
 }
@@ -0,0 +1,206 @@
+//! # Keyword Matcher
+//!
+//! Finds every configured keyword in a text with a single Aho-Corasick pass,
+//! instead of scanning the text once per keyword.
+
+use crate::domain::Domain;
+use crate::domain::config::ClassificationConfig;
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, MatchKind};
+use std::collections::HashMap;
+
+/// Whether a keyword is a strong or a weak signal, or a signal against the domain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeywordStrength {
+    Strong,
+    Weak,
+    Negative,
+}
+
+/// A domain a keyword counts towards, and how strongly
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeywordTarget {
+    pub domain: Domain,
+    pub strength: KeywordStrength,
+}
+
+/// One occurrence of a keyword in the scanned text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeywordHit<'a> {
+    /// The keyword as configured (lowercase)
+    pub keyword: &'a str,
+    /// Every domain the keyword is configured for
+    pub targets: &'a [KeywordTarget],
+    /// Byte offsets of the match in the lowercased text
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Aho-Corasick automaton over all strong, weak and negative keywords of a config snapshot
+///
+/// Matching is case-insensitive: keywords and text are lowercased once.
+/// Build it once per configuration and share it; building is the expensive part.
+#[derive(Debug, Clone)]
+pub struct KeywordMatcher {
+    automaton: AhoCorasick,
+    keywords: Vec<String>,
+    targets: Vec<Vec<KeywordTarget>>,
+    domains: Vec<Domain>,
+}
+
+impl KeywordMatcher {
+    /// Build the automaton for every keyword in `config`
+    pub fn from_config(config: &ClassificationConfig) -> Self {
+        let mut index: HashMap<String, usize> = HashMap::new();
+        let mut keywords: Vec<String> = Vec::new();
+        let mut targets: Vec<Vec<KeywordTarget>> = Vec::new();
+
+        for definition in &config.domains {
+            let lists = [
+                (&definition.strong_keywords, KeywordStrength::Strong),
+                (&definition.weak_keywords, KeywordStrength::Weak),
+                (&definition.negative_keywords, KeywordStrength::Negative),
+            ];
+            for (words, strength) in lists {
+                for word in words {
+                    let word = word.trim().to_lowercase();
+                    if word.is_empty() {
+                        continue;
+                    }
+                    let id = *index.entry(word.clone()).or_insert_with(|| {
+                        keywords.push(word);
+                        targets.push(Vec::new());
+                        keywords.len() - 1
+                    });
+                    let target = KeywordTarget {
+                        domain: definition.name,
+                        strength,
+                    };
+                    if !targets[id].contains(&target) {
+                        targets[id].push(target);
+                    }
+                }
+            }
+        }
+
+        let automaton = AhoCorasickBuilder::new()
+            .match_kind(MatchKind::Standard)
+            .build(&keywords)
+            .expect("keyword automaton should build");
+
+        Self {
+            automaton,
+            keywords,
+            targets,
+            domains: config.domain_names(),
+        }
+    }
+
+    /// The configured domains, in config order
+    pub fn domains(&self) -> &[Domain] {
+        &self.domains
+    }
+
+    /// All keyword occurrences that stand as whole words in `text`
+    ///
+    /// `text` must already be lowercased (see [`KeywordMatcher::normalize`]).
+    pub fn find_words<'a>(&'a self, text: &str) -> Vec<KeywordHit<'a>> {
+        self.find(text)
+            .filter(|hit| is_word_boundary(text, hit.start, hit.end))
+            .collect()
+    }
+
+    /// All keyword occurrences in `text`, ignoring word boundaries (used for URLs)
+    ///
+    /// `text` must already be lowercased (see [`KeywordMatcher::normalize`]).
+    pub fn find_substrings<'a>(&'a self, text: &str) -> Vec<KeywordHit<'a>> {
+        self.find(text).collect()
+    }
+
+    /// Lowercase text once before matching
+    pub fn normalize(text: &str) -> String {
+        text.to_lowercase()
+    }
+
+    fn find<'a, 't>(&'a self, text: &'t str) -> impl Iterator<Item = KeywordHit<'a>> + 't
+    where
+        'a: 't,
+    {
+        self.automaton.find_overlapping_iter(text).map(move |m| {
+            let id = m.pattern().as_usize();
+            KeywordHit {
+                keyword: &self.keywords[id],
+                targets: &self.targets[id],
+                start: m.start(),
+                end: m.end(),
+            }
+        })
+    }
+}
+
+/// Whether `text[start..end]` is not glued to letters or digits on either side
+fn is_word_boundary(text: &str, start: usize, end: usize) -> bool {
+    let before = text[..start].chars().next_back();
+    let after = text[end..].chars().next();
+    !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matcher() -> KeywordMatcher {
+        let mut config = ClassificationConfig::empty();
+        config.add_strong_keyword(Domain::new("AI"), "GPT-4".to_string());
+        config.add_strong_keyword(Domain::new("AI"), "gpt".to_string());
+        config.add_weak_keyword(Domain::new("AI"), "ai".to_string());
+        config.add_weak_keyword(Domain::new("Block"), "token".to_string());
+        config.add_weak_keyword(Domain::new("AI"), "token".to_string());
+        KeywordMatcher::from_config(&config)
+    }
+
+    #[test]
+    fn test_finds_all_hits_in_one_pass() {
+        let matcher = matcher();
+        assert_eq!(matcher.keywords.len(), 4);
+
+        let text = KeywordMatcher::normalize("GPT-4 and AI: a token story");
+        let hits: Vec<&str> = matcher
+            .find_words(&text)
+            .iter()
+            .map(|h| h.keyword)
+            .collect();
+        assert_eq!(hits, vec!["gpt", "gpt-4", "ai", "token"]);
+
+        let token = matcher
+            .find_words(&text)
+            .into_iter()
+            .find(|h| h.keyword == "token")
+            .unwrap();
+        assert_eq!(token.targets.len(), 2);
+    }
+
+    #[test]
+    fn test_word_boundaries() {
+        let matcher = matcher();
+        let text = KeywordMatcher::normalize("Said the tokenizer, not detail; ai-powered");
+        let hits: Vec<&str> = matcher
+            .find_words(&text)
+            .iter()
+            .map(|h| h.keyword)
+            .collect();
+        assert_eq!(hits, vec!["ai"]);
+
+        // Non-ASCII letters are part of a word too
+        assert!(matcher.find_words("éai").is_empty());
+
+        // URLs are matched without word boundaries
+        let url = KeywordMatcher::normalize("https://example.com/openai-gpt4-tokens");
+        let hits: Vec<&str> = matcher
+            .find_substrings(&url)
+            .iter()
+            .map(|h| h.keyword)
+            .collect();
+        assert!(hits.contains(&"gpt"));
+        assert!(hits.contains(&"token"));
+    }
+}
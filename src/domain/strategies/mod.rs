@@ -4,9 +4,13 @@
 //! Each strategy implements the `ClassificationStrategy` trait.
 
 pub mod classification_strategy;
+pub mod exclusion_rules;
 pub mod keyword_based_strategy;
+pub mod keyword_matcher;
 pub mod source_based_strategy;
 
-pub use classification_strategy::{ClassificationResult, ClassificationStrategy};
+pub use classification_strategy::{
+    ClassificationResult, ClassificationStrategy, MatchEvidence, MatchLocation,
+};
 
 pub use keyword_based_strategy::KeywordBasedStrategy;
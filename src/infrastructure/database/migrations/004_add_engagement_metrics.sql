@@ -0,0 +1,4 @@
+-- 添加热度指标列（分数、评论数、讨论页链接）
+ALTER TABLE news_items ADD COLUMN score INTEGER;
+ALTER TABLE news_items ADD COLUMN comment_count INTEGER;
+ALTER TABLE news_items ADD COLUMN discussion_url TEXT;
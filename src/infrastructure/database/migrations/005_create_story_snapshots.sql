@@ -0,0 +1,13 @@
+-- 热度快照表：已存储的新闻每次被重新抓取时记录一行
+CREATE TABLE IF NOT EXISTS story_snapshots (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    news_id TEXT NOT NULL REFERENCES news_items(id) ON DELETE CASCADE,
+    captured_at TEXT NOT NULL,
+    score INTEGER,
+    comment_count INTEGER,
+    rank INTEGER
+);
+
+-- 索引：按新闻查询快照历史、按时间窗口计算增速
+CREATE INDEX IF NOT EXISTS idx_story_snapshots_news_id ON story_snapshots(news_id, captured_at);
+CREATE INDEX IF NOT EXISTS idx_story_snapshots_captured_at ON story_snapshots(captured_at);
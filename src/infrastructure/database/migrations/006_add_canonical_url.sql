@@ -0,0 +1,3 @@
+-- 添加规范化 URL 列（回填与去重由 migrator.rs 中迁移 006 的 Rust 步骤完成，
+-- 完成后再创建唯一索引）
+ALTER TABLE news_items ADD COLUMN canonical_url TEXT;
@@ -0,0 +1,4 @@
+-- 添加新闻聚类 ID 列（同一事件的多篇报道共享同一 ID）
+ALTER TABLE news_items ADD COLUMN cluster_id TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_news_items_cluster_id ON news_items(cluster_id);
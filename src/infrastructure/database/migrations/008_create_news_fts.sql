@@ -0,0 +1,30 @@
+-- 全文检索表：镜像 news_items 的标题、正文和分类依据
+-- news_items 的主键是 TEXT，隐式 rowid 在 VACUUM 后可能变化，因此按 news_id 关联而非外部内容表
+CREATE VIRTUAL TABLE IF NOT EXISTS news_fts USING fts5(
+    news_id UNINDEXED,
+    title,
+    content,
+    classification_reason,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+-- 回填已有新闻
+INSERT INTO news_fts (news_id, title, content, classification_reason)
+SELECT id, title, COALESCE(content, ''), COALESCE(classification_reason, '') FROM news_items;
+
+-- 触发器：保持与 news_items 同步
+CREATE TRIGGER IF NOT EXISTS news_items_fts_insert AFTER INSERT ON news_items BEGIN
+    INSERT INTO news_fts (news_id, title, content, classification_reason)
+    VALUES (new.id, new.title, COALESCE(new.content, ''), COALESCE(new.classification_reason, ''));
+END;
+
+CREATE TRIGGER IF NOT EXISTS news_items_fts_delete AFTER DELETE ON news_items BEGIN
+    DELETE FROM news_fts WHERE news_id = old.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS news_items_fts_update
+AFTER UPDATE OF title, content, classification_reason ON news_items BEGIN
+    DELETE FROM news_fts WHERE news_id = old.id;
+    INSERT INTO news_fts (news_id, title, content, classification_reason)
+    VALUES (new.id, new.title, COALESCE(new.content, ''), COALESCE(new.classification_reason, ''));
+END;
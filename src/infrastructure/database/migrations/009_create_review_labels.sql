@@ -0,0 +1,11 @@
+-- 人工审核标签：审核者对新闻领域的判定，作为评估分类器的标准答案
+CREATE TABLE IF NOT EXISTS review_labels (
+    news_id TEXT PRIMARY KEY REFERENCES news_items(id) ON DELETE CASCADE,
+    decision TEXT NOT NULL,
+    domain TEXT,
+    predicted_domain TEXT,
+    predicted_confidence REAL,
+    reviewed_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_review_labels_reviewed_at ON review_labels(reviewed_at);
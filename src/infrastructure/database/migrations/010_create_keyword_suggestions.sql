@@ -0,0 +1,22 @@
+-- AI 建议关键词收件箱：每个（关键词, 领域）一条记录，人工审核后才写入分类配置
+CREATE TABLE IF NOT EXISTS keyword_suggestions (
+    keyword TEXT NOT NULL,
+    domain TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'Pending',
+    occurrences INTEGER NOT NULL DEFAULT 0,
+    first_seen_at TEXT NOT NULL,
+    last_seen_at TEXT NOT NULL,
+    PRIMARY KEY (keyword, domain)
+);
+
+CREATE INDEX IF NOT EXISTS idx_keyword_suggestions_status ON keyword_suggestions(status, occurrences);
+
+-- 产生建议的新闻（同一新闻对同一关键词只计一次）
+CREATE TABLE IF NOT EXISTS keyword_suggestion_sources (
+    keyword TEXT NOT NULL,
+    domain TEXT NOT NULL,
+    news_id TEXT NOT NULL,
+    suggested_at TEXT NOT NULL,
+    PRIMARY KEY (keyword, domain, news_id),
+    FOREIGN KEY (keyword, domain) REFERENCES keyword_suggestions(keyword, domain) ON DELETE CASCADE
+);
@@ -0,0 +1,2 @@
+-- 添加分类轨迹列（JSON，记录流水线每个阶段的结果、置信度、耗时和错误）
+ALTER TABLE news_items ADD COLUMN classification_trace TEXT;
@@ -0,0 +1,16 @@
+-- 新闻与领域的多对多关系：主领域（is_primary = 1，与 news_items.domain 一致）和次要领域
+CREATE TABLE IF NOT EXISTS news_domains (
+    news_id TEXT NOT NULL REFERENCES news_items(id) ON DELETE CASCADE,
+    domain TEXT NOT NULL,
+    confidence REAL,
+    is_primary INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (news_id, domain)
+);
+
+CREATE INDEX IF NOT EXISTS idx_news_domains_domain ON news_domains(domain);
+
+-- 已有新闻的主领域
+INSERT OR IGNORE INTO news_domains (news_id, domain, confidence, is_primary)
+SELECT id, domain, classification_confidence, 1
+FROM news_items
+WHERE domain IS NOT NULL;
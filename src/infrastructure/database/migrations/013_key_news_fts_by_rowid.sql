@@ -0,0 +1,53 @@
+-- 全文检索表改为按 rowid 关联新闻
+-- 008 的触发器按 UNINDEXED 的 news_id 删除，每次更新都要扫描整个 news_fts；
+-- 这里为每条新闻分配固定的整数 ID（显式 INTEGER PRIMARY KEY，VACUUM 后不变），作为 news_fts 的 rowid
+DROP TRIGGER IF EXISTS news_items_fts_insert;
+DROP TRIGGER IF EXISTS news_items_fts_delete;
+DROP TRIGGER IF EXISTS news_items_fts_update;
+DROP TABLE IF EXISTS news_fts;
+
+CREATE TABLE IF NOT EXISTS news_fts_rowids (
+    fts_rowid INTEGER PRIMARY KEY,
+    news_id TEXT NOT NULL UNIQUE
+);
+
+CREATE VIRTUAL TABLE news_fts USING fts5(
+    news_id UNINDEXED,
+    title,
+    content,
+    classification_reason,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+-- 回填已有新闻
+INSERT INTO news_fts_rowids (news_id) SELECT id FROM news_items;
+
+INSERT INTO news_fts (rowid, news_id, title, content, classification_reason)
+SELECT m.fts_rowid, n.id, n.title, COALESCE(n.content, ''), COALESCE(n.classification_reason, '')
+FROM news_items n
+JOIN news_fts_rowids m ON m.news_id = n.id;
+
+-- 触发器：保持与 news_items 同步，全部按 rowid 定位
+CREATE TRIGGER news_items_fts_insert AFTER INSERT ON news_items BEGIN
+    INSERT OR IGNORE INTO news_fts_rowids (news_id) VALUES (new.id);
+    INSERT INTO news_fts (rowid, news_id, title, content, classification_reason)
+    VALUES (
+        (SELECT fts_rowid FROM news_fts_rowids WHERE news_id = new.id),
+        new.id, new.title, COALESCE(new.content, ''), COALESCE(new.classification_reason, '')
+    );
+END;
+
+CREATE TRIGGER news_items_fts_delete AFTER DELETE ON news_items BEGIN
+    DELETE FROM news_fts
+    WHERE rowid = (SELECT fts_rowid FROM news_fts_rowids WHERE news_id = old.id);
+    DELETE FROM news_fts_rowids WHERE news_id = old.id;
+END;
+
+CREATE TRIGGER news_items_fts_update
+AFTER UPDATE OF title, content, classification_reason ON news_items BEGIN
+    UPDATE news_fts
+    SET title = new.title,
+        content = COALESCE(new.content, ''),
+        classification_reason = COALESCE(new.classification_reason, '')
+    WHERE rowid = (SELECT fts_rowid FROM news_fts_rowids WHERE news_id = new.id);
+END;
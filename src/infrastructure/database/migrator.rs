@@ -0,0 +1,395 @@
+use crate::domain::UrlCanonicalizer;
+use futures::future::BoxFuture;
+use sqlx::{SqliteConnection, SqlitePool};
+use std::collections::HashMap;
+use tracing::info;
+
+type MigrationResult = Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+/// 在 SQL 之后执行的 Rust 迁移步骤（与 SQL 在同一事务中）
+type RustStep = for<'c> fn(&'c mut SqliteConnection) -> BoxFuture<'c, MigrationResult>;
+
+/// 一个编号迁移
+struct Migration {
+    version: i64,
+    name: &'static str,
+    sql: &'static str,
+    post: Option<RustStep>,
+}
+
+/// 所有迁移，按版本号递增排列
+///
+/// 新增迁移只能追加到末尾，已发布的迁移不能修改或重新编号。
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "initial",
+        sql: include_str!("migrations/001_initial.sql"),
+        post: None,
+    },
+    Migration {
+        version: 2,
+        name: "add_content_and_status",
+        sql: include_str!("migrations/002_add_content_and_status.sql"),
+        post: None,
+    },
+    Migration {
+        version: 3,
+        name: "add_classification_reason",
+        sql: include_str!("migrations/003_add_classification_reason.sql"),
+        post: None,
+    },
+    Migration {
+        version: 4,
+        name: "add_engagement_metrics",
+        sql: include_str!("migrations/004_add_engagement_metrics.sql"),
+        post: None,
+    },
+    Migration {
+        version: 5,
+        name: "create_story_snapshots",
+        sql: include_str!("migrations/005_create_story_snapshots.sql"),
+        post: None,
+    },
+    Migration {
+        version: 6,
+        name: "add_canonical_url",
+        sql: include_str!("migrations/006_add_canonical_url.sql"),
+        post: Some(backfill_canonical_urls),
+    },
+    Migration {
+        version: 7,
+        name: "add_cluster_id",
+        sql: include_str!("migrations/007_add_cluster_id.sql"),
+        post: None,
+    },
+    Migration {
+        version: 8,
+        name: "create_news_fts",
+        sql: include_str!("migrations/008_create_news_fts.sql"),
+        post: None,
+    },
+    Migration {
+        version: 9,
+        name: "create_review_labels",
+        sql: include_str!("migrations/009_create_review_labels.sql"),
+        post: None,
+    },
+    Migration {
+        version: 10,
+        name: "create_keyword_suggestions",
+        sql: include_str!("migrations/010_create_keyword_suggestions.sql"),
+        post: None,
+    },
+    Migration {
+        version: 11,
+        name: "add_classification_trace",
+        sql: include_str!("migrations/011_add_classification_trace.sql"),
+        post: None,
+    },
+    Migration {
+        version: 12,
+        name: "create_news_domains",
+        sql: include_str!("migrations/012_create_news_domains.sql"),
+        post: None,
+    },
+    Migration {
+        version: 13,
+        name: "key_news_fts_by_rowid",
+        sql: include_str!("migrations/013_key_news_fts_by_rowid.sql"),
+        post: None,
+    },
+];
+
+/// 运行数据库迁移
+///
+/// - 已应用的迁移记录在 `schema_migrations` 表中，只执行尚未应用的迁移
+/// - 每个迁移（SQL 和 Rust 步骤）及其版本记录在同一个事务中提交，失败时整体回滚
+/// - 数据库版本高于程序已知的最新版本时拒绝运行，避免旧程序写坏新 schema
+pub async fn run_migrations(pool: &SqlitePool) -> MigrationResult {
+    let mut conn = pool.acquire().await?;
+
+    if !table_exists(&mut conn, "schema_migrations").await? {
+        let legacy_versions = detect_legacy_versions(&mut conn).await?;
+        let mut tx = pool.begin().await?;
+        sqlx::query(
+            r#"
+            CREATE TABLE schema_migrations (
+                version INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                applied_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )
+            "#,
+        )
+        .execute(&mut *tx)
+        .await?;
+        for migration in MIGRATIONS
+            .iter()
+            .filter(|m| legacy_versions.contains(&m.version))
+        {
+            record_applied(&mut tx, migration).await?;
+        }
+        tx.commit().await?;
+
+        if !legacy_versions.is_empty() {
+            info!(
+                "🗄️  识别到旧版数据库，已记录迁移版本: {:?}",
+                legacy_versions
+            );
+        }
+    }
+
+    let applied: Vec<i64> = sqlx::query_scalar("SELECT version FROM schema_migrations")
+        .fetch_all(&mut *conn)
+        .await?;
+    drop(conn);
+
+    let latest = MIGRATIONS.last().map_or(0, |m| m.version);
+    if let Some(&newest) = applied.iter().max()
+        && newest > latest
+    {
+        return Err(format!(
+            "数据库 schema 版本为 {}，高于程序支持的最新版本 {}，请升级 trendarc",
+            newest, latest
+        )
+        .into());
+    }
+
+    for migration in MIGRATIONS.iter().filter(|m| !applied.contains(&m.version)) {
+        info!("🗄️  执行迁移 {:03}_{}", migration.version, migration.name);
+        let mut tx = pool.begin().await?;
+        sqlx::query(migration.sql).execute(&mut *tx).await?;
+        if let Some(post) = migration.post {
+            post(&mut tx).await?;
+        }
+        record_applied(&mut tx, migration).await?;
+        tx.commit().await?;
+    }
+
+    Ok(())
+}
+
+async fn record_applied(conn: &mut SqliteConnection, migration: &Migration) -> MigrationResult {
+    sqlx::query("INSERT INTO schema_migrations (version, name) VALUES (?1, ?2)")
+        .bind(migration.version)
+        .bind(migration.name)
+        .execute(conn)
+        .await?;
+    Ok(())
+}
+
+async fn table_exists(
+    conn: &mut SqliteConnection,
+    table: &str,
+) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+    let exists = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type='table' AND name=?1)",
+    )
+    .bind(table)
+    .fetch_one(conn)
+    .await?;
+    Ok(exists)
+}
+
+/// 识别引入 `schema_migrations` 之前创建的数据库已经应用了哪些迁移
+///
+/// 旧版本按表和列是否存在决定是否执行迁移，这里沿用同样的判断，只在首次升级时执行一次。
+async fn detect_legacy_versions(
+    conn: &mut SqliteConnection,
+) -> Result<Vec<i64>, Box<dyn std::error::Error + Send + Sync>> {
+    if !table_exists(conn, "news_items").await? {
+        return Ok(Vec::new());
+    }
+
+    let columns: Vec<String> =
+        sqlx::query_scalar("SELECT name FROM pragma_table_info('news_items')")
+            .fetch_all(&mut *conn)
+            .await?;
+    let has_column = |name: &str| columns.iter().any(|c| c == name);
+
+    let mut versions = vec![1];
+    if has_column("content") {
+        versions.push(2);
+    }
+    if has_column("classification_reason") {
+        versions.push(3);
+    }
+    if has_column("score") {
+        versions.push(4);
+    }
+    if table_exists(conn, "story_snapshots").await? {
+        versions.push(5);
+    }
+    if has_column("canonical_url") {
+        versions.push(6);
+    }
+    if has_column("cluster_id") {
+        versions.push(7);
+    }
+    if table_exists(conn, "news_fts").await? {
+        versions.push(8);
+    }
+    Ok(versions)
+}
+
+/// 迁移 006 的 Rust 步骤：回填 canonical_url 并合并重复新闻
+///
+/// 规范化后相同的多条新闻只保留最早入库的一条，其余新闻的快照迁移到保留的新闻上，
+/// 最后创建唯一索引。
+fn backfill_canonical_urls(conn: &mut SqliteConnection) -> BoxFuture<'_, MigrationResult> {
+    Box::pin(async move {
+        let rows: Vec<(String, String)> =
+            sqlx::query_as("SELECT id, url FROM news_items ORDER BY created_at ASC, rowid ASC")
+                .fetch_all(&mut *conn)
+                .await?;
+
+        let mut survivors: HashMap<String, String> = HashMap::new();
+        for (id, url) in rows {
+            let canonical_url = UrlCanonicalizer::canonicalize(&url);
+
+            match survivors.get(&canonical_url) {
+                Some(survivor_id) => {
+                    sqlx::query("UPDATE story_snapshots SET news_id = ?1 WHERE news_id = ?2")
+                        .bind(survivor_id)
+                        .bind(&id)
+                        .execute(&mut *conn)
+                        .await?;
+                    sqlx::query("DELETE FROM news_items WHERE id = ?1")
+                        .bind(&id)
+                        .execute(&mut *conn)
+                        .await?;
+                }
+                None => {
+                    sqlx::query("UPDATE news_items SET canonical_url = ?1 WHERE id = ?2")
+                        .bind(&canonical_url)
+                        .bind(&id)
+                        .execute(&mut *conn)
+                        .await?;
+                    survivors.insert(canonical_url, id);
+                }
+            }
+        }
+
+        sqlx::query(
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_news_items_canonical_url ON news_items(canonical_url)",
+        )
+        .execute(&mut *conn)
+        .await?;
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn applied_versions(pool: &SqlitePool) -> Vec<i64> {
+        sqlx::query_scalar("SELECT version FROM schema_migrations ORDER BY version")
+            .fetch_all(pool)
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn test_migration_versions_are_strictly_increasing() {
+        for pair in MIGRATIONS.windows(2) {
+            assert!(pair[0].version < pair[1].version, "{}", pair[1].name);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fresh_database_applies_all_migrations_once() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+
+        run_migrations(&pool).await.unwrap();
+        run_migrations(&pool).await.unwrap();
+
+        let expected: Vec<i64> = MIGRATIONS.iter().map(|m| m.version).collect();
+        assert_eq!(applied_versions(&pool).await, expected);
+    }
+
+    #[tokio::test]
+    async fn test_legacy_database_is_baselined() {
+        // 模拟引入 schema_migrations 之前、已执行到迁移 004 的数据库
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        for migration in &MIGRATIONS[..4] {
+            sqlx::query(migration.sql).execute(&pool).await.unwrap();
+        }
+
+        run_migrations(&pool).await.unwrap();
+
+        let expected: Vec<i64> = MIGRATIONS.iter().map(|m| m.version).collect();
+        assert_eq!(applied_versions(&pool).await, expected);
+        let snapshots_exist = {
+            let mut conn = pool.acquire().await.unwrap();
+            table_exists(&mut conn, "story_snapshots").await.unwrap()
+        };
+        assert!(snapshots_exist);
+    }
+
+    #[tokio::test]
+    async fn test_refuses_newer_schema() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+        sqlx::query(
+            "INSERT INTO schema_migrations (version, name) VALUES (9999, 'from_the_future')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let err = run_migrations(&pool).await.unwrap_err();
+        assert!(err.to_string().contains("9999"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_news_fts_rows_are_keyed_by_rowid() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        run_migrations(&pool).await.unwrap();
+        for (id, title) in [("a", "Rust 2024 edition"), ("b", "Bitcoin ETF inflows")] {
+            sqlx::query(
+                "INSERT INTO news_items (id, title, url, source, author, published_at) \
+                 VALUES (?1, ?2, ?1, 'test', 'author', '2024-01-01T00:00:00+00:00')",
+            )
+            .bind(id)
+            .bind(title)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        sqlx::query("UPDATE news_items SET title = 'Go 1.23 released' WHERE id = 'a'")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let rows: Vec<(i64, String, String)> = sqlx::query_as(
+            "SELECT f.rowid, f.news_id, f.title FROM news_fts f \
+             JOIN news_fts_rowids m ON m.fts_rowid = f.rowid ORDER BY f.news_id",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        let titles: Vec<(&str, &str)> = rows
+            .iter()
+            .map(|(_, id, title)| (id.as_str(), title.as_str()))
+            .collect();
+        assert_eq!(
+            titles,
+            vec![("a", "Go 1.23 released"), ("b", "Bitcoin ETF inflows")]
+        );
+
+        sqlx::query("DELETE FROM news_items WHERE id = 'b'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM news_fts")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let mapped: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM news_fts_rowids")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!((remaining, mapped), (1, 1));
+    }
+}
@@ -1,3 +1,6 @@
+mod migrator;
+
+use migrator::run_migrations;
 use sqlx::{SqlitePool, sqlite::SqliteConnectOptions};
 use std::path::Path;
 
@@ -46,43 +49,6 @@ pub async fn create_pool(
     Ok(pool)
 }
 
-/// 运行数据库迁移
-async fn run_migrations(pool: &SqlitePool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    // 检查表是否存在
-    let table_exists: bool = sqlx::query_scalar(
-        "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type='table' AND name='news_items')",
-    )
-    .fetch_one(pool)
-    .await?;
-
-    if !table_exists {
-        // 执行迁移脚本 001
-        let migration_001 = include_str!("migrations/001_initial.sql");
-        sqlx::query(migration_001).execute(pool).await?;
-    }
-
-    // 检查 content 列是否存在
-    let columns: Vec<(String,)> =
-        sqlx::query_as("SELECT name FROM pragma_table_info('news_items')")
-            .fetch_all(pool)
-            .await?;
-    let column_names: Vec<String> = columns.into_iter().map(|(n,)| n).collect();
-
-    if !column_names.contains(&"content".to_string()) {
-        // 执行迁移脚本 002
-        let migration_002 = include_str!("migrations/002_add_content_and_status.sql");
-        sqlx::query(migration_002).execute(pool).await?;
-    }
-
-    if !column_names.contains(&"classification_reason".to_string()) {
-        // 执行迁移脚本 003
-        let migration_003 = include_str!("migrations/003_add_classification_reason.sql");
-        sqlx::query(migration_003).execute(pool).await?;
-    }
-
-    Ok(())
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -123,5 +89,99 @@ mod tests {
         let column_names: Vec<String> = columns.into_iter().map(|(n,)| n).collect();
         assert!(column_names.contains(&"content".to_string()));
         assert!(column_names.contains(&"status".to_string()));
+        assert!(column_names.contains(&"score".to_string()));
+        assert!(column_names.contains(&"comment_count".to_string()));
+        assert!(column_names.contains(&"discussion_url".to_string()));
+
+        // 验证快照表存在
+        let snapshots_exist: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type='table' AND name='story_snapshots')",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert!(snapshots_exist);
+        assert!(column_names.contains(&"canonical_url".to_string()));
+        assert!(column_names.contains(&"cluster_id".to_string()));
+
+        // 验证全文检索表存在
+        let fts_exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type='table' AND name='news_fts')",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert!(fts_exists);
+    }
+
+    #[tokio::test]
+    async fn test_canonical_url_backfill_merges_duplicates() {
+        // 模拟迁移 006 之前的数据库
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        for migration in [
+            include_str!("migrations/001_initial.sql"),
+            include_str!("migrations/002_add_content_and_status.sql"),
+            include_str!("migrations/003_add_classification_reason.sql"),
+            include_str!("migrations/004_add_engagement_metrics.sql"),
+            include_str!("migrations/005_create_story_snapshots.sql"),
+        ] {
+            sqlx::query(migration).execute(&pool).await.unwrap();
+        }
+
+        for (id, url, created_at) in [
+            ("old", "http://www.example.com/post/", "2025-01-01 00:00:00"),
+            (
+                "new",
+                "https://example.com/post?utm_source=hn",
+                "2025-01-02 00:00:00",
+            ),
+            ("other", "https://example.com/other", "2025-01-03 00:00:00"),
+        ] {
+            sqlx::query(
+                "INSERT INTO news_items (id, title, url, source, author, published_at, created_at) VALUES (?1, 'T', ?2, 's', 'a', '2025-01-01T00:00:00+00:00', ?3)",
+            )
+            .bind(id)
+            .bind(url)
+            .bind(created_at)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        sqlx::query(
+            "INSERT INTO story_snapshots (news_id, captured_at, score) VALUES ('new', '2025-01-02T00:00:00+00:00', 10)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        run_migrations(&pool).await.unwrap();
+
+        let rows: Vec<(String, String, String)> =
+            sqlx::query_as("SELECT id, url, canonical_url FROM news_items ORDER BY id")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                (
+                    "old".to_string(),
+                    "http://www.example.com/post/".to_string(),
+                    "https://example.com/post".to_string()
+                ),
+                (
+                    "other".to_string(),
+                    "https://example.com/other".to_string(),
+                    "https://example.com/other".to_string()
+                ),
+            ]
+        );
+
+        // 被合并新闻的快照迁移到保留的新闻上
+        let snapshot_owner: String = sqlx::query_scalar("SELECT news_id FROM story_snapshots")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(snapshot_owner, "old");
     }
 }
@@ -0,0 +1,444 @@
+use crate::domain::config::ClassificationConfig;
+use crate::domain::{Domain, NewsCursor, NewsItem, NewsItemStatus, NewsQuery, NewsRepository};
+use axum::extract::rejection::QueryRejection;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tracing::{error, info};
+
+/// 单次请求允许返回的最大条数
+const MAX_LIMIT: usize = 200;
+
+/// 只读 HTTP JSON API
+///
+/// **端点**：
+/// - `GET /health`：健康检查
+/// - `GET /api/news`：最近的新闻，支持 `limit`、`offset`、`cursor`、`order`、
+///   `domain` / `source` / `status`（逗号分隔）、`author`、`min_confidence`、
+///   `max_confidence`、`q`（标题或正文包含）、`since`、`until`（RFC3339）
+/// - `GET /api/news/{id}`：单条新闻
+/// - `GET /api/domains/{domain}/news`：某个领域的新闻，支持同样的查询参数
+/// - `GET /api/stats`：新闻总数与各领域数量
+///
+/// 领域名称不区分大小写，只接受 `config` 中配置的领域。
+pub fn router(repository: Arc<dyn NewsRepository>, config: ClassificationConfig) -> Router {
+    let state = ApiState {
+        repository,
+        config: Arc::new(config),
+    };
+    Router::new()
+        .route("/health", get(health))
+        .route("/api/news", get(list_news))
+        .route("/api/news/:id", get(get_news))
+        .route("/api/domains/:domain/news", get(list_domain_news))
+        .route("/api/stats", get(stats))
+        .with_state(state)
+}
+
+/// 各端点共享的状态
+#[derive(Clone)]
+struct ApiState {
+    repository: Arc<dyn NewsRepository>,
+    /// 分类配置（用于解析领域名称）
+    config: Arc<ClassificationConfig>,
+}
+
+/// 在 `addr` 上启动 API，`shutdown` 完成后停止接收新连接并退出
+pub async fn serve(
+    addr: SocketAddr,
+    repository: Arc<dyn NewsRepository>,
+    config: ClassificationConfig,
+    shutdown: impl Future<Output = ()> + Send + 'static,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    info!("🌐 HTTP API 监听于 http://{}", listener.local_addr()?);
+
+    axum::serve(listener, router(repository, config))
+        .with_graceful_shutdown(shutdown)
+        .await?;
+    Ok(())
+}
+
+/// 新闻列表的查询参数
+#[derive(Debug, Default, Deserialize)]
+struct NewsParams {
+    limit: Option<usize>,
+    offset: Option<usize>,
+    cursor: Option<String>,
+    order: Option<String>,
+    domain: Option<String>,
+    source: Option<String>,
+    status: Option<String>,
+    author: Option<String>,
+    min_confidence: Option<f32>,
+    max_confidence: Option<f32>,
+    q: Option<String>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+}
+
+impl NewsParams {
+    fn into_query(self, config: &ClassificationConfig) -> Result<NewsQuery, ApiError> {
+        let limit = self
+            .limit
+            .unwrap_or(NewsQuery::DEFAULT_LIMIT)
+            .min(MAX_LIMIT);
+        let mut query = NewsQuery::new(limit)
+            .with_offset(self.offset.unwrap_or(0))
+            .published_between(self.since, self.until);
+
+        if let Some(ref order) = self.order {
+            query = query.with_order(order.parse().map_err(ApiError::BadRequest)?);
+        }
+        if let Some(ref cursor) = self.cursor {
+            if !query.order.is_by_published_at() {
+                return Err(ApiError::BadRequest(
+                    "游标分页只支持按发布时间排序".to_string(),
+                ));
+            }
+            query = query.with_cursor(cursor.parse().map_err(ApiError::BadRequest)?);
+        }
+        if let Some(ref domains) = self.domain {
+            let domains = split_list(domains)
+                .map(|name| parse_domain(config, name))
+                .collect::<Result<Vec<_>, _>>()?;
+            query = query.with_domains(domains);
+        }
+        if let Some(ref sources) = self.source {
+            query = query.with_sources(split_list(sources).map(str::to_string).collect());
+        }
+        if let Some(ref statuses) = self.status {
+            let statuses = split_list(statuses)
+                .map(parse_status)
+                .collect::<Result<Vec<_>, _>>()?;
+            query = query.with_statuses(statuses);
+        }
+        if let Some(author) = self.author {
+            query = query.with_author(author);
+        }
+        if let Some(text) = self.q {
+            query = query.containing(text);
+        }
+        Ok(query.confidence_between(self.min_confidence, self.max_confidence))
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct NewsList {
+    count: usize,
+    items: Vec<NewsItem>,
+    /// 下一页的游标（按发布时间排序且本页已满时返回）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_cursor: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Stats {
+    total: usize,
+    by_domain: Vec<DomainCount>,
+}
+
+#[derive(Debug, Serialize)]
+struct DomainCount {
+    domain: Domain,
+    count: usize,
+}
+
+async fn health() -> Json<serde_json::Value> {
+    Json(serde_json::json!({ "status": "ok" }))
+}
+
+async fn list_news(
+    State(state): State<ApiState>,
+    params: Result<Query<NewsParams>, QueryRejection>,
+) -> Result<Json<NewsList>, ApiError> {
+    let Query(params) = params?;
+    let query = params.into_query(&state.config)?;
+    find_news(state.repository.as_ref(), &query).await
+}
+
+async fn list_domain_news(
+    State(state): State<ApiState>,
+    Path(domain): Path<String>,
+    params: Result<Query<NewsParams>, QueryRejection>,
+) -> Result<Json<NewsList>, ApiError> {
+    let Query(params) = params?;
+    let domain = parse_domain(&state.config, &domain)?;
+    let query = params.into_query(&state.config)?.with_domains(vec![domain]);
+    find_news(state.repository.as_ref(), &query).await
+}
+
+async fn get_news(
+    State(state): State<ApiState>,
+    Path(id): Path<String>,
+) -> Result<Json<NewsItem>, ApiError> {
+    state
+        .repository
+        .find_by_id(&id)
+        .await
+        .map_err(ApiError::internal)?
+        .map(Json)
+        .ok_or_else(|| ApiError::NotFound(format!("新闻不存在: {}", id)))
+}
+
+async fn stats(State(state): State<ApiState>) -> Result<Json<Stats>, ApiError> {
+    let total = state.repository.count().await.map_err(ApiError::internal)?;
+    let by_domain = state
+        .repository
+        .count_by_domain()
+        .await
+        .map_err(ApiError::internal)?
+        .into_iter()
+        .map(|(domain, count)| DomainCount { domain, count })
+        .collect();
+    Ok(Json(Stats { total, by_domain }))
+}
+
+async fn find_news(
+    repository: &dyn NewsRepository,
+    query: &NewsQuery,
+) -> Result<Json<NewsList>, ApiError> {
+    let items = repository.find(query).await.map_err(ApiError::internal)?;
+    let next_cursor = match items.last() {
+        Some(last) if query.order.is_by_published_at() && items.len() == query.limit => {
+            Some(NewsCursor::after(last).to_string())
+        }
+        _ => None,
+    };
+    Ok(Json(NewsList {
+        count: items.len(),
+        items,
+        next_cursor,
+    }))
+}
+
+fn split_list(s: &str) -> impl Iterator<Item = &str> {
+    s.split(',').map(str::trim).filter(|s| !s.is_empty())
+}
+
+fn parse_status(s: &str) -> Result<NewsItemStatus, ApiError> {
+    <NewsItemStatus as clap::ValueEnum>::from_str(s, true)
+        .map_err(|_| ApiError::BadRequest(format!("未知状态: {}", s)))
+}
+
+fn parse_domain(config: &ClassificationConfig, s: &str) -> Result<Domain, ApiError> {
+    config
+        .resolve_domain(s)
+        .ok_or_else(|| ApiError::BadRequest(format!("未知领域: {}", s.trim())))
+}
+
+/// API 错误，统一以 `{"error": "..."}` 返回
+#[derive(Debug)]
+enum ApiError {
+    BadRequest(String),
+    NotFound(String),
+    Internal(String),
+}
+
+impl ApiError {
+    fn internal(e: Box<dyn std::error::Error + Send + Sync>) -> Self {
+        error!("❌ API 查询失败: {}", e);
+        ApiError::Internal("内部错误".to_string())
+    }
+}
+
+/// 查询参数无法解析（例如 `limit=abc`）时同样返回 JSON 错误
+impl From<QueryRejection> for ApiError {
+    fn from(rejection: QueryRejection) -> Self {
+        ApiError::BadRequest(rejection.body_text())
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, message) = match self {
+            ApiError::BadRequest(m) => (StatusCode::BAD_REQUEST, m),
+            ApiError::NotFound(m) => (StatusCode::NOT_FOUND, m),
+            ApiError::Internal(m) => (StatusCode::INTERNAL_SERVER_ERROR, m),
+        };
+        (status, Json(serde_json::json!({ "error": message }))).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database::create_pool;
+    use crate::infrastructure::repositories::SqliteNewsRepository;
+    use chrono::Duration;
+    use serde_json::Value;
+
+    fn news(
+        id: &str,
+        title: &str,
+        source: &str,
+        published_at: DateTime<Utc>,
+        domain: Domain,
+        confidence: f32,
+    ) -> NewsItem {
+        NewsItem::new_with_classification(
+            id.to_string(),
+            title.to_string(),
+            format!("https://example.com/{}", id),
+            source.to_string(),
+            "author".to_string(),
+            published_at,
+            domain,
+            confidence,
+        )
+    }
+
+    async fn start_server() -> String {
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        let repository: Arc<dyn NewsRepository> = Arc::new(SqliteNewsRepository::new(pool));
+
+        let now = Utc::now();
+        let items = vec![
+            news(
+                "1",
+                "GPT-5 released",
+                "hackernews",
+                now - Duration::hours(1),
+                Domain::new("AI"),
+                0.9,
+            ),
+            news(
+                "2",
+                "Bitcoin rallies",
+                "coindesk",
+                now - Duration::hours(2),
+                Domain::new("Block"),
+                0.5,
+            ),
+            news(
+                "3",
+                "Old AI news",
+                "hackernews",
+                now - Duration::days(10),
+                Domain::new("AI"),
+                0.7,
+            ),
+        ];
+        repository.save_batch(&items).await.unwrap();
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(
+                listener,
+                router(repository, ClassificationConfig::default()),
+            )
+            .await
+            .unwrap();
+        });
+        format!("http://{}", addr)
+    }
+
+    async fn get_json(url: &str) -> (u16, Value) {
+        let response = reqwest::get(url).await.unwrap();
+        let status = response.status().as_u16();
+        (status, response.json().await.unwrap())
+    }
+
+    fn ids(body: &Value) -> Vec<&str> {
+        body["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|item| item["id"].as_str().unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_news_filters_and_pagination() {
+        let base = start_server().await;
+
+        let (status, body) = get_json(&format!("{}/api/news", base)).await;
+        assert_eq!(status, 200);
+        assert_eq!(ids(&body), vec!["1", "2", "3"]);
+
+        let (_, body) = get_json(&format!("{}/api/news?limit=1&offset=1", base)).await;
+        assert_eq!(ids(&body), vec!["2"]);
+
+        let (_, body) = get_json(&format!(
+            "{}/api/news?source=hackernews&min_confidence=0.8",
+            base
+        ))
+        .await;
+        assert_eq!(ids(&body), vec!["1"]);
+
+        let since = (Utc::now() - Duration::days(1)).to_rfc3339();
+        let url = reqwest::Url::parse_with_params(
+            &format!("{}/api/news", base),
+            &[("since", since.as_str()), ("domain", "ai,block")],
+        )
+        .unwrap();
+        let (_, body) = get_json(url.as_str()).await;
+        assert_eq!(ids(&body), vec!["1", "2"]);
+
+        let (_, body) = get_json(&format!("{}/api/news?limit=2", base)).await;
+        let cursor = body["next_cursor"].as_str().unwrap().to_string();
+        let url = reqwest::Url::parse_with_params(
+            &format!("{}/api/news", base),
+            &[("limit", "2"), ("cursor", cursor.as_str())],
+        )
+        .unwrap();
+        let (_, body) = get_json(url.as_str()).await;
+        assert_eq!(ids(&body), vec!["3"]);
+        assert!(body.get("next_cursor").is_none());
+
+        let (_, body) = get_json(&format!(
+            "{}/api/news?order=confidence&status=completed&author=author&q=RALLIES",
+            base
+        ))
+        .await;
+        assert_eq!(ids(&body), vec!["2"]);
+
+        let (status, _) = get_json(&format!("{}/api/news?order=score&cursor=x", base)).await;
+        assert_eq!(status, 400);
+
+        let (_, body) = get_json(&format!("{}/api/domains/ai/news", base)).await;
+        assert_eq!(ids(&body), vec!["1", "3"]);
+        assert_eq!(body["items"][0]["domain"], "AI");
+    }
+
+    #[tokio::test]
+    async fn test_single_item_stats_and_errors() {
+        let base = start_server().await;
+
+        let (status, body) = get_json(&format!("{}/api/news/2", base)).await;
+        assert_eq!(status, 200);
+        assert_eq!(body["title"], "Bitcoin rallies");
+
+        let (status, body) = get_json(&format!("{}/api/news/missing", base)).await;
+        assert_eq!(status, 404);
+        assert!(body["error"].is_string());
+
+        let (status, _) = get_json(&format!("{}/api/domains/sports/news", base)).await;
+        assert_eq!(status, 400);
+
+        // 无法解析的查询参数也返回 JSON 错误
+        let (status, body) = get_json(&format!("{}/api/news?limit=abc", base)).await;
+        assert_eq!(status, 400);
+        assert!(body["error"].is_string());
+        let (status, body) = get_json(&format!("{}/api/domains/ai/news?offset=-1", base)).await;
+        assert_eq!(status, 400);
+        assert!(body["error"].is_string());
+
+        let (status, body) = get_json(&format!("{}/api/stats", base)).await;
+        assert_eq!(status, 200);
+        assert_eq!(body["total"], 3);
+        assert_eq!(body["by_domain"].as_array().unwrap().len(), 2);
+
+        let (status, body) = get_json(&format!("{}/health", base)).await;
+        assert_eq!(status, 200);
+        assert_eq!(body["status"], "ok");
+    }
+}
@@ -1,5 +1,6 @@
 pub mod openai_inference_service;
 
+use crate::domain::config::DomainDefinition;
 use crate::domain::services::NewsInferenceService;
 use std::sync::Arc;
 use tracing::warn;
@@ -8,10 +9,14 @@ use tracing::warn;
 /// 
 /// 如果配置了OPENAI_API_KEY环境变量，则创建OpenAI服务
 /// 否则返回None，表示禁用AI分类
-pub fn create_inference_service() -> Option<Arc<dyn NewsInferenceService>> {
+///
+/// `domains` 为提示词中的候选领域，应与分类使用的配置一致
+pub fn create_inference_service(
+    domains: Vec<DomainDefinition>,
+) -> Option<Arc<dyn NewsInferenceService>> {
     match openai_inference_service::OpenAIInferenceService::new() {
         Ok(service) => {
-            Some(Arc::new(service))
+            Some(Arc::new(service.with_domains(domains)))
         }
         Err(e) => {
             warn!("⚠️ 无法创建AI推理服务: {}. AI分类将禁用", e);
@@ -1,9 +1,10 @@
+use crate::domain::config::DomainDefinition;
 use crate::domain::services::{InferenceResult, NewsInferenceService};
-use crate::domain::{Domain, NewsItem};
+use crate::domain::{Domain, DomainLabel, NewsItem};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 /// OpenAI 请求结构
 #[derive(Serialize)]
@@ -62,13 +63,54 @@ struct AIClassification {
     reason: Option<String>,
     #[serde(default)]
     suggested_keywords: Vec<String>,
+    #[serde(default)]
+    secondary_domains: Vec<AISecondaryDomain>,
+}
+
+/// AI 返回的次要领域
+#[derive(Deserialize)]
+struct AISecondaryDomain {
+    domain: String,
+    confidence: f32,
 }
 
+/// 系统提示词模板，`{domain_list}`、`{domain_names}` 和 `{domain_choices}` 由配置中的领域填充
+const SYSTEM_PROMPT_TEMPLATE: &str = r#"You are a professional news classifier. 
+Your task is to analyze news items and determine if they belong to:
+{domain_list}
+
+### RULES:
+1. Always provide a "reason" in brief English, regardless of the "is_relevant" value.
+2. If "is_relevant" is false:
+   - Set "domain" to null.
+   - The "reason" should explain why it does not fit the target domains.
+3. If "is_relevant" is true:
+   - "domain" MUST be one of [{domain_names}].
+   - The "reason" should highlight the specific connection to the domain.
+4. "suggested_keywords" should be an empty array [] if "is_relevant" is false.
+5. Output strictly valid JSON. No conversational filler.
+6. "is_relevant" MUST be strictly a boolean (true or false). NEVER use null or any other type.
+7. If the news clearly also belongs to another target domain, put the main one in "domain" and list the others in "secondary_domains" with their own confidence.
+   Otherwise "secondary_domains" should be an empty array [].
+
+### OUTPUT FORMAT:
+{
+  "is_relevant": boolean,
+  "domain": {domain_choices} | null,
+  "confidence": float,
+  "reason": "Short explanation in English",
+  "suggested_keywords": ["keyword1", "keyword2"],
+  "secondary_domains": [{"domain": {domain_choices}, "confidence": float}]
+}
+"#;
+
 pub struct OpenAIInferenceService {
     api_key: String,
     model_name: String,
     base_url: String,
     client: reqwest::Client,
+    /// 候选领域（提示词中的领域列表及说明）
+    domains: Vec<DomainDefinition>,
 }
 
 impl OpenAIInferenceService {
@@ -91,67 +133,56 @@ impl OpenAIInferenceService {
             model_name: model,
             base_url,
             client: reqwest::Client::new(),
+            domains: DomainDefinition::defaults(),
         })
     }
-}
 
-#[async_trait]
-impl NewsInferenceService for OpenAIInferenceService {
-    async fn infer(
-        &self,
-        news: &NewsItem,
-    ) -> Result<InferenceResult, Box<dyn Error + Send + Sync>> {
-        let content_preview = news.content.as_deref().unwrap_or("No content provided.");
-        let limit = 2000;
-        let truncated_content = if content_preview.len() > limit {
-            let mut end = limit;
-            while !content_preview.is_char_boundary(end) && end > 0 {
-                end -= 1;
-            }
-            &content_preview[..end]
-        } else {
-            content_preview
-        };
-
-        let sys_prompt = r#"You are a professional news classifier. 
-Your task is to analyze news items and determine if they belong to:
-- AI: Artificial intelligence, LLMs, Neural Networks, Robotics, etc.
-- Block: Cryptocurrency, Web3, DeFi, Smart Contracts, etc.
-- Social: Social Media platforms (Twitter/X, Meta, Tiktok, etc.), tech platform news.
+    /// 使用指定的候选领域（默认为内置领域，应传入当前分类配置中的领域）
+    pub fn with_domains(mut self, domains: Vec<DomainDefinition>) -> Self {
+        self.domains = domains;
+        self
+    }
 
-### RULES:
-1. Always provide a "reason" in brief English, regardless of the "is_relevant" value.
-2. If "is_relevant" is false:
-   - Set "domain" to null.
-   - The "reason" should explain why it does not fit the target domains.
-3. If "is_relevant" is true:
-   - "domain" MUST be one of ["AI", "Block", "Social"].
-   - The "reason" should highlight the specific connection to the domain.
-4. "suggested_keywords" should be an empty array [] if "is_relevant" is false.
-5. Output strictly valid JSON. No conversational filler.
-6. "is_relevant" MUST be strictly a boolean (true or false). NEVER use null or any other type.
+    /// 根据候选领域生成系统提示词
+    fn system_prompt(&self) -> String {
+        let domain_list: Vec<String> = self
+            .domains
+            .iter()
+            .map(|d| format!("- {}: {}", d.name, d.description))
+            .collect();
+        let quoted: Vec<String> = self
+            .domains
+            .iter()
+            .map(|d| format!("\"{}\"", d.name))
+            .collect();
+        SYSTEM_PROMPT_TEMPLATE
+            .replace("{domain_list}", &domain_list.join("\n"))
+            .replace("{domain_names}", &quoted.join(", "))
+            .replace("{domain_choices}", &quoted.join(" | "))
+    }
 
-### OUTPUT FORMAT:
-{
-  "is_relevant": boolean,
-  "domain": "AI" | "Block" | "Social" | null,
-  "confidence": float,
-  "reason": "Short explanation in English",
-  "suggested_keywords": ["keyword1", "keyword2"]
-}
-"#;
+    /// 解析 AI 返回的领域名称（不区分大小写，未配置的名称返回 None）
+    fn parse_domain(&self, name: &str) -> Option<Domain> {
+        let name = name.trim();
+        self.domains
+            .iter()
+            .find(|d| d.name.display_name().eq_ignore_ascii_case(name))
+            .map(|d| d.name)
+    }
 
-        let user_input = format!(
-            "Title: {}\nContent Snippet: {}\n",
-            news.title, truncated_content
-        );
+    /// 发送一次 chat completion 请求，返回模型输出的文本
+    async fn complete(
+        &self,
+        sys_prompt: String,
+        user_input: String,
+    ) -> Result<String, Box<dyn Error + Send + Sync>> {
         info!("Sending request to OpenAI with input: {}", &user_input);
         let request = OpenAIChatRequest {
             model: self.model_name.clone(),
             messages: vec![
                 ChatMessage {
                     role: "system".to_string(),
-                    content: sys_prompt.to_string(),
+                    content: sys_prompt,
                 },
                 ChatMessage {
                     role: "user".to_string(),
@@ -163,8 +194,6 @@ Your task is to analyze news items and determine if they belong to:
             response_format: ResponseFormat::json(),
         };
 
-
-
         let url = format!("{}/chat/completions", self.base_url);
         let response = self
             .client
@@ -195,31 +224,182 @@ Your task is to analyze news items and determine if they belong to:
         let body: OpenAIChatResponse = response.json().await?;
 
         // OpenAI返回choices数组，取第一个
-        if body.choices.is_empty() {
+        let Some(choice) = body.choices.into_iter().next() else {
             return Err("OpenAI API returned empty choices array".into());
-        }
-
-        let content = &body.choices[0].message.content;
-        info!("🤖 AI Raw Response: {}", content);
-
-        let ai_result: AIClassification = serde_json::from_str(content)?;
+        };
+        info!("🤖 AI Raw Response: {}", choice.message.content);
+        Ok(choice.message.content)
+    }
 
-        let final_domain = match ai_result.domain.as_deref() {
-            Some("AI") => Some(Domain::AI),
-            Some("Block") => Some(Domain::Block),
-            Some("Social") => Some(Domain::Social),
-            _ => None,
+    /// 把模型输出转换为推理结果（忽略未配置的领域）
+    fn to_inference_result(&self, ai_result: AIClassification) -> InferenceResult {
+        let final_domain = ai_result
+            .domain
+            .as_deref()
+            .and_then(|name| self.parse_domain(name));
+        // 忽略未知领域以及与主领域重复的次要领域
+        let secondary_domains = match final_domain {
+            Some(primary) => ai_result
+                .secondary_domains
+                .iter()
+                .filter_map(|s| {
+                    self.parse_domain(&s.domain)
+                        .filter(|&d| d != primary)
+                        .map(|d| DomainLabel::new(d, s.confidence))
+                })
+                .collect(),
+            None => Vec::new(),
         };
 
-        Ok(InferenceResult {
+        InferenceResult {
             is_relevant: ai_result.is_relevant && final_domain.is_some(),
             domain: final_domain,
+            secondary_domains,
             confidence: ai_result.confidence,
             reason: ai_result
                 .reason
                 .unwrap_or_else(|| "No reason provided".to_string()),
             suggested_keywords: ai_result.suggested_keywords,
-        })
+        }
+    }
+
+    /// 一次请求分析多条新闻，按 `id`（从 1 开始的序号）取回各自的结果
+    ///
+    /// 请求失败或整体无法解析时返回错误；个别条目缺失或格式错误时对应位置为 `None`。
+    async fn infer_packed(
+        &self,
+        news: &[NewsItem],
+    ) -> Result<Vec<Option<InferenceResult>>, Box<dyn Error + Send + Sync>> {
+        let user_input: String = news
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                format!(
+                    "ID: {}\nTitle: {}\nContent Snippet: {}\n\n",
+                    i + 1,
+                    item.title,
+                    content_snippet(item, BATCH_CONTENT_LIMIT)
+                )
+            })
+            .collect();
+        let sys_prompt = format!("{}{}", self.system_prompt(), BATCH_PROMPT_SUFFIX);
+        let content = self.complete(sys_prompt, user_input).await?;
+        let batch: AIBatchClassification = serde_json::from_str(&content)?;
+
+        let mut results: Vec<Option<InferenceResult>> = news.iter().map(|_| None).collect();
+        for entry in batch.results {
+            let Some(index) = batch_entry_index(&entry, news.len()) else {
+                warn!("⚠️ 批量推理返回了未知的 id: {}", entry);
+                continue;
+            };
+            if results[index].is_some() {
+                continue;
+            }
+            match serde_json::from_value::<AIClassification>(entry) {
+                Ok(ai_result) => results[index] = Some(self.to_inference_result(ai_result)),
+                Err(e) => warn!("⚠️ 批量推理第 {} 条格式错误: {}", index + 1, e),
+            }
+        }
+        Ok(results)
+    }
+}
+
+/// 单条请求中正文的最大字节数
+const CONTENT_LIMIT: usize = 2000;
+
+/// 批量请求中每条新闻正文的最大字节数（控制整个请求的长度）
+const BATCH_CONTENT_LIMIT: usize = 600;
+
+/// 批量请求附加在系统提示词后的说明
+const BATCH_PROMPT_SUFFIX: &str = r#"
+### BATCH MODE:
+You will receive several news items, each starting with "ID: <number>".
+Classify each item independently with the rules above and return one object per item:
+{
+  "results": [
+    {"id": <number>, "is_relevant": ..., "domain": ..., "confidence": ..., "reason": ..., "suggested_keywords": [...], "secondary_domains": [...]}
+  ]
+}
+Every ID from the input MUST appear exactly once in "results".
+"#;
+
+/// 批量推理的返回格式
+#[derive(Deserialize)]
+struct AIBatchClassification {
+    #[serde(default)]
+    results: Vec<serde_json::Value>,
+}
+
+/// 条目 `id`（数字或数字字符串，从 1 开始）对应的位置
+fn batch_entry_index(entry: &serde_json::Value, len: usize) -> Option<usize> {
+    let id = match entry.get("id")? {
+        serde_json::Value::Number(n) => n.as_u64()?,
+        serde_json::Value::String(s) => s.trim().parse().ok()?,
+        _ => return None,
+    };
+    let index = usize::try_from(id).ok()?.checked_sub(1)?;
+    (index < len).then_some(index)
+}
+
+/// 截取正文开头（不超过 `limit` 字节，不截断字符）
+fn content_snippet(news: &NewsItem, limit: usize) -> &str {
+    let content = news.content.as_deref().unwrap_or("No content provided.");
+    if content.len() <= limit {
+        return content;
+    }
+    let mut end = limit;
+    while !content.is_char_boundary(end) && end > 0 {
+        end -= 1;
+    }
+    &content[..end]
+}
+
+#[async_trait]
+impl NewsInferenceService for OpenAIInferenceService {
+    async fn infer(
+        &self,
+        news: &NewsItem,
+    ) -> Result<InferenceResult, Box<dyn Error + Send + Sync>> {
+        let user_input = format!(
+            "Title: {}\nContent Snippet: {}\n",
+            news.title,
+            content_snippet(news, CONTENT_LIMIT)
+        );
+        let content = self.complete(self.system_prompt(), user_input).await?;
+        let ai_result: AIClassification = serde_json::from_str(&content)?;
+        Ok(self.to_inference_result(ai_result))
+    }
+
+    /// 多条新闻合并为一次请求；缺失或格式错误的条目改为单独请求
+    async fn infer_batch(
+        &self,
+        news: &[NewsItem],
+    ) -> Vec<Result<InferenceResult, Box<dyn Error + Send + Sync>>> {
+        let packed = if news.len() > 1 {
+            match self.infer_packed(news).await {
+                Ok(results) => results,
+                Err(e) => {
+                    warn!("⚠️ 批量推理失败，改为逐条请求: {}", e);
+                    news.iter().map(|_| None).collect()
+                }
+            }
+        } else {
+            news.iter().map(|_| None).collect()
+        };
+
+        let mut results = Vec::with_capacity(news.len());
+        for (item, result) in news.iter().zip(packed) {
+            match result {
+                Some(result) => results.push(Ok(result)),
+                None => {
+                    if news.len() > 1 {
+                        info!("🔁 批量推理缺少该条结果，单独请求: {}", item.title);
+                    }
+                    results.push(self.infer(item).await);
+                }
+            }
+        }
+        results
     }
 
     fn name(&self) -> &str {
@@ -233,6 +413,101 @@ mod tests {
     use serde_json::json;
     use std::time::{Duration, Instant};
 
+    #[test]
+    fn test_prompt_and_parsing_follow_configured_domains() {
+        let security = DomainDefinition {
+            description: "Vulnerabilities, breaches, malware.".to_string(),
+            ..DomainDefinition::new(Domain::new("Security"))
+        };
+        let opai = OpenAIInferenceService::new()
+            .unwrap()
+            .with_domains(vec![security]);
+
+        let prompt = opai.system_prompt();
+        assert!(prompt.contains("- Security: Vulnerabilities, breaches, malware."));
+        assert!(prompt.contains(r#""domain" MUST be one of ["Security"]"#));
+        assert!(!prompt.contains("Block"));
+
+        assert_eq!(
+            opai.parse_domain(" security"),
+            Some(Domain::new("Security"))
+        );
+        assert_eq!(opai.parse_domain("AI"), None);
+    }
+
+    #[tokio::test]
+    async fn test_infer_batch_falls_back_for_missing_and_malformed_ids() {
+        use wiremock::matchers::{body_string_contains, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        fn chat_response(content: serde_json::Value) -> ResponseTemplate {
+            ResponseTemplate::new(200).set_body_json(json!({
+                "choices": [{"message": {"content": content.to_string()}}]
+            }))
+        }
+
+        let server = MockServer::start().await;
+        // 批量请求：第 2 条缺失，第 3 条格式错误
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .and(body_string_contains("BATCH MODE"))
+            .respond_with(chat_response(json!({"results": [
+                {"id": 1, "is_relevant": true, "domain": "AI", "confidence": 0.9, "reason": "llm"},
+                {"id": "3", "is_relevant": "maybe"}
+            ]})))
+            .with_priority(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        for (title, domain) in [("Second", "Block"), ("Third", "Social")] {
+            Mock::given(method("POST"))
+                .and(path("/chat/completions"))
+                .and(body_string_contains(format!("Title: {}", title)))
+                .respond_with(chat_response(json!({
+                    "is_relevant": true, "domain": domain, "confidence": 0.8, "reason": "single"
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let opai = OpenAIInferenceService {
+            api_key: "test".to_string(),
+            model_name: "test-model".to_string(),
+            base_url: server.uri(),
+            client: reqwest::Client::new(),
+            domains: DomainDefinition::defaults(),
+        };
+        let news: Vec<NewsItem> = ["First", "Second", "Third"]
+            .iter()
+            .map(|title| {
+                NewsItem::new(
+                    title.to_lowercase(),
+                    title.to_string(),
+                    format!("https://example.com/{}", title),
+                    "test".to_string(),
+                    "author".to_string(),
+                    Utc::now(),
+                )
+            })
+            .collect();
+
+        let results = opai.infer_batch(&news).await;
+
+        let domains: Vec<Option<Domain>> =
+            results.iter().map(|r| r.as_ref().unwrap().domain).collect();
+        assert_eq!(
+            domains,
+            vec![
+                Some(Domain::new("AI")),
+                Some(Domain::new("Block")),
+                Some(Domain::new("Social"))
+            ]
+        );
+        assert_eq!(results[0].as_ref().unwrap().reason, "llm");
+        assert_eq!(results[1].as_ref().unwrap().reason, "single");
+    }
+
     #[tokio::test]
     async fn test_ai_infer() {
         let news = NewsItem::new(
@@ -1,5 +1,6 @@
 pub mod database;
 pub mod discord;
+pub mod http;
 pub mod inference;
 pub mod news_sources;
 pub mod repositories;
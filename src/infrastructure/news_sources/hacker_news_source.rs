@@ -14,7 +14,11 @@ struct RawHNItem {
     title: String,
     url: Option<String>,
     by: String,
-    score: i32,
+    #[serde(default)]
+    score: Option<u32>,
+    /// 评论总数
+    #[serde(default)]
+    descendants: Option<u32>,
     time: u64,
 }
 
@@ -37,8 +41,15 @@ impl HackerNewsSource {
         }
     }
 
+    /// Override the API base URL (e.g. for a mirror)
+    pub fn with_api_base(mut self, api_base: impl Into<String>) -> Self {
+        self.api_base = api_base.into().trim_end_matches('/').to_string();
+        self
+    }
+
     /// Convert raw HN item to domain NewsItem
     fn convert_to_domain(&self, raw: RawHNItem) -> NewsItem {
+        let discussion_url = format!("https://news.ycombinator.com/item?id={}", raw.id);
         NewsItem::new(
             raw.id.to_string(),                        // id
             raw.title,                                 // title
@@ -49,6 +60,8 @@ impl HackerNewsSource {
                 .single()
                 .unwrap_or(Utc::now()), // published_at
         )
+        .with_engagement(raw.score, raw.descendants)
+        .with_discussion_url(discussion_url)
     }
 }
 
@@ -64,7 +77,8 @@ impl NewsFetcher for HackerNewsSource {
 
         let mut tasks = JoinSet::new();
 
-        for id in ids.into_iter().take(limit) {
+        for (index, id) in ids.into_iter().take(limit).enumerate() {
+            let rank = index as u32 + 1;
             let item_url = format!("{}/item/{}.json", self.api_base, id);
             let client = self.client.clone();
 
@@ -78,7 +92,7 @@ impl NewsFetcher for HackerNewsSource {
                     Ok(response) => {
                         if let Ok(raw_item) = response.json::<RawHNItem>().await {
                             if raw_item.url.is_some() {
-                                Some(raw_item)
+                                Some((rank, raw_item))
                             } else {
                                 None
                             }
@@ -97,8 +111,8 @@ impl NewsFetcher for HackerNewsSource {
         // Step 3: Collect results
         let mut news_items = Vec::new();
         while let Some(result) = tasks.join_next().await {
-            if let Ok(Some(raw_item)) = result {
-                news_items.push(self.convert_to_domain(raw_item));
+            if let Ok(Some((rank, raw_item))) = result {
+                news_items.push(self.convert_to_domain(raw_item).with_source_rank(rank));
             }
         }
 
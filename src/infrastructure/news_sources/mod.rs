@@ -1,5 +1,5 @@
-
 pub mod hacker_news_source;
+pub mod rss_feed_source;
 
-
-pub use hacker_news_source::HackerNewsSource;
\ No newline at end of file
+pub use hacker_news_source::HackerNewsSource;
+pub use rss_feed_source::RssFeedSource;
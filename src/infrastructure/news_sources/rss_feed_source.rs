@@ -0,0 +1,281 @@
+use crate::domain::NewsFetcher;
+use crate::domain::NewsItem;
+use async_trait::async_trait;
+use chrono::Utc;
+use feed_rs::model::{Entry, Feed};
+use reqwest::Client;
+use std::time::Duration;
+
+/// RSS 2.0 / Atom 数据源实现
+///
+/// 每个实例对应一个 feed，条目的 summary 存入 `NewsItem::content`，
+/// 后续分类阶段可以直接使用，无需再抓取全文。
+pub struct RssFeedSource {
+    name: String,
+    feed_url: String,
+    client: Client,
+}
+
+impl RssFeedSource {
+    /// Create a new RssFeedSource
+    ///
+    /// - `name`: 数据源名称，会写入每条新闻的 `source` 字段
+    /// - `feed_url`: feed 地址
+    pub fn new(name: impl Into<String>, feed_url: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            feed_url: feed_url.into(),
+            client: Client::builder()
+                .timeout(Duration::from_secs(10))
+                .connect_timeout(Duration::from_secs(5))
+                .build()
+                .unwrap(),
+        }
+    }
+
+    /// 解析 feed 文档并转换为领域对象
+    fn parse_feed(
+        &self,
+        body: &[u8],
+        limit: usize,
+    ) -> Result<Vec<NewsItem>, Box<dyn std::error::Error + Send + Sync>> {
+        let feed = feed_rs::parser::parse(body)?;
+        let feed_author = feed_author(&feed);
+
+        Ok(feed
+            .entries
+            .into_iter()
+            .filter_map(|entry| self.convert_to_domain(entry, feed_author.as_deref()))
+            .take(limit)
+            .enumerate()
+            .map(|(index, item)| item.with_source_rank(index as u32 + 1))
+            .collect())
+    }
+
+    /// Convert a feed entry to domain NewsItem
+    ///
+    /// 没有标题或链接的条目会被跳过。
+    fn convert_to_domain(&self, entry: Entry, feed_author: Option<&str>) -> Option<NewsItem> {
+        let title = entry
+            .title
+            .map(|t| strip_html(&t.content))
+            .filter(|t| !t.is_empty())?;
+
+        let url = entry
+            .links
+            .iter()
+            .find(|l| l.rel.as_deref().is_none_or(|rel| rel == "alternate"))
+            .or_else(|| entry.links.first())
+            .map(|l| l.href.trim().to_string())
+            .filter(|href| !href.is_empty())?;
+
+        let author = entry
+            .authors
+            .first()
+            .map(|p| p.name.trim().to_string())
+            .filter(|n| !n.is_empty())
+            .or_else(|| feed_author.map(|a| a.to_string()))
+            .unwrap_or_else(|| self.name.clone());
+
+        let published_at = entry.published.or(entry.updated).unwrap_or(Utc::now());
+
+        let summary = entry
+            .summary
+            .map(|s| s.content)
+            .or_else(|| entry.content.and_then(|c| c.body))
+            .map(|s| strip_html(&s))
+            .filter(|s| !s.is_empty());
+
+        let item = NewsItem::new(
+            format!("{}:{}", self.name, entry.id), // id
+            title,                                 // title
+            url,                                   // url
+            self.name.clone(),                     // source
+            author,                                // author
+            published_at,                          // published_at
+        );
+
+        Some(match summary {
+            Some(summary) => item.with_content(summary),
+            None => item,
+        })
+    }
+}
+
+#[async_trait]
+impl NewsFetcher for RssFeedSource {
+    async fn fetch(
+        &self,
+        limit: usize,
+    ) -> Result<Vec<NewsItem>, Box<dyn std::error::Error + Send + Sync>> {
+        let response = self.client.get(&self.feed_url).send().await?;
+        if !response.status().is_success() {
+            return Err(format!("Feed {} returned {}", self.feed_url, response.status()).into());
+        }
+
+        let body = response.bytes().await?;
+        self.parse_feed(&body, limit)
+    }
+
+    fn source_name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// feed 级别的作者（条目缺少作者时使用）
+fn feed_author(feed: &Feed) -> Option<String> {
+    feed.authors
+        .first()
+        .map(|p| p.name.trim().to_string())
+        .filter(|n| !n.is_empty())
+}
+
+/// 会断开文本的块级标签（空格分隔），去除时替换为空白；其他（行内）标签直接去掉
+const BLOCK_TAGS: &str = "address article blockquote br dd div dl dt figcaption figure footer \
+                          h1 h2 h3 h4 h5 h6 header hr li ol p pre section table td th tr ul";
+
+/// 去除 HTML 标签并合并空白（summary 经常是 HTML 片段）
+///
+/// 只有块级标签替换为空白，`<b>GPT</b>-4` 这样的行内标签不会把单词拆开
+fn strip_html(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut tag: Option<String> = None;
+
+    for c in html.chars() {
+        match (c, tag.as_mut()) {
+            ('<', None) => tag = Some(String::new()),
+            ('>', Some(inner)) => {
+                if is_block_tag(inner) {
+                    text.push(' ');
+                }
+                tag = None;
+            }
+            (_, Some(inner)) => inner.push(c),
+            (_, None) => text.push(c),
+        }
+    }
+
+    let text = text
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// 标签内容（`<` 与 `>` 之间）是否为会断开文本的块级标签，例如 `p`、`/li`、`br/`
+fn is_block_tag(inner: &str) -> bool {
+    let name = inner
+        .trim_start_matches('/')
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    BLOCK_TAGS.split(' ').any(|block| block == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const RSS_SAMPLE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0">
+  <channel>
+    <title>Example AI Blog</title>
+    <link>https://blog.example.com</link>
+    <item>
+      <title>Introducing a new LLM</title>
+      <link>https://blog.example.com/new-llm</link>
+      <author>alice@example.com (Alice)</author>
+      <pubDate>Tue, 10 Jun 2025 04:00:00 GMT</pubDate>
+      <description>&lt;p&gt;We trained a &lt;b&gt;large language model&lt;/b&gt;.&lt;/p&gt;</description>
+      <guid>https://blog.example.com/new-llm</guid>
+    </item>
+    <item>
+      <title></title>
+      <link>https://blog.example.com/untitled</link>
+    </item>
+  </channel>
+</rss>"#;
+
+    const ATOM_SAMPLE: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>Example Crypto Feed</title>
+  <id>urn:uuid:60a76c80-d399-11d9-b93C-0003939e0af6</id>
+  <updated>2025-06-10T18:30:02Z</updated>
+  <author><name>Feed Team</name></author>
+  <entry>
+    <title>Ethereum upgrade ships</title>
+    <link rel="alternate" href="https://crypto.example.com/upgrade"/>
+    <id>urn:uuid:1225c695-cfb8-4ebb-aaaa-80da344efa6a</id>
+    <updated>2025-06-10T18:30:02Z</updated>
+    <summary>The network upgrade is live.</summary>
+  </entry>
+</feed>"#;
+
+    #[test]
+    fn test_parse_rss_feed() {
+        let source = RssFeedSource::new("example-ai", "https://blog.example.com/rss");
+        let items = source.parse_feed(RSS_SAMPLE.as_bytes(), 10).unwrap();
+
+        // 无标题的条目被跳过
+        assert_eq!(items.len(), 1);
+        let item = &items[0];
+        assert_eq!(item.title, "Introducing a new LLM");
+        assert_eq!(item.url, "https://blog.example.com/new-llm");
+        assert_eq!(item.source, "example-ai");
+        assert!(item.id.starts_with("example-ai:"));
+        assert_eq!(item.source_rank, Some(1));
+        assert_eq!(item.published_at.to_rfc3339(), "2025-06-10T04:00:00+00:00");
+        assert_eq!(
+            item.content.as_deref(),
+            Some("We trained a large language model.")
+        );
+    }
+
+    #[test]
+    fn test_strip_html_keeps_inline_tags_attached() {
+        assert_eq!(strip_html("<b>GPT</b>-4 is <i>here</i>!"), "GPT-4 is here!");
+        assert_eq!(
+            strip_html("<p>First</p><p>Second<br/>line</p><ul><li>a</li><li>b</li></ul>"),
+            "First Second line a b"
+        );
+        assert_eq!(
+            strip_html("<a href=\"x\">link</a> &amp; more"),
+            "link & more"
+        );
+    }
+
+    #[test]
+    fn test_parse_atom_feed_uses_updated_and_feed_author() {
+        let source = RssFeedSource::new("example-crypto", "https://crypto.example.com/atom");
+        let items = source.parse_feed(ATOM_SAMPLE.as_bytes(), 10).unwrap();
+
+        assert_eq!(items.len(), 1);
+        let item = &items[0];
+        assert_eq!(item.url, "https://crypto.example.com/upgrade");
+        assert_eq!(item.author, "Feed Team");
+        assert_eq!(item.published_at.to_rfc3339(), "2025-06-10T18:30:02+00:00");
+        assert_eq!(item.content.as_deref(), Some("The network upgrade is live."));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_respects_limit() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/atom.xml"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(ATOM_SAMPLE))
+            .mount(&mock_server)
+            .await;
+
+        let source = RssFeedSource::new("example", format!("{}/atom.xml", mock_server.uri()));
+        assert_eq!(source.fetch(10).await.unwrap().len(), 1);
+        assert!(source.fetch(0).await.unwrap().is_empty());
+    }
+}
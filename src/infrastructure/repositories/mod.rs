@@ -1,3 +1,5 @@
+pub mod sqlite_keyword_suggestion_repository;
 pub mod sqlite_news_repository;
 
+pub use sqlite_keyword_suggestion_repository::SqliteKeywordSuggestionRepository;
 pub use sqlite_news_repository::SqliteNewsRepository;
\ No newline at end of file
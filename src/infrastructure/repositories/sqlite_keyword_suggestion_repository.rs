@@ -0,0 +1,248 @@
+use super::sqlite_news_repository::parse_domain;
+use crate::domain::{
+    Domain, KeywordSuggestion, KeywordSuggestionRepository, KeywordSuggestionStatus,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+
+/// SQLite 实现的建议关键词仓库
+pub struct SqliteKeywordSuggestionRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteKeywordSuggestionRepository {
+    /// 创建新的仓库实例
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl KeywordSuggestionRepository for SqliteKeywordSuggestionRepository {
+    async fn record(
+        &self,
+        domain: Domain,
+        news_id: &str,
+        keywords: &[String],
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut normalized: Vec<String> = keywords
+            .iter()
+            .filter_map(|k| KeywordSuggestion::normalize(k))
+            .collect();
+        normalized.sort();
+        normalized.dedup();
+        if normalized.is_empty() {
+            return Ok(());
+        }
+
+        let now = Utc::now().to_rfc3339();
+        let domain = domain.to_string();
+        let mut tx = self.pool.begin().await?;
+        for keyword in &normalized {
+            sqlx::query(
+                r#"
+                INSERT INTO keyword_suggestions (keyword, domain, first_seen_at, last_seen_at)
+                VALUES (?1, ?2, ?3, ?3)
+                ON CONFLICT (keyword, domain) DO NOTHING
+                "#,
+            )
+            .bind(keyword)
+            .bind(&domain)
+            .bind(&now)
+            .execute(&mut *tx)
+            .await?;
+
+            // 同一条新闻（例如重新分类时）只计一次
+            let inserted = sqlx::query(
+                r#"
+                INSERT OR IGNORE INTO keyword_suggestion_sources (keyword, domain, news_id, suggested_at)
+                VALUES (?1, ?2, ?3, ?4)
+                "#,
+            )
+            .bind(keyword)
+            .bind(&domain)
+            .bind(news_id)
+            .bind(&now)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+
+            if inserted > 0 {
+                sqlx::query(
+                    r#"
+                    UPDATE keyword_suggestions
+                    SET occurrences = occurrences + 1, last_seen_at = ?3
+                    WHERE keyword = ?1 AND domain = ?2
+                    "#,
+                )
+                .bind(keyword)
+                .bind(&domain)
+                .bind(&now)
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_pending(
+        &self,
+        domain: Option<Domain>,
+        limit: usize,
+    ) -> Result<Vec<KeywordSuggestion>, Box<dyn std::error::Error + Send + Sync>> {
+        let rows = sqlx::query_as::<_, KeywordSuggestionRow>(
+            r#"
+            SELECT keyword, domain, status, occurrences, first_seen_at, last_seen_at
+            FROM keyword_suggestions
+            WHERE status = 'Pending' AND (?1 IS NULL OR domain = ?1)
+            ORDER BY occurrences DESC, last_seen_at DESC, keyword ASC
+            LIMIT ?2
+            "#,
+        )
+        .bind(domain.map(|d| d.to_string()))
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut suggestions = Vec::with_capacity(rows.len());
+        for row in rows {
+            let news_ids: Vec<String> = sqlx::query_scalar(
+                r#"
+                SELECT news_id FROM keyword_suggestion_sources
+                WHERE keyword = ?1 AND domain = ?2
+                ORDER BY suggested_at DESC, news_id ASC
+                "#,
+            )
+            .bind(&row.keyword)
+            .bind(&row.domain)
+            .fetch_all(&self.pool)
+            .await?;
+            suggestions.push(row.into_suggestion(news_ids)?);
+        }
+        Ok(suggestions)
+    }
+
+    async fn set_status(
+        &self,
+        keyword: &str,
+        domain: Domain,
+        status: KeywordSuggestionStatus,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let updated = sqlx::query(
+            "UPDATE keyword_suggestions SET status = ?1 WHERE keyword = ?2 AND domain = ?3",
+        )
+        .bind(format!("{:?}", status))
+        .bind(keyword)
+        .bind(domain.to_string())
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+
+        if updated == 0 {
+            return Err(format!("建议关键词不存在: {} ({})", keyword, domain).into());
+        }
+        Ok(())
+    }
+}
+
+/// keyword_suggestions 表的一行
+#[derive(sqlx::FromRow)]
+struct KeywordSuggestionRow {
+    keyword: String,
+    domain: String,
+    status: String,
+    occurrences: i64,
+    first_seen_at: String,
+    last_seen_at: String,
+}
+
+impl KeywordSuggestionRow {
+    fn into_suggestion(
+        self,
+        news_ids: Vec<String>,
+    ) -> Result<KeywordSuggestion, Box<dyn std::error::Error + Send + Sync>> {
+        let domain =
+            parse_domain(&self.domain).ok_or_else(|| format!("未知领域: {}", self.domain))?;
+        let status = KeywordSuggestionStatus::parse(&self.status)
+            .ok_or_else(|| format!("未知建议状态: {}", self.status))?;
+        Ok(KeywordSuggestion {
+            keyword: self.keyword,
+            domain,
+            status,
+            occurrences: self.occurrences as u32,
+            news_ids,
+            first_seen_at: DateTime::parse_from_rfc3339(&self.first_seen_at)?.with_timezone(&Utc),
+            last_seen_at: DateTime::parse_from_rfc3339(&self.last_seen_at)?.with_timezone(&Utc),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database::create_pool;
+
+    async fn setup() -> SqliteKeywordSuggestionRepository {
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        SqliteKeywordSuggestionRepository::new(pool)
+    }
+
+    #[tokio::test]
+    async fn test_record_counts_distinct_news() {
+        let repo = setup().await;
+        let keywords = vec!["Vector DB".to_string(), "rag".to_string()];
+
+        repo.record(Domain::new("AI"), "1", &keywords)
+            .await
+            .unwrap();
+        repo.record(Domain::new("AI"), "1", &keywords)
+            .await
+            .unwrap();
+        repo.record(Domain::new("AI"), "2", &["RAG ".to_string()])
+            .await
+            .unwrap();
+
+        let pending = repo.find_pending(None, 10).await.unwrap();
+        assert_eq!(pending.len(), 2);
+        assert_eq!(pending[0].keyword, "rag");
+        assert_eq!(pending[0].occurrences, 2);
+        assert_eq!(pending[0].news_ids.len(), 2);
+        assert_eq!(pending[1].keyword, "vector db");
+        assert_eq!(pending[1].occurrences, 1);
+
+        assert!(
+            repo.find_pending(Some(Domain::new("Block")), 10)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rejected_suggestion_stays_out_of_inbox() {
+        let repo = setup().await;
+        repo.record(Domain::new("AI"), "1", &["hype".to_string()])
+            .await
+            .unwrap();
+        repo.set_status("hype", Domain::new("AI"), KeywordSuggestionStatus::Rejected)
+            .await
+            .unwrap();
+
+        repo.record(Domain::new("AI"), "2", &["hype".to_string()])
+            .await
+            .unwrap();
+        assert!(repo.find_pending(None, 10).await.unwrap().is_empty());
+
+        assert!(
+            repo.set_status(
+                "missing",
+                Domain::new("AI"),
+                KeywordSuggestionStatus::Rejected
+            )
+            .await
+            .is_err()
+        );
+    }
+}
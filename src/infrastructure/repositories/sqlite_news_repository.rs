@@ -1,6 +1,17 @@
-use crate::domain::{Domain, NewsItem, NewsItemStatus, NewsRepository};
+use crate::domain::{
+    ClassificationTrace, Domain, DomainLabel, NewsItem, NewsItemStatus, NewsQuery, NewsQueryOrder,
+    NewsRepository, ReviewLabel, SearchHit, StorySnapshot, UrlCanonicalizer,
+};
 use async_trait::async_trait;
-use sqlx::SqlitePool;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::{Sqlite, SqliteArguments};
+use sqlx::{QueryBuilder, SqliteConnection, SqlitePool};
+use std::collections::HashMap;
+
+/// 查询新闻时统一使用的列（与 `NewsItemRow` 字段一一对应）
+///
+/// 次要领域从 `news_domains` 表聚合为 JSON 数组（领域名称可以包含任意字符），由 `parse_secondary_domains` 解析
+const NEWS_COLUMNS: &str = "id, title, url, source, author, content, published_at, status, domain, classification_confidence, classification_reason, classification_trace, score, comment_count, discussion_url, cluster_id, (SELECT json_group_array(json_object('domain', nd.domain, 'confidence', IFNULL(nd.confidence, 0))) FROM news_domains nd WHERE nd.news_id = news_items.id AND nd.is_primary = 0) AS secondary_domains";
 
 /// SQLite 实现的新闻仓库
 pub struct SqliteNewsRepository {
@@ -17,30 +28,9 @@ impl SqliteNewsRepository {
 #[async_trait]
 impl NewsRepository for SqliteNewsRepository {
     async fn save(&self, news: &NewsItem) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let domain_str = news.domain.map(|d| d.to_string());
-        let confidence = news.classification_confidence;
-        let status_str = format!("{:?}", news.status);
-
-        sqlx::query(
-            r#"
-            INSERT OR IGNORE INTO news_items (id, title, url, source, author, content, published_at, status, domain, classification_confidence, classification_reason)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
-            "#
-        )
-        .bind(&news.id)
-        .bind(&news.title)
-        .bind(&news.url)
-        .bind(&news.source)
-        .bind(&news.author)
-        .bind(&news.content)
-        .bind(news.published_at.to_rfc3339())
-        .bind(&status_str)
-        .bind(&domain_str)
-        .bind(confidence)
-        .bind(&news.classification_reason)
-        .execute(&self.pool)
-        .await?;
-
+        let mut tx = self.pool.begin().await?;
+        insert_news(&mut tx, news).await?;
+        tx.commit().await?;
         Ok(())
     }
 
@@ -51,31 +41,49 @@ impl NewsRepository for SqliteNewsRepository {
         let mut tx = self.pool.begin().await?;
 
         for news in news_items {
-            let domain_str = news.domain.map(|d| d.to_string());
-            let confidence = news.classification_confidence;
-            let status_str = format!("{:?}", news.status);
+            insert_news(&mut tx, news).await?;
+        }
 
-            sqlx::query(
-                r#"
-                INSERT OR IGNORE INTO news_items (id, title, url, source, author, content, published_at, status, domain, classification_confidence, classification_reason)
-                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
-                "#
-            )
-            .bind(&news.id)
-            .bind(&news.title)
-            .bind(&news.url)
-            .bind(&news.source)
-            .bind(&news.author)
-            .bind(&news.content)
-            .bind(news.published_at.to_rfc3339())
-            .bind(&status_str)
-            .bind(&domain_str)
-            .bind(confidence)
-            .bind(&news.classification_reason)
-            .execute(&mut *tx)
-            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn update_classifications(
+        &self,
+        news_items: &[NewsItem],
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut tx = self.pool.begin().await?;
+        for news in news_items {
+            update_classification_query(news).execute(&mut *tx).await?;
+            replace_domain_labels(&mut tx, news).await?;
         }
+        tx.commit().await?;
+        Ok(())
+    }
 
+    async fn save_review(
+        &self,
+        news: &NewsItem,
+        label: &ReviewLabel,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut tx = self.pool.begin().await?;
+        update_classification_query(news).execute(&mut *tx).await?;
+        replace_domain_labels(&mut tx, news).await?;
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO review_labels
+                (news_id, decision, domain, predicted_domain, predicted_confidence, reviewed_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            "#,
+        )
+        .bind(&label.news_id)
+        .bind(format!("{:?}", label.decision))
+        .bind(label.domain.map(|d| d.to_string()))
+        .bind(label.predicted_domain.map(|d| d.to_string()))
+        .bind(label.predicted_confidence)
+        .bind(label.reviewed_at.to_rfc3339())
+        .execute(&mut *tx)
+        .await?;
         tx.commit().await?;
         Ok(())
     }
@@ -84,12 +92,11 @@ impl NewsRepository for SqliteNewsRepository {
         &self,
         id: &str,
     ) -> Result<Option<NewsItem>, Box<dyn std::error::Error + Send + Sync>> {
-        let row = sqlx::query_as::<_, (String, String, String, String, String, Option<String>, String, String, Option<String>, Option<f32>, Option<String>)>(
-            "SELECT id, title, url, source, author, content, published_at, status, domain, classification_confidence, classification_reason FROM news_items WHERE id = ?1"
-        )
-        .bind(id)
-        .fetch_optional(&self.pool)
-        .await?;
+        let query_str = format!("SELECT {} FROM news_items WHERE id = ?1", NEWS_COLUMNS);
+        let row = sqlx::query_as::<_, NewsItemRow>(&query_str)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
 
         Ok(row.map(row_to_news_item).transpose()?)
     }
@@ -120,32 +127,18 @@ impl NewsRepository for SqliteNewsRepository {
             .join(", ");
         let query_str = format!(
             r#"
-            SELECT id, title, url, source, author, content, published_at, status, domain, classification_confidence, classification_reason
+            SELECT {}
             FROM news_items
-            WHERE domain IN ({})
+            WHERE id IN (SELECT news_id FROM news_domains WHERE domain IN ({}))
             ORDER BY published_at DESC
             LIMIT ?{}
             "#,
+            NEWS_COLUMNS,
             placeholders,
             domains.len() + 1
         );
 
-        let mut query = sqlx::query_as::<
-            _,
-            (
-                String,
-                String,
-                String,
-                String,
-                String,
-                Option<String>,
-                String,
-                String,
-                Option<String>,
-                Option<f32>,
-                Option<String>,
-            ),
-        >(&query_str);
+        let mut query = sqlx::query_as::<_, NewsItemRow>(&query_str);
 
         for domain_str in &domain_strs {
             query = query.bind(domain_str);
@@ -160,70 +153,322 @@ impl NewsRepository for SqliteNewsRepository {
         &self,
         limit: usize,
     ) -> Result<Vec<NewsItem>, Box<dyn std::error::Error + Send + Sync>> {
-        let rows = sqlx::query_as::<_, (String, String, String, String, String, Option<String>, String, String, Option<String>, Option<f32>, Option<String>)>(
-            "SELECT id, title, url, source, author, content, published_at, status, domain, classification_confidence, classification_reason FROM news_items ORDER BY published_at DESC LIMIT ?1"
-        )
-        .bind(limit as i64)
-        .fetch_all(&self.pool)
-        .await?;
+        let query_str = format!(
+            "SELECT {} FROM news_items ORDER BY published_at DESC LIMIT ?1",
+            NEWS_COLUMNS
+        );
+        let rows = sqlx::query_as::<_, NewsItemRow>(&query_str)
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await?;
+
+        rows.into_iter().map(row_to_news_item).collect()
+    }
+
+    async fn find(
+        &self,
+        query: &NewsQuery,
+    ) -> Result<Vec<NewsItem>, Box<dyn std::error::Error + Send + Sync>> {
+        if query.cursor.is_some() && !query.order.is_by_published_at() {
+            return Err("游标分页只支持按发布时间排序".into());
+        }
+
+        let mut builder: QueryBuilder<Sqlite> = QueryBuilder::new(format!(
+            "SELECT {} FROM news_items WHERE 1 = 1",
+            NEWS_COLUMNS
+        ));
+
+        // 主领域或次要领域匹配即可
+        if !query.domains.is_empty() {
+            builder.push(" AND id IN (SELECT news_id FROM news_domains WHERE domain IN (");
+            let mut separated = builder.separated(", ");
+            for domain in &query.domains {
+                separated.push_bind(domain.to_string());
+            }
+            separated.push_unseparated("))");
+        }
+        if !query.sources.is_empty() {
+            builder.push(" AND source IN (");
+            let mut separated = builder.separated(", ");
+            for source in &query.sources {
+                separated.push_bind(source.clone());
+            }
+            separated.push_unseparated(")");
+        }
+        if let Some(ref author) = query.author {
+            builder.push(" AND author = ").push_bind(author.clone());
+        }
+        if !query.statuses.is_empty() {
+            builder.push(" AND status IN (");
+            let mut separated = builder.separated(", ");
+            for status in &query.statuses {
+                separated.push_bind(format!("{:?}", status));
+            }
+            separated.push_unseparated(")");
+        }
+        if let Some(min_confidence) = query.min_confidence {
+            builder
+                .push(" AND classification_confidence >= ")
+                .push_bind(min_confidence);
+        }
+        if let Some(max_confidence) = query.max_confidence {
+            builder
+                .push(" AND classification_confidence <= ")
+                .push_bind(max_confidence);
+        }
+        if let Some(threshold) = query.confidence_below {
+            builder
+                .push(" AND (classification_confidence IS NULL OR classification_confidence < ")
+                .push_bind(threshold)
+                .push(")");
+        }
+        // published_at 统一以 UTC RFC3339 字符串存储，可直接按字符串比较
+        if let Some(after) = query.published_after {
+            builder
+                .push(" AND published_at >= ")
+                .push_bind(after.to_rfc3339());
+        }
+        if let Some(before) = query.published_before {
+            builder
+                .push(" AND published_at < ")
+                .push_bind(before.to_rfc3339());
+        }
+        if let Some(ref text) = query.text {
+            // LIKE 对 ASCII 不区分大小写；转义通配符，按字面匹配
+            let pattern = format!("%{}%", escape_like(text));
+            builder
+                .push(" AND (title LIKE ")
+                .push_bind(pattern.clone())
+                .push(" ESCAPE '\\' OR content LIKE ")
+                .push_bind(pattern)
+                .push(" ESCAPE '\\')");
+        }
+        if query.exclude_reviewed {
+            builder.push(" AND id NOT IN (SELECT news_id FROM review_labels)");
+        }
+        if let Some(ref cursor) = query.cursor {
+            let op = match query.order {
+                NewsQueryOrder::Oldest => ">",
+                _ => "<",
+            };
+            let published_at = cursor.published_at.to_rfc3339();
+            builder
+                .push(format!(" AND (published_at {} ", op))
+                .push_bind(published_at.clone())
+                .push(" OR (published_at = ")
+                .push_bind(published_at)
+                .push(format!(" AND id {} ", op))
+                .push_bind(cursor.id.clone())
+                .push("))");
+        }
 
+        // 以 id 作为最后的排序键，保证分页结果稳定
+        builder.push(match query.order {
+            NewsQueryOrder::Newest => " ORDER BY published_at DESC, id DESC",
+            NewsQueryOrder::Oldest => " ORDER BY published_at ASC, id ASC",
+            NewsQueryOrder::Confidence => {
+                " ORDER BY classification_confidence DESC NULLS LAST, published_at DESC, id DESC"
+            }
+            NewsQueryOrder::Score => " ORDER BY score DESC NULLS LAST, published_at DESC, id DESC",
+            NewsQueryOrder::Source => " ORDER BY source ASC, published_at DESC, id DESC",
+        });
+        builder
+            .push(" LIMIT ")
+            .push_bind(query.limit as i64)
+            .push(" OFFSET ")
+            .push_bind(query.offset as i64);
+
+        let rows = builder
+            .build_query_as::<NewsItemRow>()
+            .fetch_all(&self.pool)
+            .await?;
         rows.into_iter().map(row_to_news_item).collect()
     }
 
+    async fn search(
+        &self,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<SearchHit>, Box<dyn std::error::Error + Send + Sync>> {
+        let Some(expression) = fts_match_expression(query) else {
+            return Ok(Vec::new());
+        };
+
+        // bm25 权重依次对应 news_id、title、content、classification_reason，标题命中最重要
+        let query_str = format!(
+            r#"
+            SELECT {}, hit.snippet, hit.relevance
+            FROM news_items
+            JOIN (
+                SELECT news_id,
+                       snippet(news_fts, -1, '[', ']', '…', 16) AS snippet,
+                       bm25(news_fts, 0.0, 10.0, 1.0, 2.0) AS relevance
+                FROM news_fts
+                WHERE news_fts MATCH ?1
+            ) AS hit ON hit.news_id = news_items.id
+            ORDER BY hit.relevance ASC, published_at DESC
+            LIMIT ?2
+            "#,
+            NEWS_COLUMNS
+        );
+        let rows = sqlx::query_as::<_, SearchHitRow>(&query_str)
+            .bind(expression)
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(SearchHit {
+                    news: row_to_news_item(row.news)?,
+                    snippet: row.snippet,
+                    relevance: row.relevance,
+                })
+            })
+            .collect()
+    }
+
     async fn find_by_url(
         &self,
         url: &str,
     ) -> Result<Option<NewsItem>, Box<dyn std::error::Error + Send + Sync>> {
-        let row = sqlx::query_as::<_, (String, String, String, String, String, Option<String>, String, String, Option<String>, Option<f32>, Option<String>)>(
-            "SELECT id, title, url, source, author, content, published_at, status, domain, classification_confidence, classification_reason FROM news_items WHERE url = ?1"
-        )
-        .bind(url)
-        .fetch_optional(&self.pool)
-        .await?;
+        let query_str = format!(
+            "SELECT {} FROM news_items WHERE canonical_url = ?1",
+            NEWS_COLUMNS
+        );
+        let row = sqlx::query_as::<_, NewsItemRow>(&query_str)
+            .bind(UrlCanonicalizer::canonicalize(url))
+            .fetch_optional(&self.pool)
+            .await?;
 
         Ok(row.map(row_to_news_item).transpose()?)
     }
 
-    async fn find_existing_urls(
+    async fn find_ids_by_urls(
         &self,
         urls: &[String],
-    ) -> Result<Vec<String>, Box<dyn std::error::Error + Send + Sync>> {
+    ) -> Result<HashMap<String, String>, Box<dyn std::error::Error + Send + Sync>> {
         if urls.is_empty() {
-            return Ok(Vec::new());
+            return Ok(HashMap::new());
+        }
+
+        // 按规范化 URL 查询，再映射回调用方传入的原始 URL
+        let mut canonical_to_urls: HashMap<String, Vec<&String>> = HashMap::new();
+        for url in urls {
+            canonical_to_urls
+                .entry(UrlCanonicalizer::canonicalize(url))
+                .or_default()
+                .push(url);
         }
+        let canonical_urls: Vec<&String> = canonical_to_urls.keys().collect();
 
         // SQLite 参数限制为 999，分批处理
         const BATCH_SIZE: usize = 999;
-        let mut existing_urls = Vec::new();
-
-        for chunk in urls.chunks(BATCH_SIZE) {
-            if chunk.is_empty() {
-                continue;
-            }
+        let mut ids = HashMap::new();
 
-            // 构建占位符 (?1, ?2, ...)
+        for chunk in canonical_urls.chunks(BATCH_SIZE) {
             let placeholders = chunk
                 .iter()
                 .enumerate()
                 .map(|(i, _)| format!("?{}", i + 1))
                 .collect::<Vec<_>>()
                 .join(", ");
-
             let query_str = format!(
-                "SELECT url FROM news_items WHERE url IN ({})",
+                "SELECT canonical_url, id FROM news_items WHERE canonical_url IN ({})",
                 placeholders
             );
 
-            let mut query = sqlx::query_scalar(&query_str);
-            for url in chunk {
-                query = query.bind(url);
+            let mut query = sqlx::query_as::<_, (String, String)>(&query_str);
+            for canonical_url in chunk {
+                query = query.bind(*canonical_url);
+            }
+
+            for (canonical_url, id) in query.fetch_all(&self.pool).await? {
+                for url in canonical_to_urls.get(&canonical_url).into_iter().flatten() {
+                    ids.insert((*url).clone(), id.clone());
+                }
             }
+        }
+
+        Ok(ids)
+    }
+
+    async fn append_snapshots(
+        &self,
+        snapshots: &[StorySnapshot],
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut tx = self.pool.begin().await?;
+
+        for snapshot in snapshots {
+            sqlx::query(
+                r#"
+                INSERT INTO story_snapshots (news_id, captured_at, score, comment_count, rank)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                "#,
+            )
+            .bind(&snapshot.news_id)
+            .bind(snapshot.captured_at.to_rfc3339())
+            .bind(snapshot.score)
+            .bind(snapshot.comment_count)
+            .bind(snapshot.rank)
+            .execute(&mut *tx)
+            .await?;
 
-            let chunk_results: Vec<String> = query.fetch_all(&self.pool).await?;
-            existing_urls.extend(chunk_results);
+            // 同步最新热度，保证 list / hotness 排序使用的是最新值
+            sqlx::query(
+                r#"
+                UPDATE news_items
+                SET score = COALESCE(?2, score), comment_count = COALESCE(?3, comment_count)
+                WHERE id = ?1
+                "#,
+            )
+            .bind(&snapshot.news_id)
+            .bind(snapshot.score)
+            .bind(snapshot.comment_count)
+            .execute(&mut *tx)
+            .await?;
         }
 
-        Ok(existing_urls)
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_snapshots(
+        &self,
+        news_id: &str,
+    ) -> Result<Vec<StorySnapshot>, Box<dyn std::error::Error + Send + Sync>> {
+        let rows = sqlx::query_as::<_, StorySnapshotRow>(
+            r#"
+            SELECT news_id, captured_at, score, comment_count, rank
+            FROM story_snapshots
+            WHERE news_id = ?1
+            ORDER BY captured_at ASC, id ASC
+            "#,
+        )
+        .bind(news_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(row_to_snapshot).collect()
+    }
+
+    async fn find_snapshots_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<StorySnapshot>, Box<dyn std::error::Error + Send + Sync>> {
+        let rows = sqlx::query_as::<_, StorySnapshotRow>(
+            r#"
+            SELECT news_id, captured_at, score, comment_count, rank
+            FROM story_snapshots
+            WHERE captured_at >= ?1
+            ORDER BY news_id ASC, captured_at ASC, id ASC
+            "#,
+        )
+        .bind(since.to_rfc3339())
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(row_to_snapshot).collect()
     }
 
     async fn count(&self) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
@@ -258,64 +503,242 @@ impl NewsRepository for SqliteNewsRepository {
     }
 }
 
+/// 插入一条新闻及其领域（规范化 URL 已存在时整条跳过）
+async fn insert_news(conn: &mut SqliteConnection, news: &NewsItem) -> Result<(), sqlx::Error> {
+    let inserted = insert_news_query(news).execute(&mut *conn).await?;
+    if inserted.rows_affected() > 0 {
+        replace_domain_labels(conn, news).await?;
+    }
+    Ok(())
+}
+
+/// 用新闻当前的主领域和次要领域替换 `news_domains` 中的记录
+async fn replace_domain_labels(
+    conn: &mut SqliteConnection,
+    news: &NewsItem,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM news_domains WHERE news_id = ?1")
+        .bind(&news.id)
+        .execute(&mut *conn)
+        .await?;
+
+    let primary = news
+        .domain
+        .map(|domain| (domain, news.classification_confidence, true));
+    let secondary = news
+        .secondary_domains
+        .iter()
+        .map(|label| (label.domain, Some(label.confidence), false));
+    for (domain, confidence, is_primary) in primary.into_iter().chain(secondary) {
+        sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO news_domains (news_id, domain, confidence, is_primary)
+            VALUES (?1, ?2, ?3, ?4)
+            "#,
+        )
+        .bind(&news.id)
+        .bind(domain.to_string())
+        .bind(confidence)
+        .bind(is_primary)
+        .execute(&mut *conn)
+        .await?;
+    }
+    Ok(())
+}
+
+/// 构建插入新闻的查询（规范化 URL 已存在时静默跳过，原始 URL 保留用于展示）
+fn insert_news_query(news: &NewsItem) -> sqlx::query::Query<'_, Sqlite, SqliteArguments<'_>> {
+    sqlx::query(
+        r#"
+        INSERT OR IGNORE INTO news_items (id, title, url, source, author, content, published_at, status, domain, classification_confidence, classification_reason, score, comment_count, discussion_url, canonical_url, cluster_id, classification_trace)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)
+        "#,
+    )
+    .bind(&news.id)
+    .bind(&news.title)
+    .bind(&news.url)
+    .bind(&news.source)
+    .bind(&news.author)
+    .bind(&news.content)
+    .bind(news.published_at.to_rfc3339())
+    .bind(format!("{:?}", news.status))
+    .bind(news.domain.map(|d| d.to_string()))
+    .bind(news.classification_confidence)
+    .bind(&news.classification_reason)
+    .bind(news.score)
+    .bind(news.comment_count)
+    .bind(&news.discussion_url)
+    .bind(UrlCanonicalizer::canonicalize(&news.url))
+    .bind(&news.cluster_id)
+    .bind(news.classification_trace.as_ref().map(ClassificationTrace::to_json))
+}
+
+/// 构建更新分类结果（领域、置信度、分类依据、分类轨迹、状态）的查询
+fn update_classification_query(
+    news: &NewsItem,
+) -> sqlx::query::Query<'_, Sqlite, SqliteArguments<'_>> {
+    sqlx::query(
+        r#"
+        UPDATE news_items
+        SET domain = ?1, classification_confidence = ?2, classification_reason = ?3, status = ?4,
+            classification_trace = ?6
+        WHERE id = ?5
+        "#,
+    )
+    .bind(news.domain.map(|d| d.to_string()))
+    .bind(news.classification_confidence)
+    .bind(&news.classification_reason)
+    .bind(format!("{:?}", news.status))
+    .bind(&news.id)
+    .bind(
+        news.classification_trace
+            .as_ref()
+            .map(ClassificationTrace::to_json),
+    )
+}
+
+/// news_items 表的一行（列顺序见 `NEWS_COLUMNS`）
+#[derive(sqlx::FromRow)]
+struct NewsItemRow {
+    id: String,
+    title: String,
+    url: String,
+    source: String,
+    author: String,
+    content: Option<String>,
+    published_at: String,
+    status: String,
+    domain: Option<String>,
+    classification_confidence: Option<f32>,
+    classification_reason: Option<String>,
+    classification_trace: Option<String>,
+    score: Option<u32>,
+    comment_count: Option<u32>,
+    discussion_url: Option<String>,
+    cluster_id: Option<String>,
+    secondary_domains: Option<String>,
+}
+
 fn row_to_news_item(
-    row: (
-        String,
-        String,
-        String,
-        String,
-        String,
-        Option<String>,
-        String,
-        String,
-        Option<String>,
-        Option<f32>,
-        Option<String>,
-    ),
+    row: NewsItemRow,
 ) -> Result<NewsItem, Box<dyn std::error::Error + Send + Sync>> {
-    let (
-        id,
-        title,
-        url,
-        source,
-        author,
-        content,
-        published_at_str,
-        status_str,
-        domain_str,
-        confidence,
-        reason,
-    ) = row;
-
     let published_at =
-        chrono::DateTime::parse_from_rfc3339(&published_at_str)?.with_timezone(&chrono::Utc);
-    let domain = domain_str.and_then(|s| parse_domain(&s));
-    let status = parse_status(&status_str);
+        chrono::DateTime::parse_from_rfc3339(&row.published_at)?.with_timezone(&chrono::Utc);
+    let domain = row.domain.and_then(|s| parse_domain(&s));
+    let status = parse_status(&row.status);
+    let classification_trace = row
+        .classification_trace
+        .as_deref()
+        .map(ClassificationTrace::from_json)
+        .transpose()?;
+    let secondary_domains = row
+        .secondary_domains
+        .as_deref()
+        .map(parse_secondary_domains)
+        .transpose()?
+        .unwrap_or_default();
 
     Ok(NewsItem {
-        id,
-        title,
-        url,
-        source,
-        author,
-        content,
+        id: row.id,
+        title: row.title,
+        url: row.url,
+        source: row.source,
+        author: row.author,
+        content: row.content,
         published_at,
         status,
         domain,
-        classification_confidence: confidence,
-        classification_reason: reason,
+        secondary_domains,
+        classification_confidence: row.classification_confidence,
+        classification_reason: row.classification_reason,
+        classification_trace,
+        score: row.score,
+        comment_count: row.comment_count,
+        discussion_url: row.discussion_url,
+        source_rank: None,
+        cluster_id: row.cluster_id,
     })
 }
 
-fn parse_domain(s: &str) -> Option<Domain> {
-    match s {
-        "AI" => Some(Domain::AI),
-        "Block" => Some(Domain::Block),
-        "Social" => Some(Domain::Social),
-        _ => None,
+/// `NEWS_COLUMNS` 中次要领域 JSON 数组的一项
+#[derive(serde::Deserialize)]
+struct SecondaryDomainRow {
+    domain: String,
+    confidence: f32,
+}
+
+/// 解析 `NEWS_COLUMNS` 聚合的次要领域 JSON 数组，按置信度从高到低排序（忽略无法识别的领域）
+fn parse_secondary_domains(json: &str) -> Result<Vec<DomainLabel>, serde_json::Error> {
+    let rows: Vec<SecondaryDomainRow> = serde_json::from_str(json)?;
+    let mut labels: Vec<DomainLabel> = rows
+        .into_iter()
+        .filter_map(|row| Some(DomainLabel::new(parse_domain(&row.domain)?, row.confidence)))
+        .collect();
+    labels.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    Ok(labels)
+}
+
+/// 全文检索的一行（新闻列加上片段和相关度）
+#[derive(sqlx::FromRow)]
+struct SearchHitRow {
+    #[sqlx(flatten)]
+    news: NewsItemRow,
+    snippet: String,
+    relevance: f64,
+}
+
+/// 转义 LIKE 模式中的通配符（配合 `ESCAPE '\'` 使用）
+fn escape_like(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// 把用户输入转换为 FTS5 查询表达式
+///
+/// 每个空白分隔的词都作为带引号的短语，多个词之间为"与"关系，
+/// 这样 `GPT-5`、`C++` 之类的输入不会被当成 FTS5 运算符。没有任何词时返回 `None`。
+fn fts_match_expression(query: &str) -> Option<String> {
+    let phrases: Vec<String> = query
+        .split_whitespace()
+        .map(|word| format!("\"{}\"", word.replace('"', "\"\"")))
+        .collect();
+    if phrases.is_empty() {
+        None
+    } else {
+        Some(phrases.join(" "))
     }
 }
 
+/// story_snapshots 表的一行
+#[derive(sqlx::FromRow)]
+struct StorySnapshotRow {
+    news_id: String,
+    captured_at: String,
+    score: Option<u32>,
+    comment_count: Option<u32>,
+    rank: Option<u32>,
+}
+
+fn row_to_snapshot(
+    row: StorySnapshotRow,
+) -> Result<StorySnapshot, Box<dyn std::error::Error + Send + Sync>> {
+    let captured_at = DateTime::parse_from_rfc3339(&row.captured_at)?.with_timezone(&Utc);
+
+    Ok(StorySnapshot {
+        news_id: row.news_id,
+        captured_at,
+        score: row.score,
+        comment_count: row.comment_count,
+        rank: row.rank,
+    })
+}
+
+/// 数据库中的领域名称（可能是已从配置中删除的领域，照常保留）
+pub(super) fn parse_domain(s: &str) -> Option<Domain> {
+    (!s.trim().is_empty()).then(|| Domain::new(s))
+}
+
 fn parse_status(s: &str) -> NewsItemStatus {
     match s {
         "Pending" => NewsItemStatus::Pending,
@@ -323,6 +746,7 @@ fn parse_status(s: &str) -> NewsItemStatus {
         "NeedsReview" => NewsItemStatus::NeedsReview,
         "Completed" => NewsItemStatus::Completed,
         "Failed" => NewsItemStatus::Failed,
+        "Irrelevant" => NewsItemStatus::Irrelevant,
         _ => NewsItemStatus::Pending,
     }
-}
\ No newline at end of file
+}
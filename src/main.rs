@@ -3,12 +3,21 @@ mod cli;
 mod domain;
 mod infrastructure;
 
+use crate::application::daemon::{self, Daemon, DaemonSettings, ScheduledSource};
+use crate::application::keyword_suggestions::KeywordSuggestionSession;
 use crate::application::orchestration;
-use crate::domain::NewsClassificationService;
+use crate::application::review::ReviewSession;
+use crate::application::use_cases::evaluate_classification::{
+    EvaluateClassificationService, ReplayInferenceService, load_dataset,
+};
+use crate::domain::config::{ClassificationConfig, SourceRegistryConfig};
 use crate::domain::fetchers::NewsSourceFactory;
-use crate::domain::services::DiscordService;
+use crate::domain::{Domain, NewsClassificationService, NewsQuery, SortOrder};
 use crate::infrastructure::database::create_pool;
-use crate::infrastructure::repositories::SqliteNewsRepository;
+use crate::infrastructure::repositories::{
+    SqliteKeywordSuggestionRepository, SqliteNewsRepository,
+};
+use sqlx::SqlitePool;
 use std::sync::Arc;
 use tracing::{error, info};
 
@@ -42,80 +51,63 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
             discord_webhook,
             limit,
             domain,
+            sort,
+            title_similarity,
         } => {
-            let repository = if save {
+            let domain = resolve_domains(domain)?;
+            let pool = if save {
                 info!("📊 初始化数据库: {}", db_path);
                 let pool = create_pool(&db_path).await?;
-                let repo =
-                    Arc::new(SqliteNewsRepository::new(pool)) as Arc<dyn domain::NewsRepository>;
                 info!("✅ 数据库初始化完成");
-                Some(repo)
+                Some(pool)
             } else {
                 None
             };
+            let repository = pool.clone().map(|pool| {
+                Arc::new(SqliteNewsRepository::new(pool)) as Arc<dyn domain::NewsRepository>
+            });
 
             // 根据数据源参数创建 fetcher
-            let fetcher = NewsSourceFactory::create(source);
+            let registry = SourceRegistryConfig::load_from_file("config/sources.json")?;
+            let fetcher = NewsSourceFactory::create(&source, &registry)?;
             info!("🌐 从 {} 数据源抓取数据...", fetcher.source_name());
 
             // 初始化 AI 仲裁服务 (OpenAI)
-            let ai_service = infrastructure::create_inference_service();
-            let classifier = if let Some(ai) = ai_service {
-                info!("🤖 AI分类已启用，使用模型: {}", ai.name());
-                Arc::new(NewsClassificationService::new().with_inference_service(ai))
-            } else {
-                info!("🚫 AI分类已禁用，仅使用规则引擎");
-                Arc::new(NewsClassificationService::new())
-            };
+            let classifier = create_classifier(pool.as_ref());
 
             // 根据 domain 参数决定是否执行分类
             let should_classify = domain.is_some();
 
-            let news_items =
-                orchestration::fetch_from_source_with_classification(
-                    fetcher,
-                    classifier.clone(),
-                    limit,
-                    repository.clone(),
-                    should_classify,
-                )
-                .await?;
+            let news_items = orchestration::fetch_from_source_with_classification(
+                fetcher,
+                classifier.clone(),
+                limit,
+                repository.clone(),
+                should_classify,
+                sort,
+                title_similarity,
+            )
+            .await?;
 
             // 如果指定了 domain 参数，进行过滤
-            let filtered_news = if let Some(ref domains) = domain {
-                info!(
-                    "🔍 过滤领域: {}",
-                    domains
-                        .iter()
-                        .map(|d| d.to_string())
-                        .collect::<Vec<_>>()
-                        .join(", ")
-                );
-                news_items
-                    .into_iter()
-                    .filter(|n| n.domain.map_or(false, |d| domains.contains(&d)))
-                    .collect()
-            } else {
-                news_items
-            };
+            let filtered_news = orchestration::filter_by_domains(news_items, domain.as_deref());
 
-            orchestration::display_news(&filtered_news).await;
+            let domains = classifier.domains();
+            orchestration::display_news(&filtered_news, &domains).await;
             info!("✅ 完成！共展示 {} 条新闻", filtered_news.len());
 
             // 发送到 Discord（如果启用）
             if discord && !filtered_news.is_empty() {
-                info!("📤 准备发送到 Discord...");
                 match infrastructure::create_discord_service(discord_webhook) {
                     Ok(discord_service) => {
-                        // 将新闻转换为 Discord 消息
-                        let discord_messages: Vec<_> = filtered_news
-                            .iter()
-                            .map(|news| domain::services::DiscordMessage::from_news_item(news))
-                            .collect();
-                        
-                        match discord_service.send_batch(&discord_messages).await {
-                            Ok(_) => info!("✅ Discord 消息发送成功"),
-                            Err(e) => error!("❌ Discord 发送失败: {}", e),
+                        if let Err(e) = orchestration::send_to_discord(
+                            &discord_service,
+                            &filtered_news,
+                            &domains,
+                        )
+                        .await
+                        {
+                            error!("❌ Discord 发送失败: {}", e);
                         }
                     }
                     Err(e) => {
@@ -124,19 +116,46 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
                 }
             }
         }
-        cli::Commands::List { limit, domain } => {
+        cli::Commands::List {
+            limit,
+            page,
+            domain,
+            source,
+            status,
+            min_confidence,
+            since,
+            until,
+            sort,
+        } => {
+            // hotness 随时间衰减，无法在数据库中排序后分页
+            if sort == SortOrder::Hotness && page.is_some() {
+                return Err("--sort hotness 不能与 --page 同时使用".into());
+            }
+            let page = page.unwrap_or(1);
+            let domain = resolve_domains(domain)?;
             info!("📊 初始化数据库: {}", db_path);
             let pool = create_pool(&db_path).await?;
             let repository =
                 Arc::new(SqliteNewsRepository::new(pool)) as Arc<dyn domain::NewsRepository>;
             info!("✅ 数据库连接成功");
 
-            let news_items =
-                orchestration::load_from_database(&repository, domain.as_deref(), limit).await?;
-
-            orchestration::display_news(&news_items).await;
+            let query = NewsQuery::new(limit)
+                .with_page(page)
+                .with_domains(domain.unwrap_or_default())
+                .with_sources(source)
+                .with_statuses(status)
+                .confidence_between(min_confidence, None)
+                .published_between(since, until);
+            let news_items = orchestration::load_from_database(&repository, &query, sort).await?;
+
+            let config = ClassificationConfig::load_from_file(ClassificationConfig::DEFAULT_PATH)?;
+            orchestration::display_news(&news_items, &config.domains).await;
             info!("═════════════════════════════════════════════");
-            info!("✅ 完成！共展示 {} 条新闻", news_items.len());
+            info!(
+                "✅ 完成！第 {} 页，共展示 {} 条新闻",
+                page,
+                news_items.len()
+            );
         }
         cli::Commands::Stats => {
             let pool = create_pool(&db_path).await?;
@@ -144,11 +163,244 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
                 Arc::new(SqliteNewsRepository::new(pool)) as Arc<dyn domain::NewsRepository>;
             orchestration::show_stats(&repository).await?;
         }
+        cli::Commands::Serve {
+            source,
+            discord,
+            discord_webhook,
+            limit,
+            domain,
+            sort,
+            title_similarity,
+        } => {
+            let domain = resolve_domains(domain)?;
+            info!("📊 初始化数据库: {}", db_path);
+            let pool = create_pool(&db_path).await?;
+            let repository = Arc::new(SqliteNewsRepository::new(pool.clone()))
+                as Arc<dyn domain::NewsRepository>;
+
+            let registry = SourceRegistryConfig::load_from_file("config/sources.json")?;
+            let sources = NewsSourceFactory::create_scheduled(&source, &registry)?
+                .into_iter()
+                .map(|(fetcher, interval)| ScheduledSource { fetcher, interval })
+                .collect();
+
+            let settings = DaemonSettings {
+                limit,
+                domains: domain,
+                sort_order: sort,
+                title_similarity,
+                ..DaemonSettings::default()
+            };
+            let classifier = create_classifier(Some(&pool));
+            let mut daemon = Daemon::new(sources, classifier, repository, settings);
+            if discord {
+                let discord_service = infrastructure::create_discord_service(discord_webhook)?;
+                daemon = daemon.with_discord(Arc::new(discord_service));
+            }
+
+            // SIGINT / SIGTERM 触发优雅退出
+            let (shutdown_tx, shutdown_rx) = tokio::sync::watch::channel(false);
+            tokio::spawn(async move {
+                daemon::shutdown_signal().await;
+                info!("🛑 收到停止信号，等待进行中的抓取完成...");
+                let _ = shutdown_tx.send(true);
+            });
+
+            daemon.run(shutdown_rx).await;
+        }
+        cli::Commands::Trending { window, limit } => {
+            let pool = create_pool(&db_path).await?;
+            let repository =
+                Arc::new(SqliteNewsRepository::new(pool)) as Arc<dyn domain::NewsRepository>;
+            orchestration::show_trending(&repository, window, limit).await?;
+        }
+        cli::Commands::Reclassify {
+            limit,
+            domain,
+            source,
+            status,
+            max_confidence,
+            since,
+            until,
+            dry_run,
+        } => {
+            let domain = resolve_domains(domain)?;
+            info!("📊 初始化数据库: {}", db_path);
+            let pool = create_pool(&db_path).await?;
+            let repository = Arc::new(SqliteNewsRepository::new(pool.clone()))
+                as Arc<dyn domain::NewsRepository>;
+
+            let query = NewsQuery::new(limit)
+                .with_domains(domain.unwrap_or_default())
+                .with_sources(source)
+                .with_statuses(status)
+                .published_between(since, until);
+            let query = match max_confidence {
+                Some(threshold) => query.with_confidence_below(threshold),
+                None => query,
+            };
+            let classifier = create_classifier(Some(&pool));
+            orchestration::reclassify(repository, classifier, &query, dry_run).await?;
+        }
+        cli::Commands::Review { limit, domain } => {
+            let config = ClassificationConfig::load_from_file(ClassificationConfig::DEFAULT_PATH)?;
+            let domain = config.parse_domains(&domain.unwrap_or_default())?;
+            let pool = create_pool(&db_path).await?;
+            let repository =
+                Arc::new(SqliteNewsRepository::new(pool)) as Arc<dyn domain::NewsRepository>;
+
+            let query = NewsQuery::new(limit).with_domains(domain);
+            let stdin = std::io::stdin();
+            let mut session = ReviewSession::new(repository, stdin.lock(), std::io::stdout())
+                .with_domains(config.domain_names());
+            let summary = session.run(&query).await?;
+            info!(
+                "📝 审核完成: 保存 {} 条人工标签（确认 {}，改标 {}，无关 {}），跳过 {} 条",
+                summary.reviewed(),
+                summary.accepted,
+                summary.relabeled,
+                summary.irrelevant,
+                summary.skipped
+            );
+        }
+        cli::Commands::Keywords { command } => match command {
+            cli::KeywordsCommand::Suggestions {
+                limit,
+                domain,
+                config,
+            } => {
+                let config_path =
+                    config.unwrap_or_else(|| ClassificationConfig::DEFAULT_PATH.into());
+                let domain = match domain {
+                    Some(name) => ClassificationConfig::load_from_file(&config_path)?
+                        .parse_domains(&[name])?
+                        .first()
+                        .copied(),
+                    None => None,
+                };
+                let pool = create_pool(&db_path).await?;
+                let repository = Arc::new(SqliteKeywordSuggestionRepository::new(pool))
+                    as Arc<dyn domain::KeywordSuggestionRepository>;
+
+                let stdin = std::io::stdin();
+                let summary =
+                    KeywordSuggestionSession::new(repository, stdin.lock(), std::io::stdout())
+                        .with_config_path(config_path)
+                        .run(domain, limit)
+                        .await?;
+                info!(
+                    "📝 关键词审核完成: 强关键词 {} 个，弱关键词 {} 个，拒绝 {} 个，跳过 {} 个",
+                    summary.strong, summary.weak, summary.rejected, summary.skipped
+                );
+            }
+        },
+        cli::Commands::Eval {
+            dataset,
+            strategy,
+            inference,
+            config,
+            show_errors,
+        } => {
+            let examples = load_dataset(&dataset)?;
+            info!(
+                "🧪 载入 {} 条标注样本: {}",
+                examples.len(),
+                dataset.display()
+            );
+            let config_path = config.unwrap_or_else(|| ClassificationConfig::DEFAULT_PATH.into());
+            let config = ClassificationConfig::load_from_file(&config_path)?;
+            let domains = config.domains.clone();
+
+            let mut service = EvaluateClassificationService::new(config, strategy);
+            match inference {
+                cli::EvalInference::None => {}
+                cli::EvalInference::Mock => {
+                    service = service.with_inference_service(Arc::new(
+                        ReplayInferenceService::from_dataset(&examples),
+                    ));
+                }
+                cli::EvalInference::Openai => {
+                    let ai = infrastructure::create_inference_service(domains)
+                        .ok_or("无法创建 AI 推理服务，请检查 OPENAI_API_KEY 等环境变量")?;
+                    info!("🤖 使用模型: {}", ai.name());
+                    service = service.with_inference_service(ai);
+                }
+            }
+
+            let report = service.execute(&examples).await;
+            if show_errors {
+                for p in report.predictions.iter().filter(|p| !p.is_correct()) {
+                    info!(
+                        "  ❌ {} | 期望 {:?}，预测 {:?} ({:?})",
+                        p.title, p.expected, p.predicted, p.stage
+                    );
+                }
+            }
+            orchestration::print_evaluation(&report);
+        }
+        cli::Commands::Search { query, limit } => {
+            let pool = create_pool(&db_path).await?;
+            let repository =
+                Arc::new(SqliteNewsRepository::new(pool)) as Arc<dyn domain::NewsRepository>;
+            orchestration::search_news(&repository, &query, limit).await?;
+        }
+        cli::Commands::Show { id } => {
+            let pool = create_pool(&db_path).await?;
+            let repository =
+                Arc::new(SqliteNewsRepository::new(pool)) as Arc<dyn domain::NewsRepository>;
+            if !orchestration::show_news(&repository, &id).await? {
+                error!("❌ 未找到新闻: {}", id);
+            }
+        }
+        cli::Commands::Api { bind } => {
+            info!("📊 初始化数据库: {}", db_path);
+            let pool = create_pool(&db_path).await?;
+            let repository =
+                Arc::new(SqliteNewsRepository::new(pool)) as Arc<dyn domain::NewsRepository>;
+
+            let config = ClassificationConfig::load_from_file(ClassificationConfig::DEFAULT_PATH)?;
+            infrastructure::http::serve(bind, repository, config, async {
+                daemon::shutdown_signal().await;
+                info!("🛑 收到停止信号，HTTP API 退出");
+            })
+            .await?;
+        }
     }
 
     Ok(())
 }
 
+/// 创建分类服务：配置了 OpenAI 时启用 AI 仲裁；提供数据库连接时，AI 建议的关键词会记入收件箱等待人工审核
+fn create_classifier(pool: Option<&SqlitePool>) -> Arc<NewsClassificationService> {
+    let mut classifier = NewsClassificationService::new();
+    match infrastructure::create_inference_service(classifier.domains()) {
+        Some(ai) => {
+            info!("🤖 AI分类已启用，使用模型: {}", ai.name());
+            classifier = classifier.with_inference_service(ai);
+        }
+        None => {
+            info!("🚫 AI分类已禁用，仅使用规则引擎");
+        }
+    }
+    if let Some(pool) = pool {
+        classifier = classifier.with_keyword_suggestions(Arc::new(
+            SqliteKeywordSuggestionRepository::new(pool.clone()),
+        ));
+    }
+    Arc::new(classifier)
+}
+
+/// 将命令行给出的领域名称解析为分类配置中的领域（不区分大小写，未配置的名称报错）
+fn resolve_domains(
+    names: Option<Vec<String>>,
+) -> Result<Option<Vec<Domain>>, Box<dyn std::error::Error + Send + Sync>> {
+    let Some(names) = names else {
+        return Ok(None);
+    };
+    let config = ClassificationConfig::load_from_file(ClassificationConfig::DEFAULT_PATH)?;
+    Ok(Some(config.parse_domains(&names)?))
+}
+
 // ========== 集成测试 ==========
 #[cfg(test)]
 mod integration_tests {
@@ -236,6 +488,68 @@ mod integration_tests {
         assert_eq!(loaded[0].title, "Latest GPT-4 News"); // 最新在前
     }
 
+    #[tokio::test]
+    async fn test_fetch_sorts_by_confidence_after_classification() {
+        use crate::domain::SortOrder;
+
+        let base_time = Utc::now();
+        let test_news = vec![
+            create_test_news("1", "Notes on machine learning", "url1", base_time),
+            create_test_news(
+                "2",
+                "OpenAI releases GPT-4 and ChatGPT update",
+                "url2",
+                base_time - Duration::hours(1),
+            ),
+        ];
+
+        let mock_fetcher = MockNewsFetcher::with_data(test_news);
+        let classifier = Arc::new(NewsClassificationService::new());
+        let use_case = FetchHotNewsService::new(&mock_fetcher, classifier)
+            .with_sort_order(SortOrder::Confidence);
+        let news = use_case.execute(10).await.unwrap();
+
+        let confidences: Vec<f32> = news
+            .iter()
+            .map(|n| n.classification_confidence.unwrap())
+            .collect();
+        assert_eq!(news.len(), 2);
+        assert_eq!(news[0].id, "2");
+        assert!(confidences[0] > confidences[1]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_without_classification_applies_exclusions() {
+        use crate::domain::config::ExcludePattern;
+
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        let repository: Arc<dyn domain::NewsRepository> = Arc::new(SqliteNewsRepository::new(pool));
+
+        let now = Utc::now();
+        let test_news = vec![
+            create_test_news("1", "Bitcoin Price Analysis", "https://example.com/1", now),
+            create_test_news("2", "Buy now", "https://promo.spam.example/2", now),
+            create_test_news("3", "Sponsored: new wallet", "https://example.com/3", now),
+        ];
+        let config = ClassificationConfig {
+            exclude_patterns: vec![
+                ExcludePattern::Host("spam.example".to_string()),
+                ExcludePattern::Title("(?i)^sponsored:".to_string()),
+            ],
+            ..ClassificationConfig::default()
+        };
+
+        let mock_fetcher = MockNewsFetcher::with_data(test_news);
+        let classifier = Arc::new(NewsClassificationService::new().with_config(config));
+        let use_case = FetchHotNewsService::new(&mock_fetcher, classifier)
+            .with_repository(Arc::clone(&repository));
+        let news = use_case.execute_without_classification(10).await.unwrap();
+
+        assert_eq!(news.len(), 1);
+        assert_eq!(repository.count().await.unwrap(), 1);
+        assert!(repository.find_by_id("1").await.unwrap().is_some());
+    }
+
     #[tokio::test]
     async fn test_duplicate_url_handling() {
         // 测试 URL 去重
@@ -267,4 +581,358 @@ mod integration_tests {
         let loaded = repository.find_recent(10).await.unwrap();
         assert_eq!(loaded[0].title, "GPT-4 First");
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_engagement_metrics_round_trip() {
+        // 测试热度指标的保存与加载
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        let repository: Arc<dyn domain::NewsRepository> = Arc::new(SqliteNewsRepository::new(pool));
+
+        let news = create_test_news("42", "Show HN: Something", "url42", Utc::now())
+            .with_engagement(Some(256), Some(64))
+            .with_discussion_url("https://news.ycombinator.com/item?id=42".to_string());
+        repository.save(&news).await.unwrap();
+
+        let loaded = repository.find_by_id("42").await.unwrap().unwrap();
+        assert_eq!(loaded.score, Some(256));
+        assert_eq!(loaded.comment_count, Some(64));
+        assert_eq!(
+            loaded.discussion_url.as_deref(),
+            Some("https://news.ycombinator.com/item?id=42")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_secondary_domains_round_trip_and_filters() {
+        // 测试次要领域的保存、加载、按领域过滤和分组
+        use crate::domain::{Domain, DomainLabel};
+
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        let repository: Arc<dyn domain::NewsRepository> = Arc::new(SqliteNewsRepository::new(pool));
+
+        let now = Utc::now();
+        let mut bot = create_test_news("1", "AI trading bot on Ethereum", "url-1", now);
+        bot.domain = Some(Domain::new("AI"));
+        bot.classification_confidence = Some(0.9);
+        // 领域名称中的 `,` 和 `:` 不影响次要领域的加载
+        bot.secondary_domains = vec![
+            DomainLabel::new(Domain::new("Block"), 0.7),
+            DomainLabel::new(Domain::new("R&D: Labs, Inc"), 0.5),
+        ];
+        let mut etf = create_test_news(
+            "2",
+            "Bitcoin ETF inflows",
+            "url-2",
+            now - Duration::hours(1),
+        );
+        etf.domain = Some(Domain::new("Block"));
+        etf.classification_confidence = Some(0.9);
+        repository.save_batch(&[bot.clone(), etf]).await.unwrap();
+
+        let loaded = repository.find_by_id("1").await.unwrap().unwrap();
+        assert_eq!(loaded.domain, Some(Domain::new("AI")));
+        assert_eq!(loaded.secondary_domains, bot.secondary_domains);
+
+        // 次要领域也参与领域过滤
+        let block = repository
+            .find_by_domains(&[Domain::new("Block")], 10)
+            .await
+            .unwrap();
+        assert_eq!(
+            block.iter().map(|n| n.id.as_str()).collect::<Vec<_>>(),
+            vec!["1", "2"]
+        );
+        let ai = repository
+            .find(&domain::NewsQuery::new(10).with_domains(vec![Domain::new("AI")]))
+            .await
+            .unwrap();
+        assert_eq!(ai.len(), 1);
+
+        let grouped = NewsClassificationService::new().group_by_domain(&block);
+        assert_eq!(grouped[&Domain::new("AI")].len(), 1);
+        assert_eq!(grouped[&Domain::new("Block")].len(), 2);
+
+        // 重新分类后替换原有的次要领域
+        bot.secondary_domains.clear();
+        repository.update_classifications(&[bot]).await.unwrap();
+        let block = repository
+            .find_by_domains(&[Domain::new("Block")], 10)
+            .await
+            .unwrap();
+        assert_eq!(block.len(), 1);
+        assert!(
+            repository
+                .find_by_id("1")
+                .await
+                .unwrap()
+                .unwrap()
+                .secondary_domains
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_refetch_records_snapshots() {
+        // 测试重复抓取已保存的新闻时记录快照并计算增长速度
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        let repository: Arc<dyn domain::NewsRepository> = Arc::new(SqliteNewsRepository::new(pool));
+        let classifier = Arc::new(NewsClassificationService::new());
+
+        let first = create_test_news("7", "OpenAI ships GPT-4 update", "url7", Utc::now())
+            .with_engagement(Some(100), Some(10))
+            .with_source_rank(5);
+        let fetcher = MockNewsFetcher::with_data(vec![first]);
+        FetchHotNewsService::new(&fetcher, Arc::clone(&classifier))
+            .with_repository(Arc::clone(&repository))
+            .execute(10)
+            .await
+            .unwrap();
+
+        // 第二次抓取到同一 URL，分数上升（ID 不同也应关联到已存储的新闻）
+        let second = create_test_news("7-again", "OpenAI ships GPT-4 update", "url7", Utc::now())
+            .with_engagement(Some(400), Some(50))
+            .with_source_rank(1);
+        let fetcher = MockNewsFetcher::with_data(vec![second]);
+        let fresh = FetchHotNewsService::new(&fetcher, classifier)
+            .with_repository(Arc::clone(&repository))
+            .execute(10)
+            .await
+            .unwrap();
+        assert!(fresh.is_empty());
+        assert_eq!(repository.count().await.unwrap(), 1);
+
+        let snapshots = repository.find_snapshots("7").await.unwrap();
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!(snapshots[1].score, Some(400));
+        assert_eq!(snapshots[1].rank, Some(1));
+
+        // 已存储新闻的热度同步为最新值
+        let stored = repository.find_by_id("7").await.unwrap().unwrap();
+        assert_eq!(stored.score, Some(400));
+
+        let since = Utc::now() - Duration::hours(1);
+        let snapshots = repository.find_snapshots_since(since).await.unwrap();
+        let velocities = domain::StoryVelocityService::compute(&snapshots);
+        assert_eq!(velocities.len(), 1);
+        assert_eq!(velocities[0].news_id, "7");
+        assert_eq!(velocities[0].score_gain, 300);
+    }
+
+    #[tokio::test]
+    async fn test_canonical_url_matches_stored_item() {
+        // 测试跟踪参数、协议、www 前缀不同的 URL 被识别为同一条新闻
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        let repository: Arc<dyn domain::NewsRepository> = Arc::new(SqliteNewsRepository::new(pool));
+
+        let stored = create_test_news(
+            "1",
+            "Latest GPT-4 News",
+            "http://www.example.com/gpt-4/",
+            Utc::now(),
+        );
+        repository.save(&stored).await.unwrap();
+
+        let refetched = create_test_news(
+            "2",
+            "Latest GPT-4 News",
+            "https://example.com/gpt-4?utm_source=hn#comments",
+            Utc::now(),
+        );
+        let fetcher = MockNewsFetcher::with_data(vec![refetched]);
+        let fresh = FetchHotNewsService::new(&fetcher, Arc::new(NewsClassificationService::new()))
+            .with_repository(Arc::clone(&repository))
+            .execute(10)
+            .await
+            .unwrap();
+
+        assert!(fresh.is_empty());
+        assert_eq!(repository.count().await.unwrap(), 1);
+
+        // 展示时保留原始 URL
+        let loaded = repository.find_by_id("1").await.unwrap().unwrap();
+        assert_eq!(loaded.url, "http://www.example.com/gpt-4/");
+    }
+
+    #[tokio::test]
+    async fn test_query_filters_and_pagination() {
+        // 测试组合过滤条件、页码分页和游标分页
+        use crate::domain::{Domain, NewsCursor, NewsItemStatus, NewsQueryOrder};
+
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        let repository: Arc<dyn domain::NewsRepository> = Arc::new(SqliteNewsRepository::new(pool));
+
+        let now = Utc::now();
+        let mut items: Vec<NewsItem> = (0..5)
+            .map(|i| {
+                create_test_news(
+                    &format!("{}", i),
+                    &format!("Headline {}", i),
+                    &format!("url-{}", i),
+                    now - Duration::hours(i),
+                )
+            })
+            .collect();
+        items[1].source = "coindesk".to_string();
+        items[1].domain = Some(Domain::new("Block"));
+        items[1].classification_confidence = Some(0.9);
+        items[1].status = NewsItemStatus::Completed;
+        items[2].domain = Some(Domain::new("AI"));
+        items[2].classification_confidence = Some(0.4);
+        items[2].status = NewsItemStatus::Completed;
+        items[3].title = "100% discount_code".to_string();
+        repository.save_batch(&items).await.unwrap();
+
+        let ids = |news: &[NewsItem]| news.iter().map(|n| n.id.clone()).collect::<Vec<_>>();
+
+        // 页码分页
+        let page2 = repository
+            .find(&NewsQuery::new(2).with_page(2))
+            .await
+            .unwrap();
+        assert_eq!(ids(&page2), vec!["2", "3"]);
+
+        // 游标分页与页码分页结果一致
+        let page1 = repository.find(&NewsQuery::new(2)).await.unwrap();
+        let next = repository
+            .find(&NewsQuery::new(2).with_cursor(NewsCursor::after(&page1[1])))
+            .await
+            .unwrap();
+        assert_eq!(ids(&next), ids(&page2));
+
+        // 组合过滤
+        let completed = NewsQuery::new(10).with_statuses(vec![NewsItemStatus::Completed]);
+        assert_eq!(
+            ids(&repository.find(&completed.clone()).await.unwrap()),
+            vec!["1", "2"]
+        );
+        let confident = completed.confidence_between(Some(0.5), None);
+        assert_eq!(ids(&repository.find(&confident).await.unwrap()), vec!["1"]);
+        let by_source = NewsQuery::new(10).with_sources(vec!["coindesk".to_string()]);
+        assert_eq!(ids(&repository.find(&by_source).await.unwrap()), vec!["1"]);
+        let window = NewsQuery::new(10).published_between(
+            Some(now - Duration::minutes(150)),
+            Some(now - Duration::minutes(30)),
+        );
+        assert_eq!(
+            ids(&repository.find(&window).await.unwrap()),
+            vec!["1", "2"]
+        );
+
+        // 文本包含按字面匹配通配符
+        let text = NewsQuery::new(10).containing("0% DISCOUNT_");
+        assert_eq!(ids(&repository.find(&text).await.unwrap()), vec!["3"]);
+        let wildcard = NewsQuery::new(10).containing("%");
+        assert_eq!(ids(&repository.find(&wildcard).await.unwrap()), vec!["3"]);
+
+        // 排序
+        let oldest = NewsQuery::new(1).with_order(NewsQueryOrder::Oldest);
+        assert_eq!(ids(&repository.find(&oldest).await.unwrap()), vec!["4"]);
+        let by_confidence = NewsQuery::new(1).with_order(NewsQueryOrder::Confidence);
+        assert_eq!(
+            ids(&repository.find(&by_confidence).await.unwrap()),
+            vec!["1"]
+        );
+        assert!(
+            repository
+                .find(&by_confidence.with_cursor(NewsCursor::after(&items[0])))
+                .await
+                .is_err()
+        );
+        let by_source = NewsQuery::new(1).with_order(NewsQueryOrder::Source);
+        assert_eq!(ids(&repository.find(&by_source).await.unwrap()), vec!["1"]);
+
+        // list --sort 在分页之前排序：第 2 页接在第 1 页之后，而不是对按时间取出的第 2 页重新排序
+        let page2_by_confidence = orchestration::load_from_database(
+            &repository,
+            &NewsQuery::new(2).with_page(2),
+            SortOrder::Confidence,
+        )
+        .await
+        .unwrap();
+        assert_eq!(ids(&page2_by_confidence), vec!["0", "3"]);
+    }
+
+    #[tokio::test]
+    async fn test_full_text_search() {
+        // 测试全文检索按相关度排序、高亮命中词，并随新闻删除/更新保持同步
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        let repository: Arc<dyn domain::NewsRepository> =
+            Arc::new(SqliteNewsRepository::new(pool.clone()));
+
+        let items = vec![
+            create_test_news("1", "Ethereum staking update", "url-1", Utc::now())
+                .with_content("A quiet week for Rust tooling".to_string()),
+            create_test_news("2", "Rust 2024 edition released", "url-2", Utc::now()),
+            create_test_news("3", "Bitcoin ETF sees record inflows", "url-3", Utc::now()),
+        ];
+        repository.save_batch(&items).await.unwrap();
+
+        let hits = repository.search("rust", 10).await.unwrap();
+        let ids: Vec<&str> = hits.iter().map(|h| h.news.id.as_str()).collect();
+        // 标题命中排在正文命中之前
+        assert_eq!(ids, vec!["2", "1"]);
+        assert!(hits[0].snippet.contains("[Rust]"), "{}", hits[0].snippet);
+
+        // 多个词之间为"与"关系，运算符字符按普通文本处理
+        let hits = repository.search("rust 2024-edition", 10).await.unwrap();
+        assert_eq!(hits.len(), 1);
+        assert!(repository.search("   ", 10).await.unwrap().is_empty());
+
+        sqlx::query("UPDATE news_items SET title = 'Go 1.23 released' WHERE id = '2'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("DELETE FROM news_items WHERE id = '1'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(repository.search("rust", 10).await.unwrap().is_empty());
+        assert_eq!(repository.search("go", 10).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_similar_titles_are_clustered() {
+        // 测试同一事件的不同报道（标题近似、URL 不同）归入同一聚类并全部保留
+        let pool = create_pool("sqlite::memory:").await.unwrap();
+        let repository: Arc<dyn domain::NewsRepository> = Arc::new(SqliteNewsRepository::new(pool));
+
+        let stored = create_test_news("1", "OpenAI launches GPT-4 Turbo", "url-a", Utc::now());
+        repository.save(&stored).await.unwrap();
+
+        let batch = vec![
+            create_test_news("2", "OpenAI launches GPT-4 Turbo!", "url-b", Utc::now()),
+            create_test_news("3", "Bitcoin ETF sees record inflows", "url-c", Utc::now()),
+            create_test_news(
+                "4",
+                "Bitcoin ETF sees record inflows today",
+                "url-d",
+                Utc::now(),
+            ),
+        ];
+        let fetcher = MockNewsFetcher::with_data(batch);
+        let fresh = FetchHotNewsService::new(&fetcher, Arc::new(NewsClassificationService::new()))
+            .with_repository(Arc::clone(&repository))
+            .execute(10)
+            .await
+            .unwrap();
+
+        assert_eq!(fresh.len(), 3);
+        assert_eq!(repository.count().await.unwrap(), 4);
+
+        // 与已入库新闻近似的报道加入其聚类，批次内的近似报道互相聚类
+        let cluster_of = |id: &str| {
+            fresh
+                .iter()
+                .find(|n| n.id == id)
+                .and_then(|n| n.cluster_id.clone())
+        };
+        assert_eq!(cluster_of("2").as_deref(), Some("1"));
+        assert_eq!(cluster_of("3").as_deref(), Some("3"));
+        assert_eq!(cluster_of("4").as_deref(), Some("3"));
+
+        // 聚类 ID 持久化，展示时每个事件一条
+        let loaded = repository.find_recent(10).await.unwrap();
+        let stories = domain::StoryClusteringService::group(loaded);
+        assert_eq!(stories.len(), 2);
+    }
+}